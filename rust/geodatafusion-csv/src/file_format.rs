@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::SchemaRef;
+use async_trait::async_trait;
+use datafusion::catalog::Session;
+use datafusion::common::{GetExt, Statistics};
+use datafusion::config::{ConfigField, ConfigFileType, CsvOptions};
+use datafusion::datasource::file_format::FileFormat;
+use datafusion::datasource::file_format::csv::CsvFormat;
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
+use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig, FileSource};
+use datafusion::datasource::source::DataSourceExec;
+use datafusion::error::Result;
+use datafusion::physical_expr::LexRequirement;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion_datasource::TableSchema;
+use datafusion_datasource::file_format::FileFormatFactory;
+use datafusion_datasource::file_scan_config::FileScanConfigBuilder;
+use geoarrow_schema::CoordType;
+use object_store::{ObjectMeta, ObjectStore};
+
+use crate::geometry::CsvGeometryOptions;
+use crate::source::GeoCsvSource;
+
+#[derive(Default, Debug)]
+pub struct GeoCsvFormatFactory {
+    /// inner options for CSV
+    pub options: Option<CsvOptions>,
+    /// default geometry options, used when none are passed as format options
+    pub geometry: Option<CsvGeometryOptions>,
+}
+
+impl GeoCsvFormatFactory {
+    /// Creates an instance of [GeoCsvFormatFactory]
+    pub fn new() -> Self {
+        Self {
+            options: None,
+            geometry: None,
+        }
+    }
+
+    /// Creates an instance of [GeoCsvFormatFactory] with customized default options
+    pub fn new_with_options(options: CsvOptions) -> Self {
+        Self {
+            options: Some(options),
+            geometry: None,
+        }
+    }
+
+    /// Set the default geometry options
+    pub fn with_geometry(self, geometry: CsvGeometryOptions) -> Self {
+        Self {
+            geometry: Some(geometry),
+            ..self
+        }
+    }
+}
+
+impl FileFormatFactory for GeoCsvFormatFactory {
+    fn create(
+        &self,
+        state: &dyn Session,
+        format_options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn FileFormat>> {
+        let mut format_options = format_options.clone();
+        let geometry = CsvGeometryOptions::take_from_format_options(&mut format_options)?
+            .or_else(|| self.geometry.clone());
+
+        let csv_options = match &self.options {
+            None => {
+                let mut table_options = state.default_table_options();
+                table_options.set_config_format(ConfigFileType::CSV);
+                table_options.alter_with_string_hash_map(&format_options)?;
+                table_options.csv
+            }
+            Some(csv_options) => {
+                let mut csv_options = csv_options.clone();
+                for (k, v) in &format_options {
+                    csv_options.set(k, v)?;
+                }
+                csv_options
+            }
+        };
+
+        let csv_format = CsvFormat::default().with_options(csv_options);
+        let mut format = GeoCsvFormat::new(csv_format);
+        if let Some(geometry) = geometry {
+            format = format.with_geometry(geometry);
+        }
+        Ok(Arc::new(format))
+    }
+
+    fn default(&self) -> Arc<dyn FileFormat> {
+        let mut format = GeoCsvFormat::default();
+        if let Some(geometry) = &self.geometry {
+            format = format.with_geometry(geometry.clone());
+        }
+        Arc::new(format)
+    }
+}
+
+impl GetExt for GeoCsvFormatFactory {
+    fn get_ext(&self) -> String {
+        "csv".to_string()
+    }
+}
+
+/// CSV `FileFormat` implementation that promotes WKT or x/y columns to a GeoArrow geometry
+/// column, named `geometry` unless configured otherwise.
+///
+/// Without [`CsvGeometryOptions`] this behaves like a plain [`CsvFormat`].
+#[derive(Debug, Default)]
+pub struct GeoCsvFormat {
+    inner: CsvFormat,
+    geometry: Option<CsvGeometryOptions>,
+    coord_type: CoordType,
+}
+
+impl GeoCsvFormat {
+    /// Creates a new instance of `GeoCsvFormat`
+    pub fn new(format: CsvFormat) -> Self {
+        Self {
+            inner: format,
+            geometry: None,
+            coord_type: CoordType::default(),
+        }
+    }
+
+    /// Set the columns the geometry column is constructed from
+    pub fn with_geometry(self, geometry: CsvGeometryOptions) -> Self {
+        Self {
+            geometry: Some(geometry),
+            ..self
+        }
+    }
+
+    /// Set the coordinate type of the geometry column
+    pub fn with_coord_type(self, coord_type: CoordType) -> Self {
+        Self { coord_type, ..self }
+    }
+}
+
+#[async_trait]
+impl FileFormat for GeoCsvFormat {
+    fn get_ext(&self) -> String {
+        self.inner.get_ext()
+    }
+
+    fn get_ext_with_compression(
+        &self,
+        file_compression_type: &FileCompressionType,
+    ) -> Result<String> {
+        self.inner.get_ext_with_compression(file_compression_type)
+    }
+
+    fn compression_type(&self) -> Option<FileCompressionType> {
+        self.inner.compression_type()
+    }
+
+    async fn infer_schema(
+        &self,
+        state: &dyn Session,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let schema = self.inner.infer_schema(state, store, objects).await?;
+        if let Some(geometry) = &self.geometry {
+            Ok(Arc::new(geometry.table_schema(&schema, self.coord_type)?))
+        } else {
+            Ok(schema)
+        }
+    }
+
+    async fn infer_stats(
+        &self,
+        state: &dyn Session,
+        store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        self.inner
+            .infer_stats(state, store, table_schema, object)
+            .await
+    }
+
+    async fn create_physical_plan(
+        &self,
+        state: &dyn Session,
+        conf: FileScanConfig,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let Some(geo_csv_source) = conf.file_source().downcast_ref::<GeoCsvSource>() else {
+            return self.inner.create_physical_plan(state, conf).await;
+        };
+
+        // Consult configuration options for default values, as CsvFormat does
+        let options = self.inner.options();
+        let mut csv_options = options.clone();
+        csv_options.has_header = Some(
+            options
+                .has_header
+                .unwrap_or_else(|| state.config_options().catalog.has_header),
+        );
+        csv_options.newlines_in_values = Some(
+            options
+                .newlines_in_values
+                .unwrap_or_else(|| state.config_options().catalog.newlines_in_values),
+        );
+        let source = geo_csv_source.clone().with_csv_options(csv_options);
+
+        let config = FileScanConfigBuilder::from(conf)
+            .with_file_compression_type(options.compression.into())
+            .with_source(Arc::new(source))
+            .build();
+
+        Ok(DataSourceExec::from_data_source(config))
+    }
+
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        state: &dyn Session,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.inner
+            .create_writer_physical_plan(input, state, conf, order_requirements)
+            .await
+    }
+
+    fn file_source(&self, table_schema: TableSchema) -> Arc<dyn FileSource> {
+        match &self.geometry {
+            Some(geometry) => Arc::new(
+                GeoCsvSource::new(table_schema, geometry.clone(), self.coord_type)
+                    .with_csv_options(self.inner.options().clone()),
+            ),
+            None => self.inner.file_source(table_schema),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Configuration of the geometry column built from CSV columns
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Schema};
+use datafusion::config::ConfigOptions;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::ScalarUDF;
+use datafusion::physical_expr::expressions::{Column, Literal};
+use datafusion::physical_expr::projection::{ProjectionExpr, ProjectionExprs};
+use datafusion::physical_expr::{PhysicalExpr, ScalarFunctionExpr};
+use datafusion::scalar::ScalarValue;
+use geoarrow_schema::CoordType;
+use geodatafusion::udf::native::constructors::Point;
+use geodatafusion::udf::native::io::GeomFromText;
+
+/// Default name of the geometry column appended to the table schema.
+const GEOMETRY_COLUMN_NAME: &str = "geometry";
+
+const WKT_OPTION: &str = "geometry_wkt";
+const X_OPTION: &str = "geometry_x";
+const Y_OPTION: &str = "geometry_y";
+const SRID_OPTION: &str = "geometry_srid";
+const NAME_OPTION: &str = "geometry_column_name";
+
+/// The CSV column(s) that a geometry is constructed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeometryColumns {
+    /// A single column of Well-Known Text, parsed with `ST_GeomFromText`.
+    Wkt(String),
+    /// A pair of numeric columns, combined with `ST_Point`.
+    XY { x: String, y: String },
+}
+
+/// Describes how to promote CSV columns to a GeoArrow geometry column.
+///
+/// The source columns are kept in the table schema and a geometry column, named `geometry` by
+/// default, is appended.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CsvGeometryOptions {
+    columns: GeometryColumns,
+    srid: Option<i64>,
+    name: String,
+}
+
+impl CsvGeometryOptions {
+    /// Build geometries from a column of Well-Known Text
+    pub fn wkt(column: impl Into<String>) -> Self {
+        Self {
+            columns: GeometryColumns::Wkt(column.into()),
+            srid: None,
+            name: GEOMETRY_COLUMN_NAME.to_string(),
+        }
+    }
+
+    /// Build points from a pair of x (longitude) and y (latitude) columns
+    pub fn xy(x: impl Into<String>, y: impl Into<String>) -> Self {
+        Self {
+            columns: GeometryColumns::XY {
+                x: x.into(),
+                y: y.into(),
+            },
+            srid: None,
+            name: GEOMETRY_COLUMN_NAME.to_string(),
+        }
+    }
+
+    /// Store the given SRID as the CRS of the geometry column
+    pub fn with_srid(self, srid: i64) -> Self {
+        Self {
+            srid: Some(srid),
+            ..self
+        }
+    }
+
+    /// Set the name of the geometry column
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    /// The CSV column(s) that the geometry is constructed from
+    pub fn columns(&self) -> &GeometryColumns {
+        &self.columns
+    }
+
+    /// The SRID of the geometry column, if any
+    pub fn srid(&self) -> Option<i64> {
+        self.srid
+    }
+
+    /// The name of the geometry column
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Remove the geometry-related keys from a set of table options.
+    ///
+    /// Recognizes `geometry_wkt`, or `geometry_x` and `geometry_y`, plus an optional
+    /// `geometry_srid` and `geometry_column_name`. Keys may carry the `format.` prefix used by `CREATE EXTERNAL TABLE`.
+    pub(crate) fn take_from_format_options(
+        format_options: &mut HashMap<String, String>,
+    ) -> Result<Option<Self>> {
+        let mut take = |key: &str| {
+            format_options
+                .remove(key)
+                .or_else(|| format_options.remove(&format!("format.{key}")))
+        };
+
+        let wkt = take(WKT_OPTION);
+        let x = take(X_OPTION);
+        let y = take(Y_OPTION);
+        let srid = take(SRID_OPTION)
+            .map(|srid| {
+                srid.parse::<i64>().map_err(|_| {
+                    DataFusionError::Configuration(format!(
+                        "Invalid {SRID_OPTION} '{srid}': expected an integer"
+                    ))
+                })
+            })
+            .transpose()?;
+        let name = take(NAME_OPTION);
+
+        let options = match (wkt, x, y) {
+            (Some(wkt), None, None) => Self::wkt(wkt),
+            (None, Some(x), Some(y)) => Self::xy(x, y),
+            (None, None, None) => {
+                if let Some(key) = srid
+                    .map(|_| SRID_OPTION)
+                    .or(name.as_ref().map(|_| NAME_OPTION))
+                {
+                    return Err(DataFusionError::Configuration(format!(
+                        "{key} requires either {WKT_OPTION} or {X_OPTION} and {Y_OPTION}"
+                    )));
+                }
+                return Ok(None);
+            }
+            _ => {
+                return Err(DataFusionError::Configuration(format!(
+                    "Expected either {WKT_OPTION} or both {X_OPTION} and {Y_OPTION}"
+                )));
+            }
+        };
+
+        let options = match srid {
+            Some(srid) => options.with_srid(srid),
+            None => options,
+        };
+        Ok(Some(match name {
+            Some(name) => options.with_name(name),
+            None => options,
+        }))
+    }
+
+    /// Coerce the source columns of an inferred CSV schema to the types the geometry
+    /// constructors expect.
+    fn csv_schema(&self, schema: &Schema) -> Result<Schema> {
+        let mut fields = schema.fields().to_vec();
+        let mut coerce = |name: &str, data_type: DataType| -> Result<()> {
+            let (idx, _) = schema.column_with_name(name).ok_or_else(|| {
+                DataFusionError::Plan(format!("Geometry column '{name}' not found in CSV schema"))
+            })?;
+            fields[idx] = Arc::new(Field::new(name, data_type, true));
+            Ok(())
+        };
+
+        match &self.columns {
+            GeometryColumns::Wkt(wkt) => coerce(wkt, DataType::Utf8)?,
+            GeometryColumns::XY { x, y } => {
+                coerce(x, DataType::Float64)?;
+                coerce(y, DataType::Float64)?;
+            }
+        }
+
+        Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+    }
+
+    /// The expression constructing the geometry column from a batch of CSV columns.
+    fn geometry_expr(
+        &self,
+        csv_schema: &Schema,
+        coord_type: CoordType,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        let column = |name: &str| -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(Arc::new(Column::new_with_schema(name, csv_schema)?))
+        };
+
+        let (udf, mut args) = match &self.columns {
+            GeometryColumns::Wkt(wkt) => (
+                ScalarUDF::from(GeomFromText::new(coord_type)),
+                vec![column(wkt)?],
+            ),
+            GeometryColumns::XY { x, y } => (
+                ScalarUDF::from(Point::new(coord_type)),
+                vec![column(x)?, column(y)?],
+            ),
+        };
+        if let Some(srid) = self.srid {
+            args.push(Arc::new(Literal::new(ScalarValue::Int64(Some(srid)))));
+        }
+
+        Ok(Arc::new(ScalarFunctionExpr::try_new(
+            Arc::new(udf),
+            args,
+            csv_schema,
+            Arc::new(ConfigOptions::default()),
+        )?))
+    }
+
+    /// The projection converting a batch read with `csv_schema` into a batch of the table's file
+    /// schema, i.e. the CSV columns followed by the geometry column.
+    pub(crate) fn conversion(
+        &self,
+        csv_schema: &Schema,
+        coord_type: CoordType,
+    ) -> Result<ProjectionExprs> {
+        let mut exprs = csv_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                ProjectionExpr::new(Arc::new(Column::new(field.name(), idx)), field.name())
+            })
+            .collect::<Vec<_>>();
+        exprs.push(ProjectionExpr::new(
+            self.geometry_expr(csv_schema, coord_type)?,
+            &self.name,
+        ));
+        Ok(ProjectionExprs::new(exprs))
+    }
+
+    /// Derive the table schema from a schema inferred from the CSV files.
+    pub(crate) fn table_schema(&self, schema: &Schema, coord_type: CoordType) -> Result<Schema> {
+        if schema.column_with_name(&self.name).is_some() {
+            return Err(DataFusionError::Plan(format!(
+                "CSV column '{}' clashes with the name of the geometry column, set {NAME_OPTION} \
+                 to a different name",
+                self.name
+            )));
+        }
+        let csv_schema = self.csv_schema(schema)?;
+        self.conversion(&csv_schema, coord_type)?
+            .project_schema(&csv_schema)
+    }
+}
+
+/// Recover the schema of the CSV files from a table file schema built by
+/// [`CsvGeometryOptions::table_schema`], where the geometry column is always the last field.
+pub(crate) fn csv_schema_from_table_schema(file_schema: &Schema) -> Schema {
+    let mut fields = file_schema.fields().to_vec();
+    fields.pop();
+    Schema::new_with_metadata(fields, file_schema.metadata().clone())
+}
@@ -0,0 +1,171 @@
+// #![doc = include_str!("../README.md")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+// #![warn(missing_docs)]
+#![doc(
+    html_logo_url = "https://github.com/geoarrow.png",
+    html_favicon_url = "https://github.com/geoarrow.png?size=32"
+)]
+
+pub mod file_format;
+mod geometry;
+pub mod source;
+
+pub use geometry::{CsvGeometryOptions, GeometryColumns};
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use datafusion::execution::SessionStateBuilder;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_schema::{CoordType, Crs, Dimension, GeoArrowType, Metadata, PointType};
+
+    use crate::CsvGeometryOptions;
+    use crate::file_format::GeoCsvFormatFactory;
+
+    const CITIES: &str = "../../fixtures/csv/cities.csv";
+
+    fn session(file_format: GeoCsvFormatFactory) -> SessionContext {
+        let state = SessionStateBuilder::new()
+            .with_default_features()
+            .with_file_formats(vec![Arc::new(file_format)])
+            .build();
+        let ctx = SessionContext::new_with_state(state).enable_url_table();
+        geodatafusion::register(&ctx);
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_xy_columns() {
+        let file_format = GeoCsvFormatFactory::new()
+            .with_geometry(CsvGeometryOptions::xy("lon", "lat").with_srid(4326));
+        let ctx = session(file_format);
+
+        let df = ctx
+            .sql(&format!(
+                "SELECT name, geometry FROM '{CITIES}' ORDER BY name;"
+            ))
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let batch = batches.into_iter().next().unwrap();
+        assert_eq!(batch.num_rows(), 5);
+
+        let schema = batch.schema();
+        let geo_type = GeoArrowType::try_from(schema.field(1)).unwrap();
+        let expected_metadata = Arc::new(Metadata::new(
+            Crs::from_authority_code("EPSG:4326".to_string()),
+            None,
+        ));
+        assert_eq!(
+            geo_type,
+            GeoArrowType::Point(
+                PointType::new(Dimension::XY, expected_metadata)
+                    .with_coord_type(CoordType::Separated)
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wkt_column() {
+        let ctx = session(GeoCsvFormatFactory::new());
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE cities STORED AS CSV LOCATION '{CITIES}' OPTIONS ('format.geometry_wkt' 'wkt');"
+        ))
+        .await
+        .unwrap();
+
+        let df = ctx
+            .sql("SELECT name, ST_AsText(geometry) FROM cities WHERE name = 'Paris';")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let batch = batches.into_iter().next().unwrap();
+        assert_eq!(
+            batch.column(1).as_string::<i32>().value(0),
+            "POINT(2.3522 48.8566)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geometry_column_name() {
+        let ctx = session(GeoCsvFormatFactory::new());
+        let err = ctx
+            .sql(&format!(
+                "CREATE EXTERNAL TABLE clash STORED AS CSV LOCATION '{CITIES}' OPTIONS ('format.geometry_wkt' 'wkt', 'format.geometry_column_name' 'name');"
+            ))
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("CSV column 'name' clashes with the name of the geometry column"),
+            "{err}"
+        );
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE cities STORED AS CSV LOCATION '{CITIES}' OPTIONS ('format.geometry_x' 'lon', 'format.geometry_y' 'lat', 'format.geometry_column_name' 'geom');"
+        ))
+        .await
+        .unwrap();
+
+        let sql = "SELECT * FROM cities WHERE ST_Intersects(geom, ST_GeomFromText('POLYGON((-10 35, 30 35, 30 60, -10 60, -10 35))')) ORDER BY name;";
+        let plan = ctx
+            .sql(&format!("EXPLAIN {sql}"))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let plan = arrow_cast_display(&plan);
+        assert!(plan.contains("bbox=[-10.0, 35.0, 30.0, 60.0]"), "{plan}");
+
+        let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        let schema = batches[0].schema();
+        assert_eq!(schema.fields().len(), 5);
+        assert_eq!(schema.field(4).name(), "geom");
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_bbox_pushdown() {
+        let ctx = session(GeoCsvFormatFactory::new());
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE cities STORED AS CSV LOCATION '{CITIES}' OPTIONS ('format.geometry_x' 'lon', 'format.geometry_y' 'lat');"
+        ))
+        .await
+        .unwrap();
+
+        let sql = "SELECT name FROM cities WHERE ST_Intersects(geometry, ST_GeomFromText('POLYGON((-10 35, 30 35, 30 60, -10 60, -10 35))')) ORDER BY name;";
+        let plan = ctx
+            .sql(&format!("EXPLAIN {sql}"))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let plan = arrow_cast_display(&plan);
+        assert!(plan.contains("bbox=[-10.0, 35.0, 30.0, 60.0]"), "{plan}");
+
+        let batches = ctx.sql(sql).await.unwrap().collect().await.unwrap();
+        let names = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|name| name.unwrap().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Berlin", "Paris"]);
+    }
+
+    fn arrow_cast_display(batches: &[arrow_array::RecordBatch]) -> String {
+        datafusion::arrow::util::pretty::pretty_format_batches(batches)
+            .unwrap()
+            .to_string()
+    }
+}
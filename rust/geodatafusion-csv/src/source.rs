@@ -0,0 +1,344 @@
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::{BooleanArray, RecordBatch};
+use arrow_schema::{Schema, SchemaRef};
+use datafusion::arrow::compute::filter_record_batch;
+use datafusion::config::{ConfigOptions, CsvOptions};
+use datafusion::datasource::physical_plan::{
+    CsvSource, FileOpenFuture, FileOpener, FileScanConfig, FileSource,
+};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::physical_expr::projection::{ProjectionExpr, ProjectionExprs, Projector};
+use datafusion::physical_expr::utils::collect_columns;
+use datafusion::physical_expr::{PhysicalExpr, ScalarFunctionExpr};
+use datafusion::physical_plan::expressions::Column;
+use datafusion::physical_plan::filter_pushdown::{FilterPushdownPropagation, PushedDown};
+use datafusion::physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion::physical_plan::{ColumnarValue, DisplayFormatType};
+use datafusion_datasource::{PartitionedFile, TableSchema};
+use futures::{FutureExt, StreamExt};
+use geo_traits::{CoordTrait, RectTrait};
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_schema::CoordType;
+use geodatafusion::udf::native::bounding_box::util::{bounding_rect, total_bounds};
+use object_store::ObjectStore;
+
+use crate::geometry::{CsvGeometryOptions, csv_schema_from_table_schema};
+
+/// A [`FileSource`] reading CSV files and appending a GeoArrow geometry column.
+///
+/// Supports a bounding box prefilter from `ST_Intersects(geometry, <constant>)` predicates.
+#[derive(Clone, Debug)]
+pub struct GeoCsvSource {
+    pub(crate) inner: CsvSource,
+    table_schema: TableSchema,
+    geometry: CsvGeometryOptions,
+    coord_type: CoordType,
+    projection: ProjectionExprs,
+    bbox: Option<[f64; 4]>,
+}
+
+impl GeoCsvSource {
+    /// Creates a new [`GeoCsvSource`] for a table schema derived with [`CsvGeometryOptions`].
+    pub fn new(
+        table_schema: TableSchema,
+        geometry: CsvGeometryOptions,
+        coord_type: CoordType,
+    ) -> Self {
+        let csv_file_schema = csv_schema_from_table_schema(table_schema.file_schema());
+        let csv_table_schema = TableSchema::new(
+            Arc::new(csv_file_schema),
+            table_schema.table_partition_cols().clone(),
+        );
+        let projection = ProjectionExprs::from_indices(
+            &(0..table_schema.table_schema().fields().len()).collect::<Vec<_>>(),
+            table_schema.table_schema(),
+        );
+        Self {
+            inner: CsvSource::new(csv_table_schema),
+            table_schema,
+            geometry,
+            coord_type,
+            projection,
+            bbox: None,
+        }
+    }
+
+    /// Sets the CSV options of the inner [`CsvSource`]
+    pub fn with_csv_options(mut self, options: CsvOptions) -> Self {
+        self.inner = self.inner.with_csv_options(options);
+        self
+    }
+
+    /// The bounding box prefilter pushed down into this source, if any
+    pub fn bbox(&self) -> Option<[f64; 4]> {
+        self.bbox
+    }
+
+    /// The schema of the batches produced by the inner [`CsvSource`]: the CSV columns followed by
+    /// the partition columns.
+    fn csv_table_schema(&self) -> SchemaRef {
+        self.inner.table_schema().table_schema().clone()
+    }
+}
+
+/// Allows easy conversion from GeoCsvSource to Arc\<dyn FileSource\>;
+impl From<GeoCsvSource> for Arc<dyn FileSource> {
+    fn from(source: GeoCsvSource) -> Self {
+        Arc::new(source)
+    }
+}
+
+impl FileSource for GeoCsvSource {
+    fn create_file_opener(
+        &self,
+        object_store: Arc<dyn ObjectStore>,
+        base_config: &FileScanConfig,
+        partition: usize,
+    ) -> Result<Arc<dyn FileOpener>> {
+        let inner = self
+            .inner
+            .create_file_opener(object_store, base_config, partition)?;
+
+        // The conversion is evaluated over the CSV columns, then the partition columns are
+        // appended after the geometry column to match the table schema.
+        let csv_table_schema = self.csv_table_schema();
+        let csv_file_schema = self.inner.table_schema().file_schema();
+        let num_csv_columns = csv_file_schema.fields().len();
+        let mut conversion = self
+            .geometry
+            .conversion(csv_file_schema, self.coord_type)?
+            .as_ref()
+            .to_vec();
+        for (idx, field) in csv_table_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .skip(num_csv_columns)
+        {
+            conversion.push(ProjectionExpr::new(
+                Arc::new(Column::new(field.name(), idx)),
+                field.name(),
+            ));
+        }
+        let conversion = ProjectionExprs::new(conversion).make_projector(&csv_table_schema)?;
+        let projection = self
+            .projection
+            .make_projector(self.table_schema.table_schema())?;
+
+        Ok(Arc::new(GeoCsvOpener {
+            inner,
+            conversion,
+            projection,
+            geometry_index: num_csv_columns,
+            bbox: self.bbox,
+        }))
+    }
+
+    fn table_schema(&self) -> &TableSchema {
+        &self.table_schema
+    }
+
+    fn with_batch_size(&self, batch_size: usize) -> Arc<dyn FileSource> {
+        let inner = self.inner.with_batch_size(batch_size);
+        // safe to do unwrap here because the inner type is CsvSource for sure
+        let inner = inner.downcast_ref::<CsvSource>().unwrap();
+        Arc::new(Self {
+            inner: inner.clone(),
+            ..self.clone()
+        })
+    }
+
+    fn metrics(&self) -> &ExecutionPlanMetricsSet {
+        self.inner.metrics()
+    }
+
+    fn file_type(&self) -> &str {
+        self.inner.file_type()
+    }
+
+    fn fmt_extra(&self, t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        self.inner.fmt_extra(t, f)?;
+        match (t, self.bbox) {
+            (DisplayFormatType::Default | DisplayFormatType::Verbose, Some(bbox)) => {
+                write!(f, ", bbox={bbox:?}")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn try_pushdown_filters(
+        &self,
+        filters: Vec<Arc<dyn PhysicalExpr>>,
+        _config: &ConfigOptions,
+    ) -> Result<FilterPushdownPropagation<Arc<dyn FileSource>>> {
+        // The geometry column follows the CSV columns in the table schema
+        let geometry_index = self.inner.table_schema().file_schema().fields().len();
+        let mut bbox = self.bbox;
+        for filter in &filters {
+            if let Some(filter_bbox) = extract_bbox(filter, geometry_index)? {
+                bbox = Some(match bbox {
+                    Some(bbox) => intersect_bbox(bbox, filter_bbox),
+                    None => filter_bbox,
+                });
+            }
+        }
+
+        // The bounding box is only a prefilter, so the filters are still evaluated above the scan
+        let propagation = FilterPushdownPropagation::with_parent_pushdown_result(vec![
+                PushedDown::No;
+                filters.len()
+            ]);
+        if bbox == self.bbox {
+            return Ok(propagation);
+        }
+        Ok(propagation.with_updated_node(Arc::new(Self {
+            bbox,
+            ..self.clone()
+        }) as Arc<dyn FileSource>))
+    }
+
+    fn try_pushdown_projection(
+        &self,
+        projection: &ProjectionExprs,
+    ) -> Result<Option<Arc<dyn FileSource>>> {
+        Ok(Some(Arc::new(Self {
+            projection: self.projection.try_merge(projection)?,
+            ..self.clone()
+        })))
+    }
+
+    fn projection(&self) -> Option<&ProjectionExprs> {
+        Some(&self.projection)
+    }
+
+    fn supports_repartitioning(&self) -> bool {
+        self.inner.supports_repartitioning()
+    }
+}
+
+/// Opens CSV files with an inner opener and converts the batches to the table schema.
+struct GeoCsvOpener {
+    inner: Arc<dyn FileOpener>,
+    conversion: Projector,
+    projection: Projector,
+    geometry_index: usize,
+    bbox: Option<[f64; 4]>,
+}
+
+impl FileOpener for GeoCsvOpener {
+    fn open(&self, partitioned_file: PartitionedFile) -> Result<FileOpenFuture> {
+        let inner = self.inner.open(partitioned_file)?;
+        let conversion = self.conversion.clone();
+        let projection = self.projection.clone();
+        let geometry_index = self.geometry_index;
+        let bbox = self.bbox;
+
+        Ok(async move {
+            let stream = inner.await?;
+            let stream = stream.map(move |batch| {
+                let batch = conversion.project_batch(&batch?)?;
+                let batch = match bbox {
+                    Some(bbox) => filter_by_bbox(&batch, geometry_index, bbox)?,
+                    None => batch,
+                };
+                projection.project_batch(&batch)
+            });
+            Ok(stream.boxed())
+        }
+        .boxed())
+    }
+}
+
+/// Keep only the rows whose geometry bounding box intersects `bbox`.
+fn filter_by_bbox(
+    batch: &RecordBatch,
+    geometry_index: usize,
+    [minx, miny, maxx, maxy]: [f64; 4],
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let field = schema.field(geometry_index);
+    let geo_array = from_arrow_array(batch.column(geometry_index), field)
+        .map_err(|err| DataFusionError::External(Box::new(err)))?;
+    let rects = bounding_rect(geo_array.as_ref(), false)
+        .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+    let predicate = rects
+        .iter()
+        .map(|rect| {
+            rect.transpose().map(|rect| {
+                rect.map(|rect| {
+                    let (min, max) = (rect.min(), rect.max());
+                    min.x() <= maxx && max.x() >= minx && min.y() <= maxy && max.y() >= miny
+                })
+            })
+        })
+        .collect::<std::result::Result<BooleanArray, _>>()
+        .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+    Ok(filter_record_batch(batch, &predicate)?)
+}
+
+/// Extract the bounding box of a constant geometry from an
+/// `ST_Intersects(geometry, <constant>)` predicate.
+fn extract_bbox(filter: &Arc<dyn PhysicalExpr>, geometry_index: usize) -> Result<Option<[f64; 4]>> {
+    let Some(func) = filter.downcast_ref::<ScalarFunctionExpr>() else {
+        return Ok(None);
+    };
+    if !func.name().eq_ignore_ascii_case("st_intersects") {
+        return Ok(None);
+    }
+
+    let [left, right] = func.args() else {
+        return Ok(None);
+    };
+    let constant = match (
+        is_geometry_column(left, geometry_index),
+        is_geometry_column(right, geometry_index),
+    ) {
+        (true, false) => right,
+        (false, true) => left,
+        _ => return Ok(None),
+    };
+    if !collect_columns(constant).is_empty() {
+        return Ok(None);
+    }
+
+    let empty_batch = RecordBatch::new_empty(Arc::new(Schema::empty()));
+    let ColumnarValue::Scalar(scalar) = constant.evaluate(&empty_batch)? else {
+        return Ok(None);
+    };
+    let field = constant.return_field(&Schema::empty())?;
+    let array = scalar.to_array()?;
+    let Ok(geo_array) = from_arrow_array(&array, &field) else {
+        return Ok(None);
+    };
+
+    let bounds =
+        total_bounds(geo_array.as_ref()).map_err(|err| DataFusionError::External(Box::new(err)))?;
+    if bounds.minx() > bounds.maxx() || bounds.miny() > bounds.maxy() {
+        return Ok(None);
+    }
+    Ok(Some([
+        bounds.minx(),
+        bounds.miny(),
+        bounds.maxx(),
+        bounds.maxy(),
+    ]))
+}
+
+fn is_geometry_column(expr: &Arc<dyn PhysicalExpr>, geometry_index: usize) -> bool {
+    expr.downcast_ref::<Column>()
+        .is_some_and(|column| column.index() == geometry_index)
+}
+
+fn intersect_bbox(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    [
+        a[0].max(b[0]),
+        a[1].max(b[1]),
+        a[2].min(b[2]),
+        a[3].min(b[3]),
+    ]
+}
@@ -0,0 +1,307 @@
+//! Aggregate functions producing FlatGeobuf.
+
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, RecordBatch, StructArray, new_empty_array};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema};
+use datafusion::arrow::compute::concat;
+use datafusion::common::utils::SingleRowListArrayBuilder;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, TypeSignature, Volatility,
+};
+use datafusion::physical_expr::expressions::Literal;
+use datafusion::scalar::ScalarValue;
+use geoarrow_flatgeobuf::writer::{FlatGeobufWriter, FlatGeobufWriterOptions};
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType};
+
+/// Aggregate function serializing a group of rows into an in-memory FlatGeobuf file.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsFlatGeobuf {
+    signature: Signature,
+}
+
+impl AsFlatGeobuf {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=3).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsFlatGeobuf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for AsFlatGeobuf {
+    fn name(&self) -> &str {
+        "st_asflatgeobuf"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![
+            Field::new_list(
+                format_state_name(args.name, "rows"),
+                Field::new_list_field(args.input_fields[0].data_type().clone(), true),
+                true,
+            )
+            .into(),
+        ])
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let DataType::Struct(fields) = acc_args.expr_fields[0].data_type() else {
+            return Err(DataFusionError::Plan(
+                "ST_AsFlatGeobuf expects a struct of the row to encode as its first argument"
+                    .to_string(),
+            ));
+        };
+
+        let index = matches!(
+            literal_arg(&acc_args, 1)?,
+            Some(ScalarValue::Boolean(Some(true)))
+        );
+        let geom_name = literal_arg(&acc_args, 2)?
+            .and_then(|scalar| scalar.try_as_str().flatten().map(|s| s.to_string()));
+
+        Ok(Box::new(AsFlatGeobufAccumulator {
+            schema: Arc::new(Schema::new(output_fields(fields, geom_name.as_deref())?)),
+            index,
+            rows_type: acc_args.expr_fields[0].data_type().clone(),
+            rows: vec![],
+        }))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Aggregate function returning a FlatGeobuf representation of a set of rows. The rows are passed as a struct, for example with named_struct. The struct must contain exactly one geometry column, and its other columns are written as feature properties. Optionally, a spatial index is included in the output.",
+                "ST_AsFlatGeobuf(row, index, geom_name)",
+            )
+            .with_argument("row", "struct")
+            .with_argument("index", "boolean, whether to include a spatial index, default false")
+            .with_argument("geom_name", "text, name of the geometry column, required if the row has several")
+            .build()
+        }))
+    }
+}
+
+/// The value of a literal argument of the aggregate, if it was passed.
+fn literal_arg(acc_args: &AccumulatorArgs, index: usize) -> Result<Option<ScalarValue>> {
+    let Some(expr) = acc_args.exprs.get(index) else {
+        return Ok(None);
+    };
+    let literal = expr.downcast_ref::<Literal>().ok_or_else(|| {
+        DataFusionError::Plan(format!(
+            "ST_AsFlatGeobuf argument {} must be a literal",
+            index + 1
+        ))
+    })?;
+    Ok((!literal.value().is_null()).then(|| literal.value().clone()))
+}
+
+/// Fields of the FlatGeobuf schema, with GeoArrow extension metadata on the geometry column.
+///
+/// Struct constructors like `named_struct` drop the extension metadata of their members, so a
+/// column with the layout of a GeoArrow geometry array, or the column named by `geom_name`, is
+/// tagged as a geometry again.
+fn output_fields(fields: &Fields, geom_name: Option<&str>) -> Result<Vec<FieldRef>> {
+    let has_extension =
+        |field: &Field| matches!(GeoArrowType::from_extension_field(field), Ok(Some(_)));
+    let geometry_index = match geom_name {
+        Some(geom_name) => fields.find(geom_name).map(|(index, _)| index),
+        None => {
+            let candidates = fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| has_extension(field) || geometry_union_field(field).is_some())
+                .collect::<Vec<_>>();
+            if candidates.len() > 1 {
+                let names = candidates
+                    .iter()
+                    .map(|(_, field)| field.name().as_str())
+                    .collect::<Vec<_>>();
+                return Err(DataFusionError::Plan(format!(
+                    "ST_AsFlatGeobuf found several geometry columns ({}), pass geom_name to choose one",
+                    names.join(", ")
+                )));
+            }
+            candidates.first().map(|(index, _)| *index)
+        }
+    }
+    .ok_or_else(|| {
+        DataFusionError::Plan("ST_AsFlatGeobuf could not find a geometry column".to_string())
+    })?;
+
+    let mut output = fields.iter().cloned().collect::<Vec<_>>();
+    let field = &fields[geometry_index];
+    if !has_extension(field) {
+        let geometry_field = match geometry_union_field(field) {
+            Some(geometry_field) => geometry_field,
+            None => GeoArrowType::from_arrow_field(field)
+                .map_err(|err| DataFusionError::External(Box::new(err)))?
+                .to_field(field.name(), true),
+        };
+        output[geometry_index] = Arc::new(geometry_field);
+    }
+    Ok(output)
+}
+
+fn geometry_union_field(field: &Field) -> Option<Field> {
+    [CoordType::Separated, CoordType::Interleaved]
+        .into_iter()
+        .map(|coord_type| GeometryType::new(Default::default()).with_coord_type(coord_type))
+        .find(|geometry_type| field.data_type() == &geometry_type.data_type())
+        .map(|geometry_type| geometry_type.to_field(field.name(), true))
+}
+
+#[derive(Debug)]
+struct AsFlatGeobufAccumulator {
+    schema: Arc<Schema>,
+    index: bool,
+    rows_type: DataType,
+    rows: Vec<StructArray>,
+}
+
+impl Accumulator for AsFlatGeobufAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let rows = if self.rows.is_empty() {
+            new_empty_array(&self.rows_type)
+        } else {
+            let rows = self
+                .rows
+                .iter()
+                .map(|rows| rows as &dyn Array)
+                .collect::<Vec<_>>();
+            concat(&rows)?
+        };
+        Ok(vec![
+            SingleRowListArrayBuilder::new(rows).build_list_scalar(),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let options =
+            FlatGeobufWriterOptions::new("default".to_string()).with_write_index(self.index);
+        let mut fgb_writer = FlatGeobufWriter::try_new(vec![], self.schema.clone(), options)
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+
+        for rows in &self.rows {
+            // Null rows are skipped, as FlatGeobuf has no notion of a null feature
+            let rows = match rows.nulls() {
+                Some(nulls) => datafusion::arrow::compute::filter(
+                    rows,
+                    &arrow_array::BooleanArray::new(nulls.inner().clone(), None),
+                )?
+                .as_struct()
+                .clone(),
+                None => rows.clone(),
+            };
+            let batch = RecordBatch::try_new(self.schema.clone(), rows.columns().to_vec())?;
+            fgb_writer
+                .write(&batch)
+                .map_err(|err| DataFusionError::External(Box::new(err)))?;
+        }
+
+        let fgb = fgb_writer
+            .finish()
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+        Ok(ScalarValue::Binary(Some(fgb)))
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.rows.push(values[0].as_struct().clone());
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        for rows in states[0].as_list::<i32>().iter().flatten() {
+            if !rows.is_empty() {
+                self.rows.push(rows.as_struct().clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self
+                .rows
+                .iter()
+                .map(|rows| rows.get_array_memory_size())
+                .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use datafusion::prelude::SessionContext;
+    use geodatafusion::udf::native::io::GeomFromText;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_as_flatgeobuf() {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(AsFlatGeobuf::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql("SELECT region, ST_AsFlatGeobuf(named_struct('name', name, 'geometry', ST_GeomFromText(wkt)), true) AS fgb
+                FROM (VALUES ('a', 'x', 'POINT(1 1)'), ('a', 'y', 'POINT(2 2)'), ('b', 'z', 'POINT(3 3)')) AS t(region, name, wkt)
+                GROUP BY region
+                ORDER BY region;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let fgb = batches[0].column(1).as_binary::<i32>();
+
+        let fgb_reader = flatgeobuf::FgbReader::open(Cursor::new(fgb.value(0))).unwrap();
+        assert_eq!(fgb_reader.header().features_count(), 2);
+        assert!(fgb_reader.header().index_node_size() > 0);
+        let fgb_reader = flatgeobuf::FgbReader::open(Cursor::new(fgb.value(1))).unwrap();
+        assert_eq!(fgb_reader.header().features_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_as_flatgeobuf_several_geometry_columns() {
+        let ctx = SessionContext::new();
+        crate::register(&ctx);
+        ctx.register_udf(GeomFromText::default().into());
+
+        let err = ctx
+            .sql("SELECT ST_AsFlatGeobuf(named_struct('a', ST_GeomFromText(wkt), 'b', ST_GeomFromText(wkt)))
+                FROM (VALUES ('POINT(1 1)')) AS t(wkt);")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("several geometry columns (a, b)"),
+            "unexpected error: {err}"
+        );
+    }
+}
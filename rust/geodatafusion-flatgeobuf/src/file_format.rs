@@ -22,8 +22,8 @@ use datafusion_datasource::TableSchema;
 use datafusion_datasource::display::FileGroupDisplay;
 use datafusion_datasource::file_sink_config::{FileSink, FileSinkConfig};
 use datafusion_datasource::sink::{DataSink, DataSinkExec};
-use datafusion_datasource::write::ObjectWriterBuilder;
 use datafusion_datasource::write::demux::DemuxedStreamReceiver;
+use datafusion_datasource::write::{ObjectWriterBuilder, get_writer_schema};
 use geoarrow_flatgeobuf::reader::FlatGeobufHeaderExt;
 use geoarrow_flatgeobuf::reader::schema::FlatGeobufSchemaScanner;
 use geoarrow_flatgeobuf::writer::{FlatGeobufWriter, FlatGeobufWriterOptions};
@@ -271,6 +271,8 @@ impl FileSink for FlatGeobufSink {
         object_store: Arc<dyn ObjectStore>,
     ) -> Result<u64> {
         let mut total_rows: u64 = 0;
+        // Hive partition columns are encoded in the file paths instead of the files
+        let schema = get_writer_schema(&self.config);
         while let Some((path, mut rb_rx)) = file_stream_rx.recv().await {
             // We create a tempfile on disk because the FlatGeobufWriter is sync only. So we write
             // to a temp file and then upload it to the object store.
@@ -283,12 +285,8 @@ impl FileSink for FlatGeobufSink {
                 .map(|s| s.to_string().rsplit_once(".").unwrap().0.to_string())
                 .unwrap_or_else(|| "file".to_string());
             let options = FlatGeobufWriterOptions::new(name);
-            let mut fgb_writer = FlatGeobufWriter::try_new(
-                output_file,
-                self.config.output_schema().clone(),
-                options,
-            )
-            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+            let mut fgb_writer = FlatGeobufWriter::try_new(output_file, schema.clone(), options)
+                .map_err(|err| DataFusionError::External(Box::new(err)))?;
 
             // For each record batch received, write it to the FlatGeobufWriter
             while let Some(batch) = rb_rx.recv().await {
@@ -5,12 +5,19 @@
     html_favicon_url = "https://github.com/geoarrow.png?size=32"
 )]
 
+pub mod aggregate;
 pub mod file_format;
 pub mod source;
 mod utils;
 
+pub use aggregate::AsFlatGeobuf;
 pub use file_format::{FlatGeobufFileFactory, FlatGeobufFormat, FlatGeobufFormatFactory};
 
+/// Register all functions defined in geodatafusion-flatgeobuf
+pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udaf(AsFlatGeobuf::default().into());
+}
+
 #[cfg(test)]
 mod tests {
     use std::env::temp_dir;
@@ -36,6 +43,7 @@ mod tests {
     use geodatafusion::udf::geo::relationships::Intersects;
     use geodatafusion::udf::native::bounding_box::Box2D;
     use geodatafusion::udf::native::io::GeomFromText;
+    use geodatafusion::udf::quadkey::{TileX, TileY};
     use wkt::wkt;
 
     use super::*;
@@ -289,4 +297,48 @@ mod tests {
 
         std::fs::remove_file(&file_path).unwrap();
     }
+
+    #[tokio::test]
+    async fn test_write_flatgeobuf_sink_partitioned_by_tile() {
+        let file_format = Arc::new(FlatGeobufFileFactory::default());
+        let state = SessionStateBuilder::new()
+            .with_file_formats(vec![file_format])
+            .build();
+        let ctx = SessionContext::new_with_state(state);
+        ctx.register_udf(TileX::default().into());
+        ctx.register_udf(TileY::default().into());
+
+        let (batches, schema) = sample_table();
+        let mem_table = Arc::new(MemTable::try_new(schema.clone(), vec![batches]).unwrap());
+        ctx.register_table("mem_table", mem_table).unwrap();
+
+        let dir_path = temp_dir().join("test_fgb_sink_partitioned");
+        let _ = std::fs::remove_dir_all(&dir_path);
+
+        ctx.sql(&format!(
+            "COPY (SELECT *, 8 AS z, tile_x(geometry, 8) AS x, tile_y(geometry, 8) AS y FROM mem_table)
+            TO '{}/' STORED AS FGB PARTITIONED BY (z, x, y);",
+            dir_path.display(),
+        ))
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+        for tile_dir in ["z=8/x=128/y=126", "z=8/x=129/y=125"] {
+            let files = std::fs::read_dir(dir_path.join(tile_dir))
+                .unwrap()
+                .collect::<Vec<_>>();
+            assert_eq!(files.len(), 1);
+            let file = File::open(files[0].as_ref().unwrap().path()).unwrap();
+            let fgb_reader = flatgeobuf::FgbReader::open(BufReader::new(file)).unwrap();
+            assert_eq!(fgb_reader.header().features_count(), 1);
+            let columns = fgb_reader.header().columns().unwrap();
+            assert_eq!(columns.len(), 1);
+            assert_eq!(columns.get(0).name(), "id");
+        }
+
+        std::fs::remove_dir_all(&dir_path).unwrap();
+    }
 }
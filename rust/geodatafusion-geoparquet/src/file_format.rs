@@ -1,26 +1,38 @@
 use std::collections::HashMap;
+use std::fmt::{self, Formatter};
 use std::sync::Arc;
 
 use arrow_schema::SchemaRef;
 use async_trait::async_trait;
 use datafusion::catalog::Session;
+use datafusion::common::runtime::SpawnedTask;
 use datafusion::common::{GetExt, Statistics};
 use datafusion::config::{ConfigField, ConfigFileType, TableParquetOptions};
 use datafusion::datasource::file_format::FileFormat;
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
 use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig, FileSource};
-use datafusion::error::Result;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
 use datafusion::physical_expr::LexRequirement;
-use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan};
 use datafusion_datasource::TableSchema;
+use datafusion_datasource::display::FileGroupDisplay;
 use datafusion_datasource::file_format::FileFormatFactory;
 use datafusion_datasource::file_scan_config::FileScanConfigBuilder;
+use datafusion_datasource::file_sink_config::FileSink;
+use datafusion_datasource::sink::{DataSink, DataSinkExec};
+use datafusion_datasource::write::demux::DemuxedStreamReceiver;
+use datafusion_datasource::write::get_writer_schema;
 use datafusion_datasource_parquet::ParquetFormat;
 use datafusion_datasource_parquet::source::ParquetSource;
 use geoarrow_schema::CoordType;
 use geoparquet::metadata::GeoParquetMetadata;
 use geoparquet::reader::infer_geoarrow_schema;
+use geoparquet::writer::{GeoParquetRecordBatchEncoder, GeoParquetWriterOptions};
 use object_store::{ObjectMeta, ObjectStore};
+use parquet::arrow::AsyncArrowWriter;
+use parquet::arrow::async_writer::ParquetObjectWriter;
+use parquet::file::properties::WriterPropertiesBuilder;
 
 use crate::source::GeoParquetSource;
 
@@ -166,12 +178,13 @@ impl FileFormat for GeoParquetFormat {
 
     async fn create_writer_physical_plan(
         &self,
-        _input: Arc<dyn ExecutionPlan>,
+        input: Arc<dyn ExecutionPlan>,
         _state: &dyn Session,
-        _conf: FileSinkConfig,
-        _order_requirements: Option<LexRequirement>,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        todo!("writing not implemented for GeoParquet yet")
+        let sink = Arc::new(GeoParquetSink::new(conf, self.inner.options().clone()));
+        Ok(Arc::new(DataSinkExec::new(input, sink, order_requirements)))
     }
 
     fn file_source(&self, table_schema: TableSchema) -> Arc<dyn FileSource> {
@@ -183,3 +196,106 @@ impl FileFormat for GeoParquetFormat {
         })
     }
 }
+
+/// Writes GeoArrow record batches to GeoParquet files, with geometries encoded as WKB.
+#[derive(Debug)]
+pub struct GeoParquetSink {
+    config: FileSinkConfig,
+    parquet_options: TableParquetOptions,
+}
+
+impl GeoParquetSink {
+    pub fn new(config: FileSinkConfig, parquet_options: TableParquetOptions) -> Self {
+        Self {
+            config,
+            parquet_options,
+        }
+    }
+}
+
+impl DisplayAs for GeoParquetSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "GeoParquetSink(file_groups=")?;
+                FileGroupDisplay(&self.config.file_group).fmt_as(t, f)?;
+                write!(f, ")")
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(f, "format: geoparquet")?;
+                write!(f, "file={}", self.config.original_url)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl FileSink for GeoParquetSink {
+    fn config(&self) -> &FileSinkConfig {
+        &self.config
+    }
+
+    async fn spawn_writer_tasks_and_join(
+        &self,
+        _context: &Arc<TaskContext>,
+        demux_task: SpawnedTask<Result<()>>,
+        mut file_stream_rx: DemuxedStreamReceiver,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Result<u64> {
+        let mut total_rows: u64 = 0;
+        // Hive partition columns are encoded in the file paths instead of the files
+        let schema = get_writer_schema(&self.config);
+        // The Arrow writer embeds the Arrow schema itself, so DataFusion must not require it
+        let mut parquet_options = self.parquet_options.clone();
+        parquet_options.global.skip_arrow_metadata = true;
+        let properties = WriterPropertiesBuilder::try_from(&parquet_options)?.build();
+        while let Some((path, mut rb_rx)) = file_stream_rx.recv().await {
+            // The writer options are not Send, so they must not be held across an await
+            let mut encoder =
+                GeoParquetRecordBatchEncoder::try_new(&schema, &GeoParquetWriterOptions::default())
+                    .map_err(|err| DataFusionError::External(Box::new(err)))?;
+            let object_writer = ParquetObjectWriter::new(object_store.clone(), path);
+            let mut parquet_writer = AsyncArrowWriter::try_new(
+                object_writer,
+                encoder.target_schema(),
+                Some(properties.clone()),
+            )?;
+
+            // For each record batch received, encode its geometries and write it
+            while let Some(batch) = rb_rx.recv().await {
+                total_rows += batch.num_rows() as u64;
+                let batch = encoder
+                    .encode_record_batch(&batch)
+                    .map_err(|err| DataFusionError::External(Box::new(err)))?;
+                parquet_writer.write(&batch).await?;
+            }
+
+            // The GeoParquet metadata includes the bounding box of all the batches in the file
+            let geo_metadata = encoder
+                .into_keyvalue()
+                .map_err(|err| DataFusionError::External(Box::new(err)))?;
+            parquet_writer.append_key_value_metadata(geo_metadata);
+            parquet_writer.close().await?;
+        }
+        demux_task
+            .join()
+            .await
+            .map_err(|e| DataFusionError::Execution(e.to_string()))??;
+        Ok(total_rows)
+    }
+}
+
+#[async_trait]
+impl DataSink for GeoParquetSink {
+    fn schema(&self) -> &SchemaRef {
+        self.config.output_schema()
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        FileSink::write_all(self, data, context).await
+    }
+}
@@ -17,6 +17,8 @@ mod tests {
     use datafusion::execution::SessionStateBuilder;
     use datafusion::prelude::SessionContext;
     use geodatafusion::udf::geo::processing::Centroid;
+    use geodatafusion::udf::geohash::GeoHash;
+    use geodatafusion::udf::native::constructors::Point;
 
     use crate::file_format::GeoParquetFormatFactory;
 
@@ -52,4 +54,53 @@ mod tests {
         let field = schema.field_with_unqualified_name("geometry").unwrap();
         assert_eq!(field.extension_type_name().unwrap(), "geoarrow.wkb");
     }
+
+    #[tokio::test]
+    async fn test_write_geoparquet_partitioned_by_geohash() {
+        let file_format = Arc::new(GeoParquetFormatFactory::default());
+        let state = SessionStateBuilder::new()
+            .with_file_formats(vec![file_format])
+            .build();
+        let ctx = SessionContext::new_with_state(state).enable_url_table();
+        ctx.register_udf(Point::default().into());
+        ctx.register_udf(GeoHash.into());
+
+        ctx.sql(
+            "CREATE TABLE points AS SELECT id, ST_Point(x, y) AS geometry
+            FROM (VALUES (1, 1.0, 2.0), (2, 2.0, 3.0), (3, -120.0, 40.0)) AS t(id, x, y);",
+        )
+        .await
+        .unwrap();
+
+        let dir_path = std::env::temp_dir().join("test_geoparquet_sink_partitioned");
+        let _ = std::fs::remove_dir_all(&dir_path);
+
+        ctx.sql(&format!(
+            "COPY (SELECT *, ST_GeoHash(geometry, 1) AS geohash FROM points)
+            TO '{}/' STORED AS PARQUET PARTITIONED BY (geohash);",
+            dir_path.display(),
+        ))
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+        let df = ctx
+            .sql(&format!(
+                "SELECT id, geometry FROM '{}/geohash=s/' ORDER BY id",
+                dir_path.display()
+            ))
+            .await
+            .unwrap();
+        let field = df.schema().field_with_unqualified_name("geometry").unwrap();
+        assert_eq!(field.extension_type_name().unwrap(), "geoarrow.wkb");
+        let batches = df.collect().await.unwrap();
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, 2);
+        assert_eq!(batches[0].num_columns(), 2);
+        assert!(dir_path.join("geohash=9").is_dir());
+
+        std::fs::remove_dir_all(&dir_path).unwrap();
+    }
 }
@@ -0,0 +1,71 @@
+//! Conversions between PostGIS-style integer SRIDs and GeoArrow [`Crs`] metadata.
+
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::ReturnFieldArgs;
+use datafusion::scalar::ScalarValue;
+use geoarrow_schema::{Crs, CrsType};
+
+/// Construct a [`Crs`] for an EPSG SRID.
+///
+/// SRID 0 is the PostGIS SRID of an unknown spatial reference, and has no CRS.
+pub(crate) fn crs_from_srid(srid: i32) -> Crs {
+    if srid == 0 {
+        Crs::default()
+    } else {
+        Crs::from_authority_code(format!("EPSG:{srid}"))
+    }
+}
+
+/// The optional SRID argument of a constructor at `index`, such as the `srid` of
+/// `ST_GeomFromText(text, srid)`.
+///
+/// The CRS is part of the output field, so the SRID must be a literal known when planning. A NULL
+/// SRID is treated as absent.
+pub(crate) fn srid_arg(
+    function: &str,
+    args: &ReturnFieldArgs,
+    index: usize,
+) -> Result<Option<i32>> {
+    match args.scalar_arguments.get(index) {
+        None => Ok(None),
+        Some(Some(scalar)) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(srid)) => i32::try_from(srid).map(Some).map_err(|_| {
+                DataFusionError::Plan(format!("{function} SRID {srid} is out of range"))
+            }),
+            _ => Ok(None),
+        },
+        Some(None) => Err(DataFusionError::Plan(format!(
+            "{function} requires the SRID to be a literal integer"
+        ))),
+    }
+}
+
+/// Extract an integer SRID from a [`Crs`], if it refers to an EPSG code.
+///
+/// This understands `EPSG:xxxx` authority codes, opaque SRIDs and PROJJSON with an EPSG `id`.
+pub(crate) fn srid_from_crs(crs: &Crs) -> Option<i32> {
+    let value = crs.crs_value()?;
+    match crs.crs_type() {
+        Some(CrsType::AuthorityCode) | None => {
+            let (authority, code) = value.as_str()?.split_once(':')?;
+            if authority.eq_ignore_ascii_case("EPSG") {
+                code.parse().ok()
+            } else {
+                None
+            }
+        }
+        Some(CrsType::Srid) => value.as_str()?.parse().ok(),
+        Some(CrsType::Projjson) => {
+            let id = value.get("id")?;
+            if id.get("authority")?.as_str()? != "EPSG" {
+                return None;
+            }
+            let code = id.get("code")?;
+            code.as_i64()
+                .and_then(|code| i32::try_from(code).ok())
+                .or_else(|| code.as_str()?.parse().ok())
+        }
+        _ => None,
+    }
+}
@@ -25,6 +25,12 @@ pub(crate) enum GeoDataFusionError {
 
     #[error(transparent)]
     GeoHash(#[from] geohash::GeohashError),
+
+    #[error(transparent)]
+    H3CellIndex(#[from] h3o::error::InvalidCellIndex),
+
+    #[error(transparent)]
+    H3LatLng(#[from] h3o::error::InvalidLatLng),
 }
 
 /// Crate-specific result type.
@@ -39,6 +45,8 @@ impl From<GeoDataFusionError> for DataFusionError {
             #[cfg(feature = "geos")]
             GeoDataFusionError::Geos(err) => DataFusionError::External(Box::new(err)),
             GeoDataFusionError::GeoHash(err) => DataFusionError::External(Box::new(err)),
+            GeoDataFusionError::H3CellIndex(err) => DataFusionError::External(Box::new(err)),
+            GeoDataFusionError::H3LatLng(err) => DataFusionError::External(Box::new(err)),
         }
     }
 }
@@ -6,6 +6,7 @@
     html_favicon_url = "https://github.com/geoarrow.png?size=32"
 )]
 
+pub(crate) mod crs;
 pub(crate) mod data_types;
 pub(crate) mod error;
 pub mod udf;
@@ -25,11 +26,19 @@ pub fn register(session_context: &datafusion::prelude::SessionContext) {
 
     crate::udf::geohash::register(session_context);
 
+    crate::udf::h3::register(session_context);
+
+    crate::udf::quadkey::register(session_context);
+
+    crate::udf::s2::register(session_context);
+
     crate::udf::native::accessors::register(session_context);
 
     crate::udf::native::bounding_box::register(session_context);
 
     crate::udf::native::constructors::register(session_context);
 
+    crate::udf::native::editors::register(session_context);
+
     crate::udf::native::io::register(session_context);
 }
@@ -0,0 +1,336 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo::orient::Direction;
+use geo::{Area, BooleanOps, Contains, MapCoords, Orient, RemoveRepeatedPoints, Simplify, coord};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeometryType};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::bounding_box::util::total_bounds;
+use crate::udf::native::io::util::scalar_arg;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsMVTGeom {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl AsMVTGeom {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                (2..=5).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for AsMVTGeom {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsMVTGeom {
+    fn name(&self) -> &str {
+        "st_asmvtgeom"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        // Tile coordinates have no CRS
+        let output_type = GeometryType::new(Default::default()).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_mvt_geom_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Transforms a geometry into the coordinate space of a MVT tile, clipping it to the tile bounds if required. The geometry must be in the coordinate system of the bounds, usually from ST_TileEnvelope. The result is simplified to half a tile unit, snapped to the integer tile grid and oriented as required by the MVT specification. Geometries which collapse or fall outside of the clipping area return NULL.",
+                "ST_AsMVTGeom(geom, bounds, extent, buffer, clip_geom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("bounds", "geometry or box2d")
+            .with_argument("extent", "integer, default 4096")
+            .with_argument("buffer", "integer, default 256")
+            .with_argument("clip_geom", "boolean, default true")
+            .with_related_udf("st_tileenvelope")
+            .with_related_udf("st_asmvt")
+            .build()
+        }))
+    }
+}
+
+/// The affine transformation and clipping area from geometry to tile coordinates.
+struct TileTransform {
+    bounds: [f64; 4],
+    extent: f64,
+    clip: Option<geo::Rect>,
+}
+
+fn as_mvt_geom_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let bounds = match &args.args[1] {
+        ColumnarValue::Scalar(scalar) => {
+            let array = scalar.to_array()?;
+            let bounds = total_bounds(from_arrow_array(&array, &args.arg_fields[1])?.as_ref())?;
+            [bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()]
+        }
+        ColumnarValue::Array(_) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized tile bounds not yet implemented".to_string(),
+            )
+            .into());
+        }
+    };
+    if !(bounds[2] > bounds[0] && bounds[3] > bounds[1]) {
+        return Err(DataFusionError::Execution(
+            "ST_AsMVTGeom bounds must have a non-zero width and height".to_string(),
+        )
+        .into());
+    }
+
+    let extent = scalar_arg("ST_AsMVTGeom", &args, 2, &DataType::Int64)?
+        .and_then(|v| match v {
+            ScalarValue::Int64(v) => v,
+            _ => None,
+        })
+        .unwrap_or(4096);
+    if extent <= 0 {
+        return Err(DataFusionError::Execution(
+            "ST_AsMVTGeom extent must be greater than 0".to_string(),
+        )
+        .into());
+    }
+    let buffer = scalar_arg("ST_AsMVTGeom", &args, 3, &DataType::Int64)?
+        .and_then(|v| match v {
+            ScalarValue::Int64(v) => v,
+            _ => None,
+        })
+        .unwrap_or(256);
+    let clip_geom = !matches!(
+        scalar_arg("ST_AsMVTGeom", &args, 4, &DataType::Boolean)?,
+        Some(ScalarValue::Boolean(Some(false)))
+    );
+
+    let extent = extent as f64;
+    let buffer = buffer as f64;
+    let transform = TileTransform {
+        bounds,
+        extent,
+        clip: clip_geom.then(|| {
+            geo::Rect::new(
+                coord! { x: -buffer, y: -buffer },
+                coord! { x: extent + buffer, y: extent + buffer },
+            )
+        }),
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let typ = args.return_field.extension_type::<GeometryType>();
+    let result = as_mvt_geom(&geo_array, typ, &transform)?;
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+fn as_mvt_geom(
+    array: &dyn GeoArrowArray,
+    typ: GeometryType,
+    transform: &TileTransform,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    downcast_geoarrow_array!(array, _as_mvt_geom_impl, typ, transform)
+}
+
+fn _as_mvt_geom_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: GeometryType,
+    transform: &TileTransform,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    let mut builder = GeometryBuilder::new(typ);
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geometry_to_geo(&geom?)?;
+            builder.push_geometry(transform.apply(geom).as_ref())?;
+        } else {
+            builder.push_null();
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+impl TileTransform {
+    fn to_tile(&self, coord: geo::Coord) -> geo::Coord {
+        let [xmin, ymin, xmax, ymax] = self.bounds;
+        // The tile Y axis points down
+        coord! {
+            x: (coord.x - xmin) * self.extent / (xmax - xmin),
+            y: (ymax - coord.y) * self.extent / (ymax - ymin),
+        }
+    }
+
+    fn apply(&self, geom: geo::Geometry) -> Option<geo::Geometry> {
+        match geom {
+            geo::Geometry::Point(point) => self
+                .points(geo::MultiPoint(vec![point]))
+                .map(|points| geo::Geometry::Point(points.0[0])),
+            geo::Geometry::MultiPoint(points) => self.points(points).map(geo::Geometry::MultiPoint),
+            geo::Geometry::Line(line) => {
+                self.apply(geo::Geometry::LineString(geo::LineString::from(line)))
+            }
+            geo::Geometry::LineString(line_string) => self
+                .lines(geo::MultiLineString(vec![line_string]))
+                .map(|mut lines| match lines.0.len() {
+                    1 => geo::Geometry::LineString(lines.0.remove(0)),
+                    _ => geo::Geometry::MultiLineString(lines),
+                }),
+            geo::Geometry::MultiLineString(lines) => {
+                self.lines(lines).map(geo::Geometry::MultiLineString)
+            }
+            geo::Geometry::Polygon(polygon) => {
+                self.polygons(geo::MultiPolygon(vec![polygon]))
+                    .map(|mut polygons| match polygons.0.len() {
+                        1 => geo::Geometry::Polygon(polygons.0.remove(0)),
+                        _ => geo::Geometry::MultiPolygon(polygons),
+                    })
+            }
+            geo::Geometry::MultiPolygon(polygons) => {
+                self.polygons(polygons).map(geo::Geometry::MultiPolygon)
+            }
+            geo::Geometry::Rect(rect) => self.apply(geo::Geometry::Polygon(rect.to_polygon())),
+            geo::Geometry::Triangle(triangle) => {
+                self.apply(geo::Geometry::Polygon(triangle.to_polygon()))
+            }
+            geo::Geometry::GeometryCollection(collection) => {
+                let geometries = collection
+                    .into_iter()
+                    .filter_map(|geom| self.apply(geom))
+                    .collect::<Vec<_>>();
+                (!geometries.is_empty()).then_some(geo::Geometry::GeometryCollection(
+                    geo::GeometryCollection(geometries),
+                ))
+            }
+        }
+    }
+
+    fn points(&self, points: geo::MultiPoint) -> Option<geo::MultiPoint> {
+        let points = points
+            .map_coords(|coord| self.to_tile(coord))
+            .into_iter()
+            .filter(|point| self.clip.is_none_or(|clip| clip.contains(point)))
+            .map(|point| point.map_coords(snap))
+            .collect::<geo::MultiPoint>()
+            .remove_repeated_points();
+        (!points.0.is_empty()).then_some(points)
+    }
+
+    fn lines(&self, lines: geo::MultiLineString) -> Option<geo::MultiLineString> {
+        let mut lines = lines.map_coords(|coord| self.to_tile(coord));
+        if let Some(clip) = &self.clip {
+            lines = clip.to_polygon().clip(&lines, false);
+        }
+        let mut lines = lines
+            .simplify(0.5)
+            .map_coords(snap)
+            .remove_repeated_points();
+        lines.0.retain(|line| line.0.len() >= 2);
+        (!lines.0.is_empty()).then_some(lines)
+    }
+
+    fn polygons(&self, polygons: geo::MultiPolygon) -> Option<geo::MultiPolygon> {
+        let mut polygons = polygons.map_coords(|coord| self.to_tile(coord));
+        if let Some(clip) = &self.clip {
+            polygons = polygons.intersection(&clip.to_polygon());
+        }
+        let polygons = polygons
+            .simplify(0.5)
+            .map_coords(snap)
+            .remove_repeated_points();
+
+        // Drop rings which collapsed when snapping to the grid
+        let polygons = polygons
+            .into_iter()
+            .filter(|polygon| polygon.exterior().0.len() >= 4 && polygon.unsigned_area() > 0.)
+            .map(|polygon| {
+                let (exterior, interiors) = polygon.into_inner();
+                let interiors = interiors
+                    .into_iter()
+                    .filter(|interior| {
+                        interior.0.len() >= 4
+                            && geo::Polygon::new(interior.clone(), vec![]).unsigned_area() > 0.
+                    })
+                    .collect();
+                geo::Polygon::new(exterior, interiors)
+            })
+            .collect::<geo::MultiPolygon>();
+
+        // Exterior rings have a positive area in tile coordinates, which appears clockwise with
+        // the Y axis pointing down
+        (!polygons.0.is_empty()).then(|| polygons.orient(Direction::Default))
+    }
+}
+
+fn snap(coord: geo::Coord) -> geo::Coord {
+    coord! { x: coord.x.round(), y: coord.y.round() }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_as_mvt_geom() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsMVTGeom::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql("SELECT
+                ST_AsText(ST_AsMVTGeom(ST_GeomFromText('POLYGON((0 0,10 0,10 5,0 5,0 0))'), ST_GeomFromText('LINESTRING(0 0,4096 4096)'), 4096, 0, false)),
+                ST_AsText(ST_AsMVTGeom(ST_GeomFromText('LINESTRING(-10 50,50 50,110 50)'), ST_GeomFromText('LINESTRING(0 0,100 100)'), 100, 5)),
+                ST_AsText(ST_AsMVTGeom(ST_GeomFromText('POINT(200 200)'), ST_GeomFromText('LINESTRING(0 0,100 100)')));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((0 4096,0 4091,10 4091,10 4096,0 4096))"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "LINESTRING(-5 50,105 50)"
+        );
+        assert!(batches[0].column(2).is_null(0));
+    }
+}
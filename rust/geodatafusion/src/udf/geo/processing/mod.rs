@@ -1,9 +1,11 @@
+mod as_mvt_geom;
 mod centroid;
 mod convex_hull;
 mod oriented_envelope;
 mod point_on_surface;
 mod simplify;
 
+pub use as_mvt_geom::AsMVTGeom;
 pub use centroid::Centroid;
 pub use convex_hull::ConvexHull;
 pub use oriented_envelope::OrientedEnvelope;
@@ -11,6 +13,7 @@ pub use point_on_surface::PointOnSurface;
 pub use simplify::{Simplify, SimplifyPreserveTopology, SimplifyVW};
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(AsMVTGeom::default().into());
     session_context.register_udf(Centroid::default().into());
     session_context.register_udf(ConvexHull::default().into());
     session_context.register_udf(OrientedEnvelope::default().into());
@@ -0,0 +1,213 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::BooleanArray;
+use arrow_array::builder::BooleanBuilder;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo::winding_order::WindingOrder;
+use geo::{Geometry, Polygon, Winding};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::error::GeoArrowResult;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsPolygonCW;
+
+impl IsPolygonCW {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for IsPolygonCW {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_POLYGON_CW_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsPolygonCW {
+    fn name(&self) -> &str {
+        "st_ispolygoncw"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(winding_impl(args, WindingOrder::Clockwise)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_POLYGON_CW_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if all exterior rings of the polygonal components of the input geometry use a clockwise orientation and all interior rings use a counter-clockwise orientation. Returns true if the geometry has no polygonal components.",
+                "ST_IsPolygonCW(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsPolygonCCW;
+
+impl IsPolygonCCW {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for IsPolygonCCW {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_POLYGON_CCW_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsPolygonCCW {
+    fn name(&self) -> &str {
+        "st_ispolygonccw"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(winding_impl(args, WindingOrder::CounterClockwise)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_POLYGON_CCW_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if all exterior rings of the polygonal components of the input geometry use a counter-clockwise orientation and all interior rings use a clockwise orientation. Returns true if the geometry has no polygonal components.",
+                "ST_IsPolygonCCW(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn winding_impl(
+    args: ScalarFunctionArgs,
+    exterior_order: WindingOrder,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+    let result = downcast_geoarrow_array!(geo_array_ref, winding_array, exterior_order)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn winding_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    exterior_order: WindingOrder,
+) -> GeoArrowResult<BooleanArray> {
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geometry_to_geo(&geom?)?;
+            builder.append_value(has_winding_order(&geom, exterior_order));
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Whether all polygonal components have exterior rings of the given winding order, and interior
+/// rings of the opposite order
+fn has_winding_order(geom: &Geometry, exterior_order: WindingOrder) -> bool {
+    match geom {
+        Geometry::Polygon(polygon) => polygon_has_winding_order(polygon, exterior_order),
+        Geometry::MultiPolygon(mp) => mp
+            .iter()
+            .all(|polygon| polygon_has_winding_order(polygon, exterior_order)),
+        Geometry::Rect(rect) => polygon_has_winding_order(&rect.to_polygon(), exterior_order),
+        Geometry::Triangle(triangle) => {
+            polygon_has_winding_order(&triangle.to_polygon(), exterior_order)
+        }
+        Geometry::GeometryCollection(gc) => gc.iter().all(|g| has_winding_order(g, exterior_order)),
+        _ => true,
+    }
+}
+
+fn polygon_has_winding_order(polygon: &Polygon, exterior_order: WindingOrder) -> bool {
+    let interior_order = match exterior_order {
+        WindingOrder::Clockwise => WindingOrder::CounterClockwise,
+        WindingOrder::CounterClockwise => WindingOrder::Clockwise,
+    };
+    // An empty polygon has no rings to orient
+    (polygon.exterior().0.is_empty() || polygon.exterior().winding_order() == Some(exterior_order))
+        && polygon
+            .interiors()
+            .iter()
+            .all(|ring| ring.winding_order() == Some(interior_order))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_is_polygon_cw() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(IsPolygonCW.into());
+        ctx.register_udf(IsPolygonCCW.into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_IsPolygonCW(ST_GeomFromText(wkt)), ST_IsPolygonCCW(ST_GeomFromText(wkt))
+                FROM (VALUES
+                    ('POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 4,2 2))'),
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))'),
+                    ('POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 4,4 4,4 2,2 2))'),
+                    ('MULTIPOLYGON(((0 0,0 1,1 1,0 0)),((5 5,6 5,6 6,5 5)))'),
+                    ('LINESTRING(0 0,1 1)')
+                ) AS t(wkt);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let values = |i: usize| {
+            batches[0]
+                .column(i)
+                .as_boolean()
+                .iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(0), vec![true, false, false, false, true]);
+        assert_eq!(values(1), vec![false, true, false, false, true]);
+    }
+}
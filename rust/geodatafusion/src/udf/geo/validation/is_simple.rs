@@ -0,0 +1,333 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::BooleanArray;
+use arrow_array::builder::BooleanBuilder;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo::algorithm::sweep::{Cross, Intersections};
+use geo::line_intersection::LineIntersection;
+use geo::{Coord, Geometry, LineString, Polygon, RemoveRepeatedPoints};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::error::GeoArrowResult;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsSimple;
+
+impl IsSimple {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for IsSimple {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_SIMPLE_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsSimple {
+    fn name(&self) -> &str {
+        "st_issimple"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(predicate_impl(args, is_simple)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_SIMPLE_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if the geometry has no anomalous geometric points, such as self intersection or self tangency. A LineString may only touch itself at its endpoints, when it is closed. The lines of a MultiLineString may only touch each other at their endpoints, and the points of a MultiPoint must be distinct. Polygons are simple if their rings are simple.",
+                "ST_IsSimple(geomA)",
+            )
+            .with_argument("geomA", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsRing;
+
+impl IsRing {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for IsRing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_RING_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsRing {
+    fn name(&self) -> &str {
+        "st_isring"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(predicate_impl(args, is_ring)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_RING_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if the input LineString is both closed (ST_IsClosed) and simple (ST_IsSimple). Returns NULL if the geometry is not a LineString.",
+                "ST_IsRing(g)",
+            )
+            .with_argument("g", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// Evaluate a predicate on each geometry, where `None` is a NULL result
+fn predicate_impl(
+    args: ScalarFunctionArgs,
+    predicate: fn(&Geometry) -> Option<bool>,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+    let result = downcast_geoarrow_array!(geo_array_ref, predicate_array, predicate)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn predicate_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    predicate: fn(&Geometry) -> Option<bool>,
+) -> GeoArrowResult<BooleanArray> {
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for item in array.iter() {
+        if let Some(geom) = item {
+            builder.append_option(predicate(&geometry_to_geo(&geom?)?));
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn is_simple(geom: &Geometry) -> Option<bool> {
+    Some(geometry_is_simple(geom))
+}
+
+fn geometry_is_simple(geom: &Geometry) -> bool {
+    match geom {
+        Geometry::Point(_) => true,
+        Geometry::MultiPoint(mp) => {
+            let mut coords = mp.0.iter().map(|p| p.0).collect::<Vec<_>>();
+            coords.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+            coords.windows(2).all(|w| w[0] != w[1])
+        }
+        Geometry::Line(line) => line.start != line.end,
+        Geometry::LineString(ls) => lines_are_simple(std::slice::from_ref(ls)),
+        Geometry::MultiLineString(mls) => lines_are_simple(&mls.0),
+        Geometry::Polygon(polygon) => polygon_is_simple(polygon),
+        Geometry::MultiPolygon(mp) => mp.iter().all(polygon_is_simple),
+        Geometry::Rect(_) | Geometry::Triangle(_) => true,
+        Geometry::GeometryCollection(gc) => gc.iter().all(geometry_is_simple),
+    }
+}
+
+fn polygon_is_simple(polygon: &Polygon) -> bool {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .all(|ring| lines_are_simple(std::slice::from_ref(ring)))
+}
+
+fn is_ring(geom: &Geometry) -> Option<bool> {
+    match geom {
+        Geometry::LineString(ls) => {
+            Some(!ls.0.is_empty() && ls.is_closed() && lines_are_simple(std::slice::from_ref(ls)))
+        }
+        _ => None,
+    }
+}
+
+/// A segment of one of a set of LineStrings
+#[derive(Debug, Clone)]
+pub(super) struct Segment {
+    pub(super) line_index: usize,
+    pub(super) segment_index: usize,
+    pub(super) line: geo::Line,
+}
+
+impl Cross for Segment {
+    type Scalar = f64;
+
+    fn line(&self) -> geo::Line {
+        self.line
+    }
+}
+
+/// Find all intersecting pairs of segments of a set of LineStrings
+pub(super) fn segment_intersections<'a>(
+    lines: impl IntoIterator<Item = &'a LineString>,
+) -> Intersections<Segment> {
+    lines
+        .into_iter()
+        .enumerate()
+        .flat_map(|(line_index, ls)| {
+            ls.lines()
+                .enumerate()
+                .map(move |(segment_index, line)| Segment {
+                    line_index,
+                    segment_index,
+                    line,
+                })
+        })
+        .collect()
+}
+
+/// Whether a set of LineStrings is simple: each LineString only touches itself at consecutive
+/// segments, or at its endpoints if it is closed, and LineStrings only touch each other at
+/// endpoints of both.
+fn lines_are_simple(lines: &[LineString]) -> bool {
+    first_non_simple_point(lines).is_none()
+}
+
+/// The first point at which a set of LineStrings is not simple
+pub(super) fn first_non_simple_point(lines: &[LineString]) -> Option<Coord> {
+    // Repeated points do not make a line non-simple, but would create zero-length segments
+    let lines = lines
+        .iter()
+        .map(|ls| ls.remove_repeated_points())
+        .collect::<Vec<_>>();
+
+    segment_intersections(&lines).find_map(|(a, b, intersection)| {
+        let intersection = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => intersection,
+            // Overlapping segments
+            LineIntersection::Collinear { intersection } => return Some(intersection.start),
+        };
+        let allowed = if a.line_index == b.line_index {
+            let ls = &lines[a.line_index];
+            let num_segments = ls.0.len() - 1;
+            let (first, last) = if a.segment_index < b.segment_index {
+                (a.segment_index, b.segment_index)
+            } else {
+                (b.segment_index, a.segment_index)
+            };
+            // Consecutive segments share a vertex, as do the first and last segment of a ring
+            last == first + 1 || (ls.is_closed() && first == 0 && last == num_segments - 1)
+        } else {
+            is_boundary(&lines[a.line_index], intersection)
+                && is_boundary(&lines[b.line_index], intersection)
+        };
+        (!allowed).then_some(intersection)
+    })
+}
+
+/// Whether a point is on the boundary of a LineString, which is its endpoints unless it is closed
+fn is_boundary(ls: &LineString, coord: Coord) -> bool {
+    !ls.is_closed() && (ls.0.first() == Some(&coord) || ls.0.last() == Some(&coord))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    async fn evaluate(function: &str, wkts: &[&str]) -> Vec<Option<bool>> {
+        let ctx = SessionContext::new();
+        ctx.register_udf(IsSimple.into());
+        ctx.register_udf(IsRing.into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let values = wkts
+            .iter()
+            .map(|wkt| format!("('{wkt}')"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let df = ctx
+            .sql(&format!(
+                "SELECT {function}(ST_GeomFromText(wkt)) FROM (VALUES {values}) AS t(wkt);"
+            ))
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        batches[0].column(0).as_boolean().iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_is_simple() {
+        let result = evaluate(
+            "ST_IsSimple",
+            &[
+                "LINESTRING(0 0,1 1,2 2)",
+                "LINESTRING(0 0,2 2,2 0,0 2)",
+                "LINESTRING(0 0,1 0,1 1,0 1,0 0)",
+                "LINESTRING(0 0,1 1,1 1,2 2)",
+                "LINESTRING(0 0,2 0,1 0)",
+                "MULTILINESTRING((0 0,1 1),(1 1,2 0))",
+                "MULTILINESTRING((0 0,2 2),(0 2,2 0))",
+                "MULTIPOINT(0 0,1 1)",
+                "MULTIPOINT(0 0,0 0)",
+                "POLYGON((0 0,1 0,1 1,0 1,0 0))",
+            ],
+        )
+        .await;
+        assert_eq!(
+            result,
+            [
+                true, false, true, true, false, true, false, true, false, true
+            ]
+            .map(Some)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_is_ring() {
+        let result = evaluate(
+            "ST_IsRing",
+            &[
+                "LINESTRING(0 0,0 1,1 1,1 0,0 0)",
+                "LINESTRING(0 0,0 1,1 1,1 0)",
+                "LINESTRING(0 0,1 1,1 0,0 1,0 0)",
+                "LINESTRING EMPTY",
+                "POINT(0 0)",
+            ],
+        )
+        .await;
+        assert_eq!(
+            result,
+            vec![Some(true), Some(false), Some(false), Some(false), None]
+        );
+    }
+}
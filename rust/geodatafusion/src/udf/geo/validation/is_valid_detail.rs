@@ -0,0 +1,384 @@
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use arrow_array::builder::{BooleanBuilder, StringBuilder};
+use arrow_array::{ArrayRef, StructArray};
+use arrow_buffer::NullBufferBuilder;
+use arrow_schema::{DataType, Field, FieldRef, Fields};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo::line_intersection::LineIntersection;
+use geo::validation::{
+    CoordIndex, InvalidGeometry, InvalidGeometryCollection, InvalidLine, InvalidLineString,
+    InvalidMultiLineString, InvalidMultiPoint, InvalidMultiPolygon, InvalidPolygon, InvalidRect,
+    InvalidTriangle, RingRole,
+};
+use geo::{Contains, Coord, Geometry, LineString, Polygon, Validation};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::PointBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PointType};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::geo::validation::is_simple::{first_non_simple_point, segment_intersections};
+
+/// PostGIS flag to consider self-intersecting rings forming holes as valid, as in the ESRI model
+const ESRI_FLAG: i64 = 1;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsValidDetail {
+    coord_type: CoordType,
+}
+
+impl IsValidDetail {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for IsValidDetail {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+static SIGNATURE: LazyLock<Signature> = LazyLock::new(|| {
+    Signature::one_of(
+        vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+        Volatility::Immutable,
+    )
+});
+
+impl ScalarUDFImpl for IsValidDetail {
+    fn name(&self) -> &str {
+        "st_isvaliddetail"
+    }
+
+    fn signature(&self) -> &Signature {
+        &SIGNATURE
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let location_type = location_type(args.arg_fields[0].as_ref(), self.coord_type);
+        Ok(Arc::new(Field::new(
+            "",
+            DataType::Struct(detail_fields(&location_type)),
+            true,
+        )))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(is_valid_detail_impl(args, self.coord_type)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a struct with fields valid, reason and location, stating if a geometry is valid, or if not the reason and a point at the first location of invalidity. The reason and location are NULL for valid geometries. The location is the first invalid coordinate, such as a self-intersection or a point where rings or polygons touch or overlap. The ESRI flag (1), considering self-intersecting rings forming holes as valid, is not supported.",
+                "ST_IsValidDetail(geom, flags)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("flags", "integer, optional")
+            .build()
+        }))
+    }
+}
+
+fn location_type(input_field: &Field, coord_type: CoordType) -> PointType {
+    let metadata = Arc::new(Metadata::try_from(input_field).unwrap_or_default());
+    PointType::new(Dimension::XY, metadata).with_coord_type(coord_type)
+}
+
+fn detail_fields(location_type: &PointType) -> Fields {
+    Fields::from(vec![
+        Field::new("valid", DataType::Boolean, false),
+        Field::new("reason", DataType::Utf8, true),
+        location_type.to_field("location", true),
+    ])
+}
+
+fn is_valid_detail_impl(
+    args: ScalarFunctionArgs,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<ColumnarValue> {
+    match args.args.get(1) {
+        None => {}
+        Some(ColumnarValue::Scalar(scalar)) => {
+            if let ScalarValue::Int64(Some(flags)) = scalar.cast_to(&DataType::Int64)?
+                && flags & ESRI_FLAG != 0
+            {
+                return Err(DataFusionError::NotImplemented(
+                    "ST_IsValidDetail ESRI flag not yet implemented".to_string(),
+                )
+                .into());
+            }
+        }
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized ST_IsValidDetail flags not yet implemented".to_string(),
+            )
+            .into());
+        }
+    }
+
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let location_type = location_type(args.arg_fields[0].as_ref(), coord_type);
+
+    let geo_array_ref = geo_array.as_ref();
+    let result = downcast_geoarrow_array!(geo_array_ref, is_valid_detail_array, &location_type)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn is_valid_detail_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    location_type: &PointType,
+) -> GeoDataFusionResult<StructArray> {
+    let mut valid_builder = BooleanBuilder::with_capacity(array.len());
+    let mut reason_builder = StringBuilder::with_capacity(array.len(), 0);
+    let mut location_builder = PointBuilder::with_capacity(location_type.clone(), array.len());
+    let mut validity = NullBufferBuilder::new(array.len());
+
+    for item in array.iter() {
+        let Some(geom) = item else {
+            // Child values of a null struct row are arbitrary, but must be valid for the field
+            valid_builder.append_value(false);
+            reason_builder.append_null();
+            location_builder.push_null();
+            validity.append_null();
+            continue;
+        };
+
+        let geom = geometry_to_geo(&geom?)?;
+        match geom.check_validation() {
+            Ok(()) => {
+                valid_builder.append_value(true);
+                reason_builder.append_null();
+                location_builder.push_null();
+            }
+            Err(err) => {
+                valid_builder.append_value(false);
+                reason_builder.append_value(err.to_string());
+                location_builder.push_coord(geometry_location(&geom, &err).as_ref());
+            }
+        }
+        validity.append_non_null();
+    }
+
+    Ok(StructArray::try_new(
+        detail_fields(location_type),
+        vec![
+            Arc::new(valid_builder.finish()) as ArrayRef,
+            Arc::new(reason_builder.finish()),
+            location_builder.finish().into_array_ref(),
+        ],
+        validity.finish(),
+    )?)
+}
+
+/// The first coordinate at which a geometry is invalid
+fn geometry_location(geom: &Geometry, err: &InvalidGeometry) -> Option<Coord> {
+    match (geom, err) {
+        (Geometry::Point(point), _) => Some(point.0),
+        (Geometry::Line(line), InvalidGeometry::InvalidLine(err)) => match err {
+            InvalidLine::IdenticalCoords | InvalidLine::NonFiniteCoord(CoordIndex(0)) => {
+                Some(line.start)
+            }
+            InvalidLine::NonFiniteCoord(_) => Some(line.end),
+        },
+        (Geometry::LineString(ls), InvalidGeometry::InvalidLineString(err)) => {
+            line_string_location(ls, err)
+        }
+        (Geometry::Polygon(polygon), InvalidGeometry::InvalidPolygon(err)) => {
+            polygon_location(polygon, err)
+        }
+        (
+            Geometry::MultiPoint(mp),
+            InvalidGeometry::InvalidMultiPoint(InvalidMultiPoint::InvalidPoint(i, _)),
+        ) => mp.0.get(i.0).map(|point| point.0),
+        (
+            Geometry::MultiLineString(mls),
+            InvalidGeometry::InvalidMultiLineString(InvalidMultiLineString::InvalidLineString(
+                i,
+                err,
+            )),
+        ) => line_string_location(mls.0.get(i.0)?, err),
+        (Geometry::MultiPolygon(mp), InvalidGeometry::InvalidMultiPolygon(err)) => match err {
+            InvalidMultiPolygon::InvalidPolygon(i, err) => polygon_location(mp.0.get(i.0)?, err),
+            InvalidMultiPolygon::ElementsOverlaps(i, j)
+            | InvalidMultiPolygon::ElementsTouchOnALine(i, j) => {
+                let (a, b) = (mp.0.get(i.0)?, mp.0.get(j.0)?);
+                first_intersection(&rings(a), &rings(b)).or_else(|| b.exterior().0.first().copied())
+            }
+        },
+        (
+            Geometry::GeometryCollection(gc),
+            InvalidGeometry::InvalidGeometryCollection(InvalidGeometryCollection::InvalidGeometry(
+                i,
+                err,
+            )),
+        ) => geometry_location(gc.0.get(i.0)?, err),
+        (Geometry::Rect(rect), InvalidGeometry::InvalidRect(InvalidRect::NonFiniteCoord(i))) => {
+            Some(if i.0 == 0 { rect.min() } else { rect.max() })
+        }
+        (Geometry::Triangle(triangle), InvalidGeometry::InvalidTriangle(err)) => match err {
+            InvalidTriangle::NonFiniteCoord(i) | InvalidTriangle::IdenticalCoords(i, _) => {
+                triangle.to_array().get(i.0).copied()
+            }
+            InvalidTriangle::CollinearCoords => Some(triangle.v1()),
+        },
+        _ => None,
+    }
+}
+
+fn line_string_location(ls: &LineString, err: &InvalidLineString) -> Option<Coord> {
+    match err {
+        InvalidLineString::TooFewPoints => ls.0.first().copied(),
+        InvalidLineString::NonFiniteCoord(i) => ls.0.get(i.0).copied(),
+    }
+}
+
+fn polygon_location(polygon: &Polygon, err: &InvalidPolygon) -> Option<Coord> {
+    match err {
+        InvalidPolygon::TooFewPointsInRing(role) => ring(polygon, *role)?.0.first().copied(),
+        InvalidPolygon::SelfIntersection(role) => {
+            let ring = ring(polygon, *role)?;
+            first_non_simple_point(std::slice::from_ref(ring)).or_else(|| ring.0.first().copied())
+        }
+        InvalidPolygon::NonFiniteCoord(role, i) => ring(polygon, *role)?.0.get(i.0).copied(),
+        InvalidPolygon::InteriorRingNotContainedInExteriorRing(role) => {
+            let ring = ring(polygon, *role)?;
+            let exterior = Polygon::new(polygon.exterior().clone(), vec![]);
+            ring.0
+                .iter()
+                .find(|coord| !exterior.contains(*coord))
+                .or_else(|| ring.0.first())
+                .copied()
+        }
+        InvalidPolygon::IntersectingRingsOnALine(a, b)
+        | InvalidPolygon::IntersectingRingsOnAnArea(a, b) => {
+            let (a, b) = (ring(polygon, *a)?, ring(polygon, *b)?);
+            first_intersection(&[a], &[b]).or_else(|| b.0.first().copied())
+        }
+    }
+}
+
+fn ring(polygon: &Polygon, role: RingRole) -> Option<&LineString> {
+    match role {
+        RingRole::Exterior => Some(polygon.exterior()),
+        RingRole::Interior(i) => polygon.interiors().get(i),
+    }
+}
+
+fn rings(polygon: &Polygon) -> Vec<&LineString> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .collect()
+}
+
+/// The first point where lines of `a` intersect lines of `b`, preferring overlaps, then proper
+/// crossings, over touches
+fn first_intersection(a: &[&LineString], b: &[&LineString]) -> Option<Coord> {
+    let is_a = |line_index: usize| line_index < a.len();
+    segment_intersections(a.iter().chain(b).copied())
+        .filter(|(s1, s2, _)| is_a(s1.line_index) != is_a(s2.line_index))
+        .map(|(_, _, intersection)| match intersection {
+            LineIntersection::Collinear { intersection } => (2, intersection.start),
+            LineIntersection::SinglePoint {
+                intersection,
+                is_proper,
+            } => (usize::from(is_proper), intersection),
+        })
+        // Keep the first of the highest priority
+        .reduce(|best, next| if next.0 > best.0 { next } else { best })
+        .map(|(_, coord)| coord)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+    use geo_traits::{CoordTrait, PointTrait};
+    use geoarrow_array::array::PointArray;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_is_valid_detail() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(IsValidDetail::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_IsValidDetail(ST_GeomFromText(wkt)) FROM (VALUES
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0))'),
+                    ('POLYGON((0 0,10 10,10 0,0 10,0 0))'),
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0),(20 20,21 20,21 21,20 20))'),
+                    ('MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)),((2 0,4 0,4 2,2 2,2 0)))'),
+                    (NULL)
+                ) AS t(wkt);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let details = batches[0].column(0).as_struct();
+        let valid = details.column(0).as_boolean();
+        let reason = details.column(1).as_string::<i32>();
+        let location =
+            PointArray::try_from((details.column(2).as_ref(), details.fields()[2].as_ref()))
+                .unwrap();
+        let location_xy = |i: usize| {
+            let coord = location.value(i).unwrap().coord().unwrap();
+            (coord.x(), coord.y())
+        };
+
+        assert!(valid.value(0));
+        assert!(reason.is_null(0));
+        assert!(location.is_null(0));
+
+        assert!(!valid.value(1));
+        assert_eq!(reason.value(1), "exterior ring has a self-intersection");
+        assert_eq!(location_xy(1), (5.0, 5.0));
+
+        assert!(!valid.value(2));
+        assert_eq!(location_xy(2), (20.0, 20.0));
+
+        assert!(!valid.value(3));
+        assert_eq!(location_xy(3), (2.0, 2.0));
+
+        assert!(details.is_null(4));
+    }
+
+    #[tokio::test]
+    async fn test_esri_flag_not_implemented() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(IsValidDetail::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let result = ctx
+            .sql("SELECT ST_IsValidDetail(ST_GeomFromText('POINT(0 0)'), 1);")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(result.is_err());
+    }
+}
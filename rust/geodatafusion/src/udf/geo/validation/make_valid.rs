@@ -0,0 +1,495 @@
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo::bool_ops::{FillRule, unary_union};
+use geo::{
+    BooleanOps, Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, RemoveRepeatedPoints, Validation,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeometryType, Metadata};
+
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct MakeValid {
+    coord_type: CoordType,
+}
+
+impl MakeValid {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for MakeValid {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+static SIGNATURE: LazyLock<Signature> = LazyLock::new(|| {
+    Signature::one_of(
+        vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+        Volatility::Immutable,
+    )
+});
+
+impl ScalarUDFImpl for MakeValid {
+    fn name(&self) -> &str {
+        "st_makevalid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &SIGNATURE
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(make_valid_return_field(args, self.coord_type))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_valid_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(make_valid_documentation())
+    }
+}
+
+/// Shared with the GEOS-backed implementation, which replaces this one when enabled
+pub(crate) fn make_valid_documentation() -> &'static Documentation {
+    DOCUMENTATION.get_or_init(|| {
+        Documentation::builder(
+            DOC_SECTION_OTHER,
+            "Attempts to create a valid representation of an invalid geometry without losing any of the input vertices. Valid geometries are returned unchanged. The params are space-separated key=value pairs:\n\n- method=linework (default) builds polygons from all ring edges, where areas enclosed an odd number of times are interior, and keeps collapsed components as lines or points.\n- method=structure unions the area of each exterior ring and subtracts the area of the holes, so that overlapping polygons are merged rather than removed.\n- keepcollapsed=true|false (default false), for the structure method, keeps components which collapse to a lower dimension as lines or points, rather than dropping them.",
+            "ST_MakeValid(geom, 'method=structure keepcollapsed=true')",
+        )
+        .with_argument("geom", "geometry")
+        .with_argument("params", "string, optional")
+        .with_related_udf("st_isvalid")
+        .with_related_udf("st_isvalidreason")
+        .build()
+    })
+}
+
+pub(crate) fn make_valid_return_field(args: ReturnFieldArgs, coord_type: CoordType) -> FieldRef {
+    let metadata = Arc::new(Metadata::try_from(args.arg_fields[0].as_ref()).unwrap_or_default());
+    let output_type = GeometryType::new(metadata).with_coord_type(coord_type);
+    Arc::new(output_type.to_field("", true))
+}
+
+/// The algorithm used to repair invalid geometries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum RepairMethod {
+    /// Build polygons from the noded linework of all rings, using the even-odd rule
+    #[default]
+    Linework,
+    /// Union the areas of the exterior rings, and subtract the areas of the holes
+    Structure,
+}
+
+/// Parsed `ST_MakeValid` params
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct RepairOptions {
+    pub(crate) method: RepairMethod,
+    pub(crate) keep_collapsed: bool,
+}
+
+impl RepairOptions {
+    /// Parse the optional params argument, such as `method=structure keepcollapsed=true`
+    pub(crate) fn from_args(args: &ScalarFunctionArgs) -> GeoDataFusionResult<Self> {
+        match args.args.get(1) {
+            None => Ok(Self::default()),
+            Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Utf8)? {
+                ScalarValue::Utf8(Some(params)) => Self::parse(&params),
+                _ => Ok(Self::default()),
+            },
+            Some(ColumnarValue::Array(_)) => Err(DataFusionError::NotImplemented(
+                "Vectorized ST_MakeValid params not yet implemented".to_string(),
+            )
+            .into()),
+        }
+    }
+
+    fn parse(params: &str) -> GeoDataFusionResult<Self> {
+        let mut options = Self::default();
+        for param in params.split([' ', ',']).filter(|p| !p.is_empty()) {
+            let invalid_param =
+                || DataFusionError::Execution(format!("Invalid ST_MakeValid param '{param}'"));
+            let (key, value) = param.split_once('=').ok_or_else(invalid_param)?;
+            match (
+                key.to_ascii_lowercase().as_str(),
+                value.to_ascii_lowercase().as_str(),
+            ) {
+                ("method", "linework") => options.method = RepairMethod::Linework,
+                ("method", "structure") => options.method = RepairMethod::Structure,
+                ("keepcollapsed", "true") => options.keep_collapsed = true,
+                ("keepcollapsed", "false") => options.keep_collapsed = false,
+                _ => return Err(invalid_param().into()),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether components collapsing to a lower dimension are kept
+    fn keeps_collapsed(&self) -> bool {
+        self.method == RepairMethod::Linework || self.keep_collapsed
+    }
+}
+
+fn make_valid_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let options = RepairOptions::from_args(&args)?;
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let typ = args.return_field.extension_type::<GeometryType>();
+
+    let geo_array_ref = geo_array.as_ref();
+    let result = downcast_geoarrow_array!(geo_array_ref, make_valid_array, typ, &options)?;
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+fn make_valid_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    typ: GeometryType,
+    options: &RepairOptions,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    let mut builder = GeometryBuilder::new(typ);
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geom?;
+            let geo_geom = geometry_to_geo(&geom)?;
+            if geo_geom.is_valid() {
+                // Push the input itself, which keeps any Z or M values
+                builder.push_geometry(Some(&geom))?;
+            } else {
+                builder.push_geometry(Some(&repair(&geo_geom, options)))?;
+            }
+        } else {
+            builder.push_null();
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+fn make_valid(geom: Geometry, options: &RepairOptions) -> Geometry {
+    if geom.is_valid() {
+        geom
+    } else {
+        repair(&geom, options)
+    }
+}
+
+fn repair(geom: &Geometry, options: &RepairOptions) -> Geometry {
+    match geom {
+        Geometry::Point(point) => {
+            if is_finite(&point.0) {
+                Geometry::Point(*point)
+            } else {
+                Geometry::MultiPoint(MultiPoint::new(vec![]))
+            }
+        }
+        Geometry::MultiPoint(mp) => simplify_multi(Geometry::MultiPoint(
+            mp.iter().copied().filter(|p| is_finite(&p.0)).collect(),
+        )),
+        Geometry::Line(line) => repair_lines(&[line.into()], options),
+        Geometry::LineString(ls) => repair_lines(std::slice::from_ref(ls), options),
+        Geometry::MultiLineString(mls) => repair_lines(&mls.0, options),
+        Geometry::Polygon(polygon) => repair_polygons(std::slice::from_ref(polygon), options),
+        Geometry::MultiPolygon(mp) => repair_polygons(&mp.0, options),
+        Geometry::Rect(rect) => repair_polygons(&[rect.to_polygon()], options),
+        Geometry::Triangle(triangle) => repair_polygons(&[triangle.to_polygon()], options),
+        Geometry::GeometryCollection(gc) => simplify_multi(Geometry::GeometryCollection(
+            gc.iter()
+                .map(|g| make_valid(g.clone(), options))
+                .filter(|g| !is_empty(g))
+                .collect(),
+        )),
+    }
+}
+
+/// Remove non-finite and repeated coordinates, so that lines collapse to points
+fn clean_line(ls: &LineString) -> LineString {
+    ls.0.iter()
+        .copied()
+        .filter(is_finite)
+        .collect::<LineString>()
+        .remove_repeated_points()
+}
+
+fn repair_lines(lines: &[LineString], options: &RepairOptions) -> Geometry {
+    let mut kept_lines = Vec::with_capacity(lines.len());
+    let mut collapsed = Vec::new();
+    for ls in lines.iter().map(clean_line) {
+        match ls.0.len() {
+            0 => {}
+            1 => collapsed.push(Point(ls.0[0])),
+            _ => kept_lines.push(ls),
+        }
+    }
+    if !options.keeps_collapsed() {
+        collapsed.clear();
+    }
+
+    if kept_lines.is_empty() && collapsed.is_empty() {
+        return Geometry::LineString(LineString::new(vec![]));
+    }
+    let lines = Geometry::MultiLineString(MultiLineString::new(kept_lines));
+    if collapsed.is_empty() {
+        simplify_multi(lines)
+    } else if is_empty(&lines) {
+        simplify_multi(Geometry::MultiPoint(MultiPoint::new(collapsed)))
+    } else {
+        Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            simplify_multi(lines),
+            simplify_multi(Geometry::MultiPoint(MultiPoint::new(collapsed))),
+        ]))
+    }
+}
+
+fn repair_polygons(polygons: &[Polygon], options: &RepairOptions) -> Geometry {
+    let polygons = polygons
+        .iter()
+        .map(|polygon| {
+            Polygon::new(
+                clean_line(polygon.exterior()),
+                polygon.interiors().iter().map(clean_line).collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let parts = polygons
+        .iter()
+        .map(|polygon| polygon_area(polygon, options.method))
+        .collect::<Vec<_>>();
+    let area = match options.method {
+        // All rings together, so that areas covered by an even number of rings are removed
+        RepairMethod::Linework => MultiPolygon::new(polygons.clone())
+            .union_with_fill_rule(&MultiPolygon::new(vec![]), FillRule::EvenOdd),
+        RepairMethod::Structure => unary_union(&parts),
+    };
+
+    // The linework of the parts which collapsed to zero area
+    let collapsed = if options.keeps_collapsed() {
+        let rings = polygons
+            .iter()
+            .zip(&parts)
+            .filter(|(_, part)| part.0.is_empty())
+            .flat_map(|(polygon, _)| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .cloned()
+            .collect::<Vec<_>>();
+        Some(repair_lines(&rings, options)).filter(|lines| !is_empty(lines))
+    } else {
+        None
+    };
+
+    match (area.0.is_empty(), collapsed) {
+        (false, None) => simplify_multi(Geometry::MultiPolygon(area)),
+        (false, Some(collapsed)) => {
+            let mut members = vec![simplify_multi(Geometry::MultiPolygon(area))];
+            match collapsed {
+                Geometry::GeometryCollection(gc) => members.extend(gc.0),
+                collapsed => members.push(collapsed),
+            }
+            Geometry::GeometryCollection(GeometryCollection::new_from(members))
+        }
+        (true, Some(collapsed)) => collapsed,
+        (true, None) => Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![])),
+    }
+}
+
+/// The area of a single polygon part with the given repair method
+fn polygon_area(polygon: &Polygon, method: RepairMethod) -> MultiPolygon {
+    let empty = MultiPolygon::new(vec![]);
+    match method {
+        RepairMethod::Linework => polygon.union_with_fill_rule(&empty, FillRule::EvenOdd),
+        RepairMethod::Structure => {
+            let shell = Polygon::new(polygon.exterior().clone(), vec![])
+                .union_with_fill_rule(&empty, FillRule::NonZero);
+            let holes = polygon
+                .interiors()
+                .iter()
+                .map(|ring| Polygon::new(ring.clone(), vec![]))
+                .collect::<MultiPolygon>()
+                .union_with_fill_rule(&empty, FillRule::NonZero);
+            shell.difference(&holes)
+        }
+    }
+}
+
+/// Unwrap multi geometries and collections with a single member
+fn simplify_multi(geom: Geometry) -> Geometry {
+    match geom {
+        Geometry::MultiPoint(mut mp) if mp.0.len() == 1 => Geometry::Point(mp.0.remove(0)),
+        Geometry::MultiLineString(mut mls) if mls.0.len() == 1 => {
+            Geometry::LineString(mls.0.remove(0))
+        }
+        Geometry::MultiPolygon(mut mp) if mp.0.len() == 1 => Geometry::Polygon(mp.0.remove(0)),
+        Geometry::GeometryCollection(mut gc) if gc.0.len() == 1 => gc.0.remove(0),
+        geom => geom,
+    }
+}
+
+fn is_empty(geom: &Geometry) -> bool {
+    match geom {
+        Geometry::MultiPoint(mp) => mp.0.is_empty(),
+        Geometry::LineString(ls) => ls.0.is_empty(),
+        Geometry::MultiLineString(mls) => mls.0.is_empty(),
+        Geometry::Polygon(polygon) => polygon.exterior().0.is_empty(),
+        Geometry::MultiPolygon(mp) => mp.0.is_empty(),
+        Geometry::GeometryCollection(gc) => gc.0.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_finite(coord: &Coord) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::geo::measurement::Area;
+    use crate::udf::geo::validation::IsValid;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    fn ctx() -> SessionContext {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakeValid::default().into());
+        ctx.register_udf(IsValid.into());
+        ctx.register_udf(Area.into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_make_valid() {
+        let ctx = ctx();
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(g), ST_IsValid(g), ST_Area(g)
+                FROM (SELECT ST_MakeValid(ST_GeomFromText(wkt)) AS g FROM (VALUES
+                    ('POLYGON((0 0,1 0,1 1,0 1,0 0))'),
+                    ('POLYGON((0 0,2 2,2 0,0 2,0 0))'),
+                    ('LINESTRING(0 0,0 0)'),
+                    ('POLYGON((0 0,1 0,1 0,0 0))')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let wkt = batches[0].column(0).as_string::<i32>();
+        let valid = batches[0].column(1).as_boolean();
+        let area = batches[0]
+            .column(2)
+            .as_primitive::<arrow_array::types::Float64Type>();
+
+        assert_eq!(wkt.value(0), "POLYGON((0 0,1 0,1 1,0 1,0 0))");
+        assert!(wkt.value(1).starts_with("MULTIPOLYGON"));
+        assert_eq!(area.value(1), 2.0);
+        assert_eq!(wkt.value(2), "POINT(0 0)");
+        assert_eq!(wkt.value(3), "LINESTRING(0 0,1 0,0 0)");
+        assert!(valid.iter().all(|v| v == Some(true)));
+    }
+
+    #[tokio::test]
+    async fn test_make_valid_methods() {
+        let ctx = ctx();
+        let df = ctx
+            .sql(
+                "SELECT
+                    ST_Area(ST_MakeValid(ST_GeomFromText(wkt))),
+                    ST_Area(ST_MakeValid(ST_GeomFromText(wkt), 'method=structure')),
+                    ST_AsText(ST_MakeValid(ST_GeomFromText(collapsed), 'method=structure')),
+                    ST_AsText(ST_MakeValid(ST_GeomFromText(collapsed), 'method=structure keepcollapsed=true'))
+                FROM (VALUES
+                    ('MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)),((1 1,3 1,3 3,1 3,1 1)))', 'LINESTRING(1 1,1 1)')
+                ) AS t(wkt, collapsed);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let linework = batches[0]
+            .column(0)
+            .as_primitive::<arrow_array::types::Float64Type>();
+        let structure = batches[0]
+            .column(1)
+            .as_primitive::<arrow_array::types::Float64Type>();
+        // Linework removes the overlap, while structure merges it
+        assert_eq!(linework.value(0), 6.0);
+        assert_eq!(structure.value(0), 7.0);
+        assert_eq!(
+            batches[0].column(2).as_string::<i32>().value(0),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(
+            batches[0].column(3).as_string::<i32>().value(0),
+            "POINT(1 1)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_make_valid_keeps_z_and_collapsed_parts() {
+        let ctx = ctx();
+        let df = ctx
+            .sql(
+                "SELECT
+                    ST_AsText(ST_MakeValid(ST_GeomFromText('POLYGON Z((0 0 1,1 0 1,1 1 1,0 1 1,0 0 1))'))),
+                    ST_AsText(ST_MakeValid(ST_GeomFromText(collapsed))),
+                    ST_AsText(ST_MakeValid(ST_GeomFromText(collapsed), 'method=structure keepcollapsed=true')),
+                    ST_AsText(ST_MakeValid(ST_GeomFromText(collapsed), 'method=structure'))
+                FROM (VALUES
+                    ('MULTIPOLYGON(((0 0,2 2,2 0,0 2,0 0)),((5 5,6 5,5 5)))')
+                ) AS t(collapsed);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let wkt = |column: usize| batches[0].column(column).as_string::<i32>().value(0);
+        assert_eq!(wkt(0), "POLYGON Z((0 0 1,1 0 1,1 1 1,0 1 1,0 0 1))");
+        // The collapsed second part is kept as a line, unless the structure method drops it
+        let triangles = "MULTIPOLYGON(((0 2,0 0,1 1,0 2)),((2 2,1 1,2 0,2 2)))";
+        let kept = format!("GEOMETRYCOLLECTION({triangles},LINESTRING(5 5,6 5,5 5))");
+        assert_eq!(wkt(1), kept);
+        assert_eq!(wkt(2), kept);
+        assert_eq!(wkt(3), triangles);
+    }
+
+    #[tokio::test]
+    async fn test_make_valid_invalid_params() {
+        let ctx = ctx();
+        let result = ctx
+            .sql("SELECT ST_MakeValid(ST_GeomFromText('POINT(0 0)'), 'method=magic');")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(result.is_err());
+    }
+}
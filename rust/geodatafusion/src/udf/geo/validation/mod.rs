@@ -1,10 +1,28 @@
+mod is_polygon_cw;
+mod is_simple;
 mod is_valid;
+mod is_valid_detail;
 mod is_valid_reason;
+mod make_valid;
 
+pub use is_polygon_cw::{IsPolygonCCW, IsPolygonCW};
+pub use is_simple::{IsRing, IsSimple};
 pub use is_valid::IsValid;
+pub use is_valid_detail::IsValidDetail;
 pub use is_valid_reason::IsValidReason;
+pub use make_valid::MakeValid;
+#[cfg(feature = "geos-3_11")]
+pub(crate) use make_valid::{
+    RepairMethod, RepairOptions, make_valid_documentation, make_valid_return_field,
+};
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(IsPolygonCCW.into());
+    session_context.register_udf(IsPolygonCW.into());
+    session_context.register_udf(IsRing.into());
+    session_context.register_udf(IsSimple.into());
     session_context.register_udf(IsValid.into());
+    session_context.register_udf(IsValidDetail::default().into());
     session_context.register_udf(IsValidReason.into());
+    session_context.register_udf(MakeValid::default().into());
 }
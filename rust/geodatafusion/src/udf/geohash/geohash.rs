@@ -2,18 +2,24 @@ use std::sync::{Arc, LazyLock, OnceLock};
 
 use arrow_array::builder::StringViewBuilder;
 use arrow_schema::DataType;
-use datafusion::error::Result;
+use datafusion::error::{DataFusionError, Result};
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
-    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
 };
-use geo_traits::PointTrait;
+use datafusion::scalar::ScalarValue;
+use geo::Coord;
+use geo_traits::RectTrait;
 use geo_traits::to_geo::ToGeoCoord;
 use geoarrow_array::GeoArrowArrayAccessor;
-use geoarrow_array::array::PointArray;
-use geoarrow_schema::{CoordType, Dimension, PointType};
+use geoarrow_array::array::from_arrow_array;
 
 use crate::error::GeoDataFusionResult;
+use crate::udf::native::bounding_box::util::bounding_rect;
+
+/// The maximum GeoHash length supported by the `geohash` crate.
+const MAX_GEOHASH_LENGTH: usize = 12;
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 pub struct GeoHash;
@@ -32,15 +38,10 @@ impl Default for GeoHash {
 
 static GEOHASH_DOC: OnceLock<Documentation> = OnceLock::new();
 static GEOHASH_SIGNATURE: LazyLock<Signature> = LazyLock::new(|| {
-    let valid_types = vec![
-        PointType::new(Dimension::XY, Default::default())
-            .with_coord_type(CoordType::Separated)
-            .data_type(),
-        PointType::new(Dimension::XY, Default::default())
-            .with_coord_type(CoordType::Interleaved)
-            .data_type(),
-    ];
-    Signature::uniform(1, valid_types, Volatility::Immutable)
+    Signature::one_of(
+        vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+        Volatility::Immutable,
+    )
 });
 
 impl ScalarUDFImpl for GeoHash {
@@ -64,39 +65,85 @@ impl ScalarUDFImpl for GeoHash {
         Some(GEOHASH_DOC.get_or_init(|| {
             Documentation::builder(
                 DOC_SECTION_OTHER,
-                "Computes a GeoHash representation of a geometry. A GeoHash encodes a geographic Point into a text form that is sortable and searchable based on prefixing. A shorter GeoHash is a less precise representation of a point. It can be thought of as a box that contains the point.",
-                "ST_GeoHash(point)",
+                "Computes a GeoHash representation of a geometry. A GeoHash encodes a geographic Point into a text form that is sortable and searchable based on prefixing. A shorter GeoHash is a less precise representation of a point. It can be thought of as a box that contains the point.\n\nIf maxchars is not given, points are encoded with the maximum of 12 characters, and other geometries with the longest GeoHash whose cell contains their bounding box. Otherwise the center of the bounding box is encoded with maxchars characters, clamped to 12.",
+                "ST_GeoHash(geom, maxchars)",
             )
             .with_argument("geom", "geometry")
+            .with_argument("maxchars", "integer, optional")
             .build()
         }))
     }
 }
 
 fn geohash_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
-    let array = ColumnarValue::values_to_arrays(&args.args)?
+    let max_chars = match args.args.get(1) {
+        None => None,
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(max_chars)) if max_chars > 0 => {
+                Some((max_chars as usize).min(MAX_GEOHASH_LENGTH))
+            }
+            _ => None,
+        },
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized ST_GeoHash maxchars not yet implemented".to_string(),
+            )
+            .into());
+        }
+    };
+
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
         .into_iter()
         .next()
         .unwrap();
-    let point_array = PointArray::try_from((array.as_ref(), args.arg_fields[0].as_ref()))?;
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let rect_array = bounding_rect(&geo_array, false)?;
     let mut builder = StringViewBuilder::with_capacity(array.len());
 
-    for point in point_array.iter() {
-        if let Some(point) = point {
-            let coord = point?.coord().unwrap();
-            // TODO: make arg
-            // 12 is the max length supported by rust geohash. We should document this and maybe
-            // clamp numbers to 12.
-            let s = geohash::encode(coord.to_coord(), 12)?;
-            builder.append_value(s);
-        } else {
+    for rect in rect_array.iter() {
+        let Some(rect) = rect else {
+            builder.append_null();
+            continue;
+        };
+        let rect = rect?;
+        let (min, max) = (rect.min().to_coord(), rect.max().to_coord());
+        // The bounding box of an empty geometry is inverted
+        if min.x > max.x || min.y > max.y {
             builder.append_null();
+            continue;
         }
+
+        let hash = match max_chars {
+            Some(max_chars) => {
+                let center = Coord {
+                    x: (min.x + max.x) / 2.0,
+                    y: (min.y + max.y) / 2.0,
+                };
+                geohash::encode(center, max_chars)?
+            }
+            None => bbox_geohash(min, max)?,
+        };
+        builder.append_value(hash);
     }
 
     Ok(ColumnarValue::Array(Arc::new(builder.finish())))
 }
 
+/// The longest GeoHash whose cell contains the bounding box with the given corners.
+///
+/// A cell contains both corners of the box if and only if it contains the whole box, so this is
+/// the common prefix of the GeoHashes of the two corners.
+fn bbox_geohash(min: Coord, max: Coord) -> Result<String, geohash::GeohashError> {
+    let min_hash = geohash::encode(min, MAX_GEOHASH_LENGTH)?;
+    let max_hash = geohash::encode(max, MAX_GEOHASH_LENGTH)?;
+    let prefix_len = min_hash
+        .bytes()
+        .zip(max_hash.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    Ok(min_hash[..prefix_len].to_string())
+}
+
 #[cfg(test)]
 mod test {
     use arrow_array::cast::AsArray;
@@ -104,6 +151,7 @@ mod test {
 
     use super::*;
     use crate::udf::native::constructors::Point;
+    use crate::udf::native::io::GeomFromText;
 
     #[tokio::test]
     async fn test_geohash() {
@@ -122,4 +170,26 @@ mod test {
 
         assert_eq!(string_arr.value(0), "c0w3hf1s70w3");
     }
+
+    #[tokio::test]
+    async fn test_geohash_maxchars_and_polygon() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeoHash.into());
+        ctx.register_udf(Point::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_GeoHash(ST_Point(-126,48), 5),
+                    ST_GeoHash(ST_GeomFromText('POLYGON((-126 48,-125.9 48,-125.9 48.1,-126 48.1,-126 48))')),
+                    ST_GeoHash(ST_GeomFromText('LINESTRING(-126 48,-125.9 48.1)'), 2);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(batches[0].column(0).as_string_view().value(0), "c0w3h");
+        assert_eq!(batches[0].column(1).as_string_view().value(0), "c0w3");
+        assert_eq!(batches[0].column(2).as_string_view().value(0), "c0");
+    }
 }
@@ -0,0 +1,250 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{ListBuilder, StringViewBuilder};
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo::{BoundingRect, Coord, Intersects};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+
+use crate::error::GeoDataFusionResult;
+
+/// The maximum GeoHash length supported by the `geohash` crate.
+const MAX_GEOHASH_LENGTH: i64 = 12;
+
+/// The maximum number of cells considered when covering a single geometry.
+const MAX_COVER_CELLS: f64 = 1_000_000.0;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeoHashCover {
+    signature: Signature,
+}
+
+impl GeoHashCover {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for GeoHashCover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeoHashCover {
+    fn name(&self) -> &str {
+        "st_geohashcover"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            DataType::Utf8View,
+            true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geohash_cover_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the list of GeoHashes of the given precision whose cells intersect a geometry, ordered from south to north and then from west to east. Use unnest to return one row per GeoHash.",
+                "ST_GeoHashCover(geom, precision)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("precision", "integer between 1 and 12")
+            .build()
+        }))
+    }
+}
+
+fn geohash_cover_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let precision = match &args.args[1] {
+        ColumnarValue::Scalar(scalar) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(precision))
+                if (1..=MAX_GEOHASH_LENGTH).contains(&precision) =>
+            {
+                precision as usize
+            }
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "ST_GeoHashCover precision must be between 1 and {MAX_GEOHASH_LENGTH}"
+                ))
+                .into());
+            }
+        },
+        ColumnarValue::Array(_) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized ST_GeoHashCover precision not yet implemented".to_string(),
+            )
+            .into());
+        }
+    };
+
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let mut builder = ListBuilder::with_capacity(StringViewBuilder::new(), array.len());
+    geohash_cover(&geo_array, precision, &mut builder)?;
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+fn geohash_cover(
+    array: &dyn GeoArrowArray,
+    precision: usize,
+    builder: &mut ListBuilder<StringViewBuilder>,
+) -> GeoDataFusionResult<()> {
+    downcast_geoarrow_array!(array, _geohash_cover_impl, precision, builder)
+}
+
+fn _geohash_cover_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    precision: usize,
+    builder: &mut ListBuilder<StringViewBuilder>,
+) -> GeoDataFusionResult<()> {
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geometry_to_geo(&geom?)?;
+            for hash in cover_geometry(&geom, precision)? {
+                builder.values().append_value(hash);
+            }
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(())
+}
+
+/// The GeoHashes of the cells that intersect a geometry.
+///
+/// This walks the grid of cells over the bounding box of the geometry, and keeps the cells that
+/// intersect the geometry itself.
+fn cover_geometry(geom: &geo::Geometry, precision: usize) -> GeoDataFusionResult<Vec<String>> {
+    let Some(bbox) = geom.bounding_rect() else {
+        return Ok(vec![]);
+    };
+
+    // All cells of a given precision have the same size
+    let first_cell = geohash::decode_bbox(&geohash::encode(bbox.min(), precision)?)?;
+    let (width, height) = (first_cell.width(), first_cell.height());
+    // A bounding box that ends on a cell edge does not reach into the next cell
+    let columns = ((bbox.max().x - first_cell.min().x) / width)
+        .ceil()
+        .max(1.0);
+    let rows = ((bbox.max().y - first_cell.min().y) / height)
+        .ceil()
+        .max(1.0);
+    if columns * rows > MAX_COVER_CELLS {
+        return Err(DataFusionError::Execution(format!(
+            "ST_GeoHashCover would consider more than {MAX_COVER_CELLS} cells, use a lower precision"
+        ))
+        .into());
+    }
+
+    let mut hashes = vec![];
+    for row in 0..rows as usize {
+        for column in 0..columns as usize {
+            let center = Coord {
+                x: first_cell.min().x + (column as f64 + 0.5) * width,
+                y: first_cell.min().y + (row as f64 + 0.5) * height,
+            };
+            // Rounding may reach past the antimeridian or the poles, where there are no cells
+            if center.x > 180.0 || center.y > 90.0 {
+                continue;
+            }
+            let hash = geohash::encode(center, precision)?;
+            if geom.intersects(&geohash::decode_bbox(&hash)?) {
+                hashes.push(hash);
+            }
+        }
+    }
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_geohash_cover() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeoHashCover::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        // A triangle over a block of 3 by 2 cells, which misses the two northeastern cells
+        let df = ctx
+            .sql(
+                "SELECT ST_GeoHashCover(ST_GeomFromText('POLYGON((-122.4 37.75,-122.34 37.75,-122.4 37.8,-122.4 37.75))'), 5);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let list = batches[0].column(0).as_list::<i32>().value(0);
+        let hashes = list.as_string_view();
+        let hashes = (0..hashes.len())
+            .map(|i| hashes.value(i))
+            .collect::<Vec<_>>();
+        assert_eq!(hashes, ["9q8yy", "9q8yz", "9q9nb", "9q8zn"]);
+    }
+
+    #[tokio::test]
+    async fn test_geohash_cover_world_edges() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeoHashCover::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_GeoHashCover(ST_GeomFromText('POINT(180 0)'), 5),
+                    ST_GeoHashCover(ST_GeomFromText('POINT(-180 -90)'), 5),
+                    ST_GeoHashCover(ST_GeomFromText('LINESTRING(170 80,180 90)'), 3);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+
+        let hashes = |column: usize| {
+            let list = batches[0].column(column).as_list::<i32>().value(0);
+            list.as_string_view()
+                .iter()
+                .map(|hash| hash.unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hashes(0), ["xbpbp"]);
+        assert_eq!(hashes(1), ["00000"]);
+        let line = hashes(2);
+        assert!(line.contains(&"zzz".to_string()), "{line:?}");
+        for hash in &line {
+            let cell = geohash::decode_bbox(hash).unwrap();
+            assert!(cell.max().x <= 180.0 && cell.max().y <= 90.0);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArrayType;
+use arrow_array::builder::{ListBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeoHashNeighbors {
+    signature: Signature,
+}
+
+impl GeoHashNeighbors {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for GeoHashNeighbors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeoHashNeighbors {
+    fn name(&self) -> &str {
+        "st_geohashneighbors"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            DataType::Utf8View,
+            true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geohash_neighbors_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the 8 GeoHashes of the same length that surround a GeoHash, in the order north, northeast, east, southeast, south, southwest, west and northwest.",
+                "ST_GeoHashNeighbors(geohash)",
+            )
+            .with_argument("text", "geohash")
+            .build()
+        }))
+    }
+}
+
+fn geohash_neighbors_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let mut builder = ListBuilder::with_capacity(StringViewBuilder::new(), array.len());
+    match array.data_type() {
+        DataType::Utf8 => push_neighbors(&mut builder, &array.as_string::<i32>()),
+        DataType::LargeUtf8 => push_neighbors(&mut builder, &array.as_string::<i64>()),
+        DataType::Utf8View => push_neighbors(&mut builder, &array.as_string_view()),
+        _ => unreachable!(),
+    }?;
+
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+fn push_neighbors<'a>(
+    builder: &mut ListBuilder<StringViewBuilder>,
+    array: &impl StringArrayType<'a>,
+) -> GeoDataFusionResult<()> {
+    for s in array.iter() {
+        if let Some(s) = s {
+            let neighbors = geohash::neighbors(s)?;
+            for neighbor in [
+                neighbors.n,
+                neighbors.ne,
+                neighbors.e,
+                neighbors.se,
+                neighbors.s,
+                neighbors.sw,
+                neighbors.w,
+                neighbors.nw,
+            ] {
+                builder.values().append_value(neighbor);
+            }
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_geohash_neighbors() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeoHashNeighbors::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_GeoHashNeighbors('9q8yy');")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let list = batches[0].column(0).as_list::<i32>().value(0);
+        let neighbors = list.as_string_view();
+        let neighbors = (0..neighbors.len())
+            .map(|i| neighbors.value(i))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            [
+                "9q8zn", "9q8zp", "9q8yz", "9q8yx", "9q8yw", "9q8yt", "9q8yv", "9q8zj"
+            ]
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArrayType;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::PolygonArray;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PolygonType};
+
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromGeoHash {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromGeoHash {
+    pub fn new(coord_type: CoordType) -> Self {
+        let mut variants = vec![];
+        for input_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            variants.push(TypeSignature::Exact(vec![input_type.clone()]));
+            variants.push(TypeSignature::Exact(vec![input_type, DataType::Int64]));
+        }
+        Self {
+            signature: Signature::one_of(variants, Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromGeoHash {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromGeoHash {
+    fn name(&self) -> &str {
+        "st_geomfromgeohash"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_geohash_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return a polygon of the cell of a GeoHash string. If precision is given, only the first precision characters of the GeoHash are used.",
+                "ST_GeomFromGeoHash(geohash, precision)",
+            )
+            .with_argument("text", "geohash")
+            .with_argument("precision", "integer, optional")
+            .build()
+        }))
+    }
+}
+
+fn return_field_impl(
+    args: ReturnFieldArgs,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<FieldRef> {
+    let metadata = Arc::new(Metadata::try_from(args.arg_fields[0].as_ref()).unwrap_or_default());
+    let output_type = PolygonType::new(Dimension::XY, metadata).with_coord_type(coord_type);
+    Ok(Arc::new(output_type.to_field("", true)))
+}
+
+fn geom_from_geohash_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let precision = match args.args.get(1) {
+        None => None,
+        Some(ColumnarValue::Scalar(ScalarValue::Int64(Some(precision)))) if *precision > 0 => {
+            Some(*precision as usize)
+        }
+        Some(ColumnarValue::Scalar(_)) => None,
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized ST_GeomFromGeoHash precision not yet implemented".to_string(),
+            )
+            .into());
+        }
+    };
+
+    let array = ColumnarValue::values_to_arrays(&args.args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let polygon_arr = match array.data_type() {
+        DataType::Utf8 => build_polygon_arr(typ, &array.as_string::<i32>(), precision),
+        DataType::LargeUtf8 => build_polygon_arr(typ, &array.as_string::<i64>(), precision),
+        DataType::Utf8View => build_polygon_arr(typ, &array.as_string_view(), precision),
+        _ => unreachable!(),
+    }?;
+
+    Ok(ColumnarValue::Array(polygon_arr.into_array_ref()))
+}
+
+fn build_polygon_arr<'a>(
+    typ: PolygonType,
+    array: &impl StringArrayType<'a>,
+    precision: Option<usize>,
+) -> GeoDataFusionResult<PolygonArray> {
+    let mut builder = PolygonBuilder::with_capacity(typ, Default::default());
+    for s in array.iter() {
+        if let Some(s) = s {
+            let s = match precision {
+                Some(precision) => s.get(..precision).unwrap_or(s),
+                None => s,
+            };
+            let rect = geohash::decode_bbox(s)?;
+            let (min, max) = (rect.min(), rect.max());
+            // Same ring order as PostGIS
+            let polygon = geo::Polygon::new(
+                geo::LineString::from(vec![
+                    (min.x, min.y),
+                    (min.x, max.y),
+                    (max.x, max.y),
+                    (max.x, min.y),
+                    (min.x, min.y),
+                ]),
+                vec![],
+            );
+            builder.push_polygon(Some(&polygon))?;
+        } else {
+            builder.push_polygon(None::<&geo::Polygon>)?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::AsText;
+
+    #[tokio::test]
+    async fn test_geom_from_geohash() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGeoHash::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql("SELECT ST_AsText(ST_GeomFromGeoHash('9qqj7nmxncgyy4d0dbxqz0', 4));")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((-115.3125 36.03515625,-115.3125 36.2109375,-114.9609375 36.2109375,-114.9609375 36.03515625,-115.3125 36.03515625))"
+        );
+    }
+}
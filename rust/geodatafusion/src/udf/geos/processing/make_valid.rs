@@ -0,0 +1,249 @@
+use std::sync::LazyLock;
+
+use arrow_array::{Array, BinaryArray};
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::cast::{from_wkb, to_wkb};
+use geoarrow_schema::{CoordType, GeoArrowType};
+use geos::{Geom, Geometry, MakeValidMethod, MakeValidParams};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::geo::validation::{
+    RepairMethod, RepairOptions, make_valid_documentation, make_valid_return_field,
+};
+
+/// A single geometry argument, optionally followed by the params string.
+static SIGNATURE: LazyLock<Signature> = LazyLock::new(|| {
+    Signature::one_of(
+        vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+        Volatility::Immutable,
+    )
+});
+
+/// Repairs invalid geometries with the GEOS MakeValid algorithms.
+///
+/// This replaces the pure-Rust [`crate::udf::geo::validation::MakeValid`] when registered, and
+/// accepts the same params.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct MakeValid {
+    coord_type: CoordType,
+}
+
+impl MakeValid {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for MakeValid {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl ScalarUDFImpl for MakeValid {
+    fn name(&self) -> &str {
+        "st_makevalid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &SIGNATURE
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(make_valid_return_field(args, self.coord_type))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(make_valid_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(make_valid_documentation())
+    }
+}
+
+fn make_valid_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let options = RepairOptions::from_args(&args)?;
+    let params = MakeValidParams::builder()
+        .method(match options.method {
+            RepairMethod::Linework => MakeValidMethod::Linework,
+            RepairMethod::Structure => MakeValidMethod::Structure,
+        })
+        .keep_collapsed(options.keep_collapsed)
+        .build()?;
+
+    let arrays = ColumnarValue::values_to_arrays(&args.args[0..1])?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let metadata = geo_array.data_type().metadata().clone();
+
+    // Bridge to GEOS via WKB, as in ST_LineMerge.
+    let wkb_array = to_wkb::<i32>(geo_array.as_ref())?;
+
+    let mut repaired: Vec<Option<Vec<u8>>> = Vec::with_capacity(wkb_array.inner().len());
+    for maybe_wkb in wkb_array.inner() {
+        match maybe_wkb {
+            None => repaired.push(None),
+            Some(wkb) => {
+                let geom = Geometry::new_from_wkb(wkb)?;
+                if geom.is_valid()? {
+                    // Valid geometries are returned unchanged, as with PostGIS.
+                    repaired.push(Some(wkb.to_vec()));
+                } else {
+                    repaired.push(Some(geom.make_valid_with_params(&params)?.to_wkb()?));
+                }
+            }
+        }
+    }
+
+    let result_wkb = WkbArray::new(repaired.into_iter().collect::<BinaryArray>(), metadata);
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+    let result = from_wkb(&result_wkb, to_type)?;
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::geo::measurement::Area;
+    use crate::udf::geo::validation::IsValid;
+    use crate::udf::native::accessors::ST_GeometryType;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    fn ctx() -> SessionContext {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MakeValid::default().into());
+        ctx.register_udf(IsValid.into());
+        ctx.register_udf(Area.into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+        ctx.register_udf(ST_GeometryType.into());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_st_makevalid() {
+        let ctx = ctx();
+
+        let df = ctx
+            .sql(
+                "SELECT ST_IsValid(g), ST_Area(g)
+                FROM (SELECT ST_MakeValid(ST_GeomFromText(wkt), 'method=structure') AS g FROM (VALUES
+                    ('POLYGON((0 0,2 2,2 0,0 2,0 0))'),
+                    ('MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)),((1 1,3 1,3 3,1 3,1 1)))')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let valid = batch.column(0).as_boolean();
+        let area = batch.column(1).as_primitive::<Float64Type>();
+
+        assert!(valid.iter().all(|v| v == Some(true)));
+        assert_eq!(area.value(0), 2.0);
+        assert_eq!(area.value(1), 7.0);
+    }
+
+    #[tokio::test]
+    async fn test_st_makevalid_linework() {
+        let ctx = ctx();
+
+        // The exact vertices of a repaired geometry depend on the GEOS noding, so only check
+        // that each result is valid and of the expected type and area
+        let df = ctx
+            .sql(
+                "SELECT ST_IsValid(g), ST_GeometryType(g), ST_Area(g)
+                FROM (SELECT ST_MakeValid(ST_GeomFromText(wkt), 'method=linework') AS g FROM (VALUES
+                    ('POLYGON((0 0,2 2,2 0,0 2,0 0))'),
+                    ('POLYGON((0 0,1 0,1 0,0 0))')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let valid = batch.column(0).as_boolean();
+        let geometry_type = batch.column(1).as_string_view();
+        let area = batch.column(2).as_primitive::<Float64Type>();
+
+        assert!(valid.iter().all(|v| v == Some(true)));
+        assert_eq!(geometry_type.value(0), "ST_MultiPolygon");
+        assert_eq!(area.value(0), 2.0);
+        // A polygon collapsed to a line keeps its linework
+        assert!(
+            matches!(
+                geometry_type.value(1),
+                "ST_LineString" | "ST_MultiLineString"
+            ),
+            "{}",
+            geometry_type.value(1)
+        );
+        assert_eq!(area.value(1), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_st_makevalid_collapsed_part() {
+        let ctx = ctx();
+
+        // A valid part and a part collapsed to a line, which linework keeps alongside the area
+        let df = ctx
+            .sql(
+                "SELECT ST_IsValid(g), ST_GeometryType(g), ST_Area(g)
+                FROM (SELECT ST_MakeValid(ST_GeomFromText(
+                    'MULTIPOLYGON(((0 0,2 2,2 0,0 2,0 0)),((5 5,6 5,5 5)))'
+                )) AS g);",
+            )
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        assert!(batch.column(0).as_boolean().value(0));
+        assert_eq!(
+            batch.column(1).as_string_view().value(0),
+            "ST_GeometryCollection"
+        );
+        assert_eq!(batch.column(2).as_primitive::<Float64Type>().value(0), 2.0);
+    }
+
+    #[tokio::test]
+    async fn test_st_makevalid_keep_collapsed() {
+        let ctx = ctx();
+
+        let cases = [
+            ("method=structure keepcollapsed=false", "LINESTRING EMPTY"),
+            ("method=structure keepcollapsed=true", "POINT(1 1)"),
+        ];
+        for (params, expected) in cases {
+            let sql = format!(
+                "SELECT ST_AsText(ST_MakeValid(ST_GeomFromText('LINESTRING(1 1,1 1)'), '{params}'))"
+            );
+            let batch = ctx
+                .sql(&sql)
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap();
+            assert_eq!(
+                batch.column(0).as_string::<i32>().value(0),
+                expected,
+                "Failed on {params}"
+            );
+        }
+    }
+}
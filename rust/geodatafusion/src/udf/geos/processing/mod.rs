@@ -1,9 +1,15 @@
 mod line_merge;
+mod make_valid;
 
 #[cfg(feature = "geos-3_11")]
 pub use line_merge::LineMerge;
+#[cfg(feature = "geos-3_11")]
+pub use make_valid::MakeValid;
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
     #[cfg(feature = "geos-3_11")]
     session_context.register_udf(LineMerge::default().into());
+    // Replaces the pure-Rust ST_MakeValid
+    #[cfg(feature = "geos-3_11")]
+    session_context.register_udf(MakeValid::default().into());
 }
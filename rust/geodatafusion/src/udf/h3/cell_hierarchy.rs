@@ -0,0 +1,211 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::UInt64Array;
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use h3o::CellIndex;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::h3::util::{cell_arg, cell_list_type, resolution_arg};
+
+/// The maximum number of children returned for a single cell.
+const MAX_CHILDREN: u64 = 1 << 20;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CellToParent {
+    signature: Signature,
+}
+
+impl CellToParent {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for CellToParent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static CELL_TO_PARENT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellToParent {
+    fn name(&self) -> &str {
+        "h3_cell_to_parent"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(cell_to_parent_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(CELL_TO_PARENT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cell of the given resolution that contains a cell. The resolution must not be finer than the resolution of the cell.",
+                "h3_cell_to_parent(cell, resolution)",
+            )
+            .with_argument("cell", "H3 cell index")
+            .with_argument("resolution", "integer between 0 and 15")
+            .build()
+        }))
+    }
+}
+
+fn cell_to_parent_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args, 1)?;
+    let result = cell_arg(&args, 0)?
+        .iter()
+        .map(|cell| {
+            let Some(cell) = cell else {
+                return Ok(None);
+            };
+            let cell = CellIndex::try_from(cell)?;
+            let parent = cell.parent(resolution).ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "h3_cell_to_parent resolution {resolution} is finer than the cell resolution {}",
+                    cell.resolution()
+                ))
+            })?;
+            Ok(Some(u64::from(parent)))
+        })
+        .collect::<GeoDataFusionResult<UInt64Array>>()?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CellToChildren {
+    signature: Signature,
+}
+
+impl CellToChildren {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for CellToChildren {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static CELL_TO_CHILDREN_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellToChildren {
+    fn name(&self) -> &str {
+        "h3_cell_to_children"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(cell_list_type())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(cell_to_children_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(CELL_TO_CHILDREN_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cells of the given resolution contained in a cell. The resolution must not be coarser than the resolution of the cell, and at most 1048576 children are returned per cell.",
+                "h3_cell_to_children(cell, resolution)",
+            )
+            .with_argument("cell", "H3 cell index")
+            .with_argument("resolution", "integer between 0 and 15")
+            .build()
+        }))
+    }
+}
+
+fn cell_to_children_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args, 1)?;
+    let cells = cell_arg(&args, 0)?;
+    let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), cells.len());
+    for cell in cells.iter() {
+        let Some(cell) = cell else {
+            builder.append_null();
+            continue;
+        };
+        let cell = CellIndex::try_from(cell)?;
+        if resolution < cell.resolution() {
+            return Err(DataFusionError::Execution(format!(
+                "h3_cell_to_children resolution {resolution} is coarser than the cell resolution {}",
+                cell.resolution()
+            ))
+            .into());
+        }
+        if cell.children_count(resolution) > MAX_CHILDREN {
+            return Err(DataFusionError::Execution(format!(
+                "h3_cell_to_children would return more than {MAX_CHILDREN} cells, use a coarser resolution"
+            ))
+            .into());
+        }
+        for child in cell.children(resolution) {
+            builder.values().append_value(u64::from(child));
+        }
+        builder.append(true);
+    }
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cell_hierarchy() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(CellToParent::default().into());
+        ctx.register_udf(CellToChildren::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT h3_cell_to_parent(617700169958293503, 8),
+                    h3_cell_to_children(617700169958293503, 10);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_primitive::<UInt64Type>().value(0),
+            0x8828308281fffff
+        );
+        let children = batches[0].column(1).as_list::<i32>().value(0);
+        assert_eq!(children.len(), 7);
+        assert_eq!(
+            children.as_primitive::<UInt64Type>().value(0),
+            0x8a28308280c7fff
+        );
+    }
+}
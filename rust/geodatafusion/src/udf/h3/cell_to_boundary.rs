@@ -0,0 +1,119 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PolygonType};
+use h3o::CellIndex;
+
+use crate::crs::crs_from_srid;
+use crate::error::GeoDataFusionResult;
+use crate::udf::h3::util::{cell_arg, cell_polygon};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CellToBoundary {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl CellToBoundary {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(1)], Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for CellToBoundary {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellToBoundary {
+    fn name(&self) -> &str {
+        "h3_cell_to_boundary"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        let metadata = Arc::new(Metadata::new(crs_from_srid(4326), None));
+        let output_type =
+            PolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(cell_to_boundary_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the boundary of an H3 cell as a polygon with the EPSG:4326 CRS. Cells crossing the antimeridian are not split.",
+                "h3_cell_to_boundary(cell)",
+            )
+            .with_argument("cell", "H3 cell index")
+            .build()
+        }))
+    }
+}
+
+fn cell_to_boundary_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let cells = cell_arg(&args, 0)?;
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let mut builder = PolygonBuilder::with_capacity(typ, Default::default());
+    for cell in cells.iter() {
+        if let Some(cell) = cell {
+            let polygon = cell_polygon(CellIndex::try_from(cell)?);
+            builder.push_polygon(Some(&polygon))?;
+        } else {
+            builder.push_polygon(None::<&geo::Polygon>)?;
+        }
+    }
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::AsText;
+
+    #[tokio::test]
+    async fn test_cell_to_boundary() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(CellToBoundary::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql("SELECT ST_AsText(h3_cell_to_boundary(617700169958293503));")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let polygon = batches[0].column(0).as_string::<i32>().value(0);
+        // A hexagon around the center of the cell, at 37.7759 N 122.4180 W
+        assert!(polygon.starts_with("POLYGON((-122.41719971841655 37.77519778289337,"));
+        assert_eq!(polygon.matches(',').count(), 6);
+    }
+}
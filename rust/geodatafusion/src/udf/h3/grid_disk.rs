@@ -0,0 +1,124 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use h3o::CellIndex;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::h3::util::{cell_arg, cell_list_type, u32_arg};
+
+/// The maximum grid distance, which bounds the output at about 3 million cells per row.
+const MAX_K: u32 = 1000;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GridDisk {
+    signature: Signature,
+}
+
+impl GridDisk {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for GridDisk {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GridDisk {
+    fn name(&self) -> &str {
+        "h3_grid_disk"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(cell_list_type())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(grid_disk_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cells within grid distance k of a cell, including the cell itself.",
+                "h3_grid_disk(cell, k)",
+            )
+            .with_argument("cell", "H3 cell index")
+            .with_argument("k", "integer between 0 and 1000")
+            .build()
+        }))
+    }
+}
+
+fn grid_disk_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let k = u32_arg(&args, 1, "k")?;
+    if k > MAX_K {
+        return Err(
+            DataFusionError::Execution(format!("h3_grid_disk k must be at most {MAX_K}")).into(),
+        );
+    }
+
+    let cells = cell_arg(&args, 0)?;
+    let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), cells.len());
+    for cell in cells.iter() {
+        if let Some(cell) = cell {
+            let disk: Vec<CellIndex> = CellIndex::try_from(cell)?.grid_disk(k);
+            for neighbor in disk {
+                builder.values().append_value(u64::from(neighbor));
+            }
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_grid_disk() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GridDisk::default().into());
+
+        let df = ctx
+            .sql("SELECT h3_grid_disk(617700169958293503, 1), h3_grid_disk(617700169958293503, 2);")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let disk = batches[0].column(0).as_list::<i32>().value(0);
+        assert_eq!(disk.len(), 7);
+        assert!(
+            disk.as_primitive::<UInt64Type>()
+                .values()
+                .contains(&617700169958293503)
+        );
+        assert_eq!(batches[0].column(1).as_list::<i32>().value(0).len(), 19);
+    }
+}
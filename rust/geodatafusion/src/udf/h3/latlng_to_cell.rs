@@ -0,0 +1,138 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::UInt64Array;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowError;
+use h3o::{LatLng, Resolution};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::h3::util::resolution_arg;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct LatLngToCell {
+    signature: Signature,
+}
+
+impl LatLngToCell {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for LatLngToCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for LatLngToCell {
+    fn name(&self) -> &str {
+        "h3_latlng_to_cell"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(latlng_to_cell_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cell of the given resolution that contains a point. The point is expected to be in longitude/latitude order. Empty points return NULL.",
+                "h3_latlng_to_cell(point, resolution)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("resolution", "integer between 0 and 15")
+            .build()
+        }))
+    }
+}
+
+fn latlng_to_cell_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args, 1)?;
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let result = latlng_to_cell(&geo_array, resolution)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn latlng_to_cell(
+    array: &dyn GeoArrowArray,
+    resolution: Resolution,
+) -> GeoDataFusionResult<UInt64Array> {
+    downcast_geoarrow_array!(array, _latlng_to_cell_impl, resolution)
+}
+
+fn _latlng_to_cell_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    resolution: Resolution,
+) -> GeoDataFusionResult<UInt64Array> {
+    array
+        .iter()
+        .map(|item| {
+            let Some(geom) = item.transpose()? else {
+                return Ok(None);
+            };
+            let GeometryType::Point(point) = geom.as_type() else {
+                return Err(GeoArrowError::IncorrectGeometryType(
+                    "h3_latlng_to_cell only supports Point geometries".to_string(),
+                )
+                .into());
+            };
+            let Some(coord) = point.coord() else {
+                return Ok(None);
+            };
+            let cell = LatLng::new(coord.y(), coord.x())?.to_cell(resolution);
+            Ok(Some(u64::from(cell)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_latlng_to_cell() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(LatLngToCell::default().into());
+        ctx.register_udf(Point::default().into());
+
+        let df = ctx
+            .sql("SELECT h3_latlng_to_cell(ST_Point(-122.0553238, 37.3615593), 7);")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_primitive::<UInt64Type>().value(0),
+            0x87283472bffffff
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! H3 hexagonal grid functions, using the `h3o` crate.
+
+mod cell_hierarchy;
+mod cell_to_boundary;
+mod grid_disk;
+mod latlng_to_cell;
+mod polygon_to_cells;
+mod util;
+
+pub use cell_hierarchy::{CellToChildren, CellToParent};
+pub use cell_to_boundary::CellToBoundary;
+pub use grid_disk::GridDisk;
+pub use latlng_to_cell::LatLngToCell;
+pub use polygon_to_cells::PolygonToCells;
+
+pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(CellToBoundary::default().into());
+    session_context.register_udf(CellToChildren::default().into());
+    session_context.register_udf(CellToParent::default().into());
+    session_context.register_udf(GridDisk::default().into());
+    session_context.register_udf(LatLngToCell::default().into());
+    session_context.register_udf(PolygonToCells::default().into());
+}
@@ -0,0 +1,191 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo::{Contains, CoordsIter, Intersects};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use h3o::{CellIndex, LatLng, Resolution};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::h3::util::{cell_center, cell_list_type, cell_polygon, resolution_arg};
+
+/// The maximum number of cells visited when filling a single geometry.
+const MAX_VISITED_CELLS: usize = 1 << 20;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct PolygonToCells {
+    signature: Signature,
+}
+
+impl PolygonToCells {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for PolygonToCells {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for PolygonToCells {
+    fn name(&self) -> &str {
+        "h3_polygon_to_cells"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(cell_list_type())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(polygon_to_cells_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the H3 cells of the given resolution whose centers are inside a polygon, in ascending order. The polygon is expected to be in longitude/latitude order.",
+                "h3_polygon_to_cells(geom, resolution)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("resolution", "integer between 0 and 15")
+            .build()
+        }))
+    }
+}
+
+fn polygon_to_cells_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let resolution = resolution_arg(&args, 1)?;
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let mut builder = ListBuilder::with_capacity(UInt64Builder::new(), array.len());
+    polygon_to_cells(&geo_array, resolution, &mut builder)?;
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+fn polygon_to_cells(
+    array: &dyn GeoArrowArray,
+    resolution: Resolution,
+    builder: &mut ListBuilder<UInt64Builder>,
+) -> GeoDataFusionResult<()> {
+    downcast_geoarrow_array!(array, _polygon_to_cells_impl, resolution, builder)
+}
+
+fn _polygon_to_cells_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    resolution: Resolution,
+    builder: &mut ListBuilder<UInt64Builder>,
+) -> GeoDataFusionResult<()> {
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geometry_to_geo(&geom?)?;
+            for cell in fill_geometry(&geom, resolution)? {
+                builder.values().append_value(u64::from(cell));
+            }
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(())
+}
+
+/// The cells whose centers are inside a geometry.
+///
+/// This floods the grid from the cells of the vertices of the geometry, through the cells that
+/// intersect the geometry. Every cell whose center is inside the geometry is reached, as the
+/// cells intersecting each polygon are connected and contain one of its vertices.
+fn fill_geometry(
+    geom: &geo::Geometry,
+    resolution: Resolution,
+) -> GeoDataFusionResult<Vec<CellIndex>> {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    for coord in geom.coords_iter() {
+        let cell = LatLng::new(coord.y, coord.x)?.to_cell(resolution);
+        if visited.insert(cell) {
+            queue.push_back(cell);
+        }
+    }
+
+    let mut cells = vec![];
+    while let Some(cell) = queue.pop_front() {
+        if !geom.intersects(&cell_polygon(cell)) {
+            continue;
+        }
+        if geom.contains(&cell_center(cell)) {
+            cells.push(cell);
+        }
+        for neighbor in cell.grid_disk::<Vec<_>>(1) {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+        if visited.len() > MAX_VISITED_CELLS {
+            return Err(DataFusionError::Execution(format!(
+                "h3_polygon_to_cells would visit more than {MAX_VISITED_CELLS} cells, use a coarser resolution"
+            ))
+            .into());
+        }
+    }
+    cells.sort();
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::h3::CellToBoundary;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_polygon_to_cells() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(PolygonToCells::default().into());
+        ctx.register_udf(CellToBoundary::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT h3_polygon_to_cells(h3_cell_to_boundary(617700169958293503), 9),
+                    h3_polygon_to_cells(ST_GeomFromText('POLYGON((-122.42 37.77,-122.40 37.77,-122.40 37.79,-122.42 37.79,-122.42 37.77))'), 7);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        // A cell boundary only contains the center of that cell
+        let cells = batches[0].column(0).as_list::<i32>().value(0);
+        assert_eq!(
+            cells.as_primitive::<UInt64Type>().values(),
+            &[617700169958293503]
+        );
+        // A box of about 2 by 2 km contains the center of a single cell of about 5 km²
+        let cells = batches[0].column(1).as_list::<i32>().value(0);
+        assert_eq!(cells.len(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use arrow_array::UInt64Array;
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion::scalar::ScalarValue;
+use h3o::{CellIndex, LatLng, Resolution};
+
+/// The data type of a list of H3 cells.
+pub(super) fn cell_list_type() -> DataType {
+    DataType::List(Arc::new(Field::new_list_field(DataType::UInt64, true)))
+}
+
+/// The H3 cells of the argument at `index`, cast from any integer type.
+pub(super) fn cell_arg(args: &ScalarFunctionArgs, index: usize) -> Result<UInt64Array> {
+    let array = args.args[index]
+        .cast_to(&DataType::UInt64, None)?
+        .into_array(args.number_rows)?;
+    Ok(array.as_primitive::<UInt64Type>().clone())
+}
+
+/// The non-negative integer scalar argument at `index`.
+pub(super) fn u32_arg(args: &ScalarFunctionArgs, index: usize, name: &str) -> Result<u32> {
+    match &args.args[index] {
+        ColumnarValue::Scalar(scalar) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(value)) => u32::try_from(value)
+                .map_err(|_| DataFusionError::Execution(format!("H3 {name} must not be negative"))),
+            _ => Err(DataFusionError::Execution(format!(
+                "H3 {name} must not be null"
+            ))),
+        },
+        ColumnarValue::Array(_) => Err(DataFusionError::NotImplemented(format!(
+            "Vectorized H3 {name} not yet implemented"
+        ))),
+    }
+}
+
+/// The H3 resolution scalar argument at `index`.
+pub(super) fn resolution_arg(args: &ScalarFunctionArgs, index: usize) -> Result<Resolution> {
+    let resolution = u32_arg(args, index, "resolution")?;
+    u8::try_from(resolution)
+        .ok()
+        .and_then(|resolution| Resolution::try_from(resolution).ok())
+        .ok_or_else(|| {
+            DataFusionError::Execution(format!(
+                "H3 resolution must be between 0 and 15, got {resolution}"
+            ))
+        })
+}
+
+/// The boundary of an H3 cell as a polygon of longitude/latitude coordinates.
+///
+/// Cells crossing the antimeridian are not split.
+pub(super) fn cell_polygon(cell: CellIndex) -> geo::Polygon {
+    let mut coords = cell
+        .boundary()
+        .iter()
+        .map(|vertex| geo::coord! { x: vertex.lng(), y: vertex.lat() })
+        .collect::<Vec<_>>();
+    coords.push(coords[0]);
+    geo::Polygon::new(geo::LineString::new(coords), vec![])
+}
+
+/// The center of an H3 cell as a longitude/latitude point.
+pub(super) fn cell_center(cell: CellIndex) -> geo::Point {
+    let center = LatLng::from(cell);
+    geo::Point::new(center.lng(), center.lat())
+}
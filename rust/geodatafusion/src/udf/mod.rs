@@ -2,4 +2,7 @@ pub mod geo;
 pub mod geohash;
 #[cfg(feature = "geos-3_11")]
 pub mod geos;
+pub mod h3;
 pub mod native;
+pub mod quadkey;
+pub mod s2;
@@ -0,0 +1,371 @@
+//! Accessors from multi-geometries and geometry collections
+
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::UInt32Builder;
+use arrow_array::{Int64Array, UInt32Array};
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::{GeometryBuilder, LineStringBuilder, PointBuilder, PolygonBuilder};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{
+    CoordType, GeoArrowType, GeometryType, LineStringType, PointType, PolygonType,
+};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::accessors::is_empty::is_geometry_topologically_empty;
+use crate::udf::native::accessors::util::{index_arg, resolve_index};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct NumGeometries;
+
+impl NumGeometries {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for NumGeometries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static NUM_GEOMETRIES_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for NumGeometries {
+    fn name(&self) -> &str {
+        "st_numgeometries"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(num_geometries_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(NUM_GEOMETRIES_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the number of elements in a geometry collection (GEOMETRYCOLLECTION or MULTI*). For non-empty atomic geometries returns 1. For empty geometries returns 0.",
+                "ST_NumGeometries(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeometryN {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeometryN {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeometryN {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOMETRY_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeometryN {
+    fn name(&self) -> &str {
+        "st_geometryn"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geometry_n_impl(args, self.coord_type)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOMETRY_N_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the 1-based Nth element geometry of an input geometry which is a GEOMETRYCOLLECTION or MULTI*. For non-empty atomic geometries, returns the geometry itself if N is 1. Returns NULL if the index is out of range.",
+                "ST_GeometryN(geometry, 1)",
+            )
+            .with_argument("g1", "geometry")
+            .with_argument("n", "integer, 1-based")
+            .build()
+        }))
+    }
+}
+
+/// The number of member geometries, counting a non-empty atomic geometry as one member
+fn num_geometries(geom: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        MultiPoint(g) => g.num_points(),
+        MultiLineString(g) => g.num_line_strings(),
+        MultiPolygon(g) => g.num_polygons(),
+        GeometryCollection(g) => g.num_geometries(),
+        _ => usize::from(!is_geometry_topologically_empty(geom)),
+    }
+}
+
+fn num_geometries_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+    let out = downcast_geoarrow_array!(geo_array_ref, num_geometries_array)?;
+    Ok(ColumnarValue::Array(Arc::new(out)))
+}
+
+fn num_geometries_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<UInt32Array> {
+    let mut builder = UInt32Builder::with_capacity(array.len());
+
+    for item in array.iter() {
+        if let Some(geom) = item {
+            builder.append_value(num_geometries(&geom?) as u32);
+        } else {
+            builder.append_null();
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+fn return_field_impl(
+    args: ReturnFieldArgs,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<FieldRef> {
+    let input_type = GeoArrowType::from_arrow_field(args.arg_fields[0].as_ref())?;
+    let output_type = output_type(&input_type, coord_type);
+    Ok(Arc::new(output_type.to_field("", true)))
+}
+
+/// Members of Point, MultiPoint, LineString, MultiLineString, Polygon and MultiPolygon input have
+/// a fixed-dim output type. Members of other geometry input have a variable-dim Geometry output.
+fn output_type(input_type: &GeoArrowType, coord_type: CoordType) -> GeoArrowType {
+    match input_type {
+        GeoArrowType::Point(typ) => GeoArrowType::Point(
+            PointType::new(typ.dimension(), typ.metadata().clone()).with_coord_type(coord_type),
+        ),
+        GeoArrowType::MultiPoint(typ) => GeoArrowType::Point(
+            PointType::new(typ.dimension(), typ.metadata().clone()).with_coord_type(coord_type),
+        ),
+        GeoArrowType::LineString(typ) => GeoArrowType::LineString(
+            LineStringType::new(typ.dimension(), typ.metadata().clone())
+                .with_coord_type(coord_type),
+        ),
+        GeoArrowType::MultiLineString(typ) => GeoArrowType::LineString(
+            LineStringType::new(typ.dimension(), typ.metadata().clone())
+                .with_coord_type(coord_type),
+        ),
+        GeoArrowType::Polygon(typ) => GeoArrowType::Polygon(
+            PolygonType::new(typ.dimension(), typ.metadata().clone()).with_coord_type(coord_type),
+        ),
+        GeoArrowType::MultiPolygon(typ) => GeoArrowType::Polygon(
+            PolygonType::new(typ.dimension(), typ.metadata().clone()).with_coord_type(coord_type),
+        ),
+        _ => GeoArrowType::Geometry(
+            GeometryType::new(input_type.metadata().clone()).with_coord_type(coord_type),
+        ),
+    }
+}
+
+/// A builder that member geometries can be pushed into
+trait MemberBuilder {
+    fn push_member(&mut self, member: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()>;
+}
+
+impl MemberBuilder for PointBuilder {
+    fn push_member(&mut self, member: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        self.push_geometry(member)
+    }
+}
+
+impl MemberBuilder for LineStringBuilder {
+    fn push_member(&mut self, member: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        self.push_geometry(member)
+    }
+}
+
+impl MemberBuilder for PolygonBuilder {
+    fn push_member(&mut self, member: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        self.push_geometry(member)
+    }
+}
+
+impl MemberBuilder for GeometryBuilder {
+    fn push_member(&mut self, member: Option<&impl GeometryTrait<T = f64>>) -> GeoArrowResult<()> {
+        self.push_geometry(member)
+    }
+}
+
+fn geometry_n_impl(
+    args: ScalarFunctionArgs,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let indices = index_arg(&args, 1)?;
+    let arrays = ColumnarValue::values_to_arrays(&args.args[..1])?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+
+    let out: Arc<dyn GeoArrowArray> = match output_type(&geo_array.data_type(), coord_type) {
+        GeoArrowType::Point(typ) => {
+            let mut builder = PointBuilder::with_capacity(typ, geo_array.len());
+            downcast_geoarrow_array!(geo_array_ref, geometry_n_array, &indices, &mut builder)?;
+            Arc::new(builder.finish())
+        }
+        GeoArrowType::LineString(typ) => {
+            let mut builder = LineStringBuilder::new(typ);
+            downcast_geoarrow_array!(geo_array_ref, geometry_n_array, &indices, &mut builder)?;
+            Arc::new(builder.finish())
+        }
+        GeoArrowType::Polygon(typ) => {
+            let mut builder = PolygonBuilder::new(typ);
+            downcast_geoarrow_array!(geo_array_ref, geometry_n_array, &indices, &mut builder)?;
+            Arc::new(builder.finish())
+        }
+        GeoArrowType::Geometry(typ) => {
+            let mut builder = GeometryBuilder::new(typ);
+            downcast_geoarrow_array!(geo_array_ref, geometry_n_array, &indices, &mut builder)?;
+            Arc::new(builder.finish())
+        }
+        _ => unreachable!(),
+    };
+
+    Ok(out.into_array_ref().into())
+}
+
+fn geometry_n_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    indices: &Int64Array,
+    builder: &mut impl MemberBuilder,
+) -> GeoArrowResult<()> {
+    use geo_traits::GeometryType::*;
+
+    for (geom, n) in array.iter().zip(indices.iter()) {
+        let (Some(geom), Some(n)) = (geom, n) else {
+            builder.push_member(None::<&geo::Geometry>)?;
+            continue;
+        };
+        let geom = geom?;
+        let Some(i) = resolve_index(n, num_geometries(&geom), false) else {
+            builder.push_member(None::<&geo::Geometry>)?;
+            continue;
+        };
+        match geom.as_type() {
+            MultiPoint(g) => builder.push_member(g.point(i).as_ref())?,
+            MultiLineString(g) => builder.push_member(g.line_string(i).as_ref())?,
+            MultiPolygon(g) => builder.push_member(g.polygon(i).as_ref())?,
+            GeometryCollection(g) => builder.push_member(g.geometry(i).as_ref())?,
+            _ => builder.push_member(Some(&geom))?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt32Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_num_geometries() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(NumGeometries.into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_NumGeometries(ST_GeomFromText(wkt))
+                FROM (VALUES
+                    ('POINT(1 2)'),
+                    ('POINT EMPTY'),
+                    ('MULTILINESTRING((0 0,1 1),(2 2,3 3))'),
+                    ('GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1),POINT(3 4))')
+                ) AS t(wkt);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let counts = batches[0].column(0).as_primitive::<UInt32Type>();
+        assert_eq!(counts.values().as_ref(), &[1, 0, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_geometry_n() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeometryN::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_GeometryN(ST_GeomFromText(wkt), n))
+                FROM (VALUES
+                    ('MULTIPOINT((1 2),(3 4))', 2),
+                    ('GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))', 2),
+                    ('POLYGON((0 0,1 0,1 1,0 0))', 1),
+                    ('POLYGON((0 0,1 0,1 1,0 0))', 2),
+                    ('MULTIPOINT((1 2),(3 4))', 0),
+                    ('MULTIPOINT((1 2),(3 4))', -1)
+                ) AS t(wkt, n);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let wkt = batches[0].column(0).as_string::<i32>();
+        assert_eq!(wkt.value(0), "POINT(3 4)");
+        assert_eq!(wkt.value(1), "LINESTRING(0 0,1 1)");
+        assert_eq!(wkt.value(2), "POLYGON((0 0,1 0,1 1,0 0))");
+        assert!(wkt.is_null(3));
+        assert!(wkt.is_null(4));
+        assert!(wkt.is_null(5));
+    }
+}
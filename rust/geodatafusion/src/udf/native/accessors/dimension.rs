@@ -0,0 +1,509 @@
+//! Dimension and flag accessors
+//!
+//! Most of these are constant for all geometries of a native GeoArrow type, in which case a scalar
+//! is returned instead of iterating over the array.
+
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::{ArrayRef, BooleanArray, UInt8Array};
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::{Dimensions, GeometryCollectionTrait, GeometryTrait};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::GeoArrowType;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Dimension;
+
+impl Dimension {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Dimension {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DIMENSION_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Dimension {
+    fn name(&self) -> &str {
+        "st_dimension"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(code_impl(args, Code::Dimension)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DIMENSION_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the topological dimension of this Geometry object: 0 for points, 1 for lines and 2 for polygons. For a GeometryCollection, returns the largest dimension of its members, or 0 if it is empty.",
+                "ST_Dimension(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct ZmFlag;
+
+impl ZmFlag {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ZmFlag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static ZM_FLAG_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for ZmFlag {
+    fn name(&self) -> &str {
+        "st_zmflag"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(code_impl(args, Code::ZmFlag)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(ZM_FLAG_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a code indicating the ZM coordinate dimension of a geometry: 0 for 2D, 1 for 3D-M, 2 for 3D-Z and 3 for 4D.",
+                "ST_Zmflag(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct HasZ;
+
+impl HasZ {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for HasZ {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static HAS_Z_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for HasZ {
+    fn name(&self) -> &str {
+        "st_hasz"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(flag_impl(args, Flag::HasZ)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(HAS_Z_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Checks if a geometry has a Z dimension.",
+                "ST_HasZ(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct HasM;
+
+impl HasM {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for HasM {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static HAS_M_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for HasM {
+    fn name(&self) -> &str {
+        "st_hasm"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(flag_impl(args, Flag::HasM)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(HAS_M_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Checks if a geometry has an M (measure) dimension.",
+                "ST_HasM(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct IsCollection;
+
+impl IsCollection {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for IsCollection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static IS_COLLECTION_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for IsCollection {
+    fn name(&self) -> &str {
+        "st_iscollection"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(flag_impl(args, Flag::IsCollection)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(IS_COLLECTION_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns true if the geometry type is a geometry collection type: GEOMETRYCOLLECTION, MULTIPOINT, MULTILINESTRING or MULTIPOLYGON. Empty collections also return true.",
+                "ST_IsCollection(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// Integer-valued accessors
+#[derive(Debug, Clone, Copy)]
+enum Code {
+    Dimension,
+    ZmFlag,
+}
+
+impl Code {
+    /// The value for every geometry of an array type, if it doesn't vary per row
+    fn of_type(self, typ: &GeoArrowType) -> Option<u8> {
+        match self {
+            Self::Dimension => match typ {
+                GeoArrowType::Point(_) | GeoArrowType::MultiPoint(_) => Some(0),
+                GeoArrowType::LineString(_) | GeoArrowType::MultiLineString(_) => Some(1),
+                GeoArrowType::Polygon(_)
+                | GeoArrowType::MultiPolygon(_)
+                | GeoArrowType::Rect(_) => Some(2),
+                _ => None,
+            },
+            Self::ZmFlag => typ.dimension().map(|dim| zm_flag(dim.into())),
+        }
+    }
+
+    fn of_geometry(self, geom: &impl GeometryTrait<T = f64>) -> u8 {
+        match self {
+            Self::Dimension => topological_dimension(geom),
+            Self::ZmFlag => zm_flag(geom.dim()),
+        }
+    }
+}
+
+/// Boolean-valued accessors
+#[derive(Debug, Clone, Copy)]
+enum Flag {
+    HasZ,
+    HasM,
+    IsCollection,
+}
+
+impl Flag {
+    /// The value for every geometry of an array type, if it doesn't vary per row
+    fn of_type(self, typ: &GeoArrowType) -> Option<bool> {
+        match self {
+            Self::HasZ => typ.dimension().map(|dim| has_z(dim.into())),
+            Self::HasM => typ.dimension().map(|dim| has_m(dim.into())),
+            Self::IsCollection => match typ {
+                GeoArrowType::Point(_)
+                | GeoArrowType::LineString(_)
+                | GeoArrowType::Polygon(_)
+                | GeoArrowType::Rect(_) => Some(false),
+                GeoArrowType::MultiPoint(_)
+                | GeoArrowType::MultiLineString(_)
+                | GeoArrowType::MultiPolygon(_)
+                | GeoArrowType::GeometryCollection(_) => Some(true),
+                _ => None,
+            },
+        }
+    }
+
+    fn of_geometry(self, geom: &impl GeometryTrait<T = f64>) -> bool {
+        use geo_traits::GeometryType::*;
+
+        match self {
+            Self::HasZ => has_z(geom.dim()),
+            Self::HasM => has_m(geom.dim()),
+            Self::IsCollection => matches!(
+                geom.as_type(),
+                MultiPoint(_) | MultiLineString(_) | MultiPolygon(_) | GeometryCollection(_)
+            ),
+        }
+    }
+}
+
+fn has_z(dim: Dimensions) -> bool {
+    matches!(dim, Dimensions::Xyz | Dimensions::Xyzm)
+}
+
+fn has_m(dim: Dimensions) -> bool {
+    matches!(dim, Dimensions::Xym | Dimensions::Xyzm)
+}
+
+fn zm_flag(dim: Dimensions) -> u8 {
+    match dim {
+        Dimensions::Xym => 1,
+        Dimensions::Xyz => 2,
+        Dimensions::Xyzm => 3,
+        _ => 0,
+    }
+}
+
+fn topological_dimension(geom: &impl GeometryTrait<T = f64>) -> u8 {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(_) | MultiPoint(_) => 0,
+        LineString(_) | MultiLineString(_) | Line(_) => 1,
+        Polygon(_) | MultiPolygon(_) | Rect(_) | Triangle(_) => 2,
+        GeometryCollection(gc) => gc
+            .geometries()
+            .map(|g| topological_dimension(&g))
+            .max()
+            .unwrap_or(0),
+    }
+}
+
+fn code_impl(args: ScalarFunctionArgs, code: Code) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+
+    // Null rows need an array result
+    if geo_array.logical_null_count() == 0
+        && let Some(value) = code.of_type(&geo_array.data_type())
+    {
+        return Ok(ColumnarValue::Scalar(ScalarValue::UInt8(Some(value))));
+    }
+
+    let geo_array_ref = geo_array.as_ref();
+    let result: ArrayRef = Arc::new(downcast_geoarrow_array!(geo_array_ref, code_array, code)?);
+    Ok(ColumnarValue::Array(result))
+}
+
+fn code_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    code: Code,
+) -> GeoDataFusionResult<UInt8Array> {
+    array
+        .iter()
+        .map(|geom| geom.map(|geom| Ok(code.of_geometry(&geom?))).transpose())
+        .collect()
+}
+
+fn flag_impl(args: ScalarFunctionArgs, flag: Flag) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+
+    // Null rows need an array result
+    if geo_array.logical_null_count() == 0
+        && let Some(value) = flag.of_type(&geo_array.data_type())
+    {
+        return Ok(ColumnarValue::Scalar(ScalarValue::Boolean(Some(value))));
+    }
+
+    let geo_array_ref = geo_array.as_ref();
+    let result: ArrayRef = Arc::new(downcast_geoarrow_array!(geo_array_ref, flag_array, flag)?);
+    Ok(ColumnarValue::Array(result))
+}
+
+fn flag_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    flag: Flag,
+) -> GeoDataFusionResult<BooleanArray> {
+    array
+        .iter()
+        .map(|geom| geom.map(|geom| Ok(flag.of_geometry(&geom?))).transpose())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt8Type;
+    use arrow_schema::Schema;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_array::builder::PointBuilder;
+    use geoarrow_schema::PointType;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    fn ctx() -> SessionContext {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Dimension.into());
+        ctx.register_udf(ZmFlag.into());
+        ctx.register_udf(HasZ.into());
+        ctx.register_udf(HasM.into());
+        ctx.register_udf(IsCollection.into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn test_per_row() {
+        let ctx = ctx();
+        let df = ctx
+            .sql(
+                "SELECT ST_Dimension(g), ST_Zmflag(g), ST_HasZ(g), ST_HasM(g), ST_IsCollection(g)
+                FROM (SELECT ST_GeomFromText(wkt) AS g FROM (VALUES
+                    ('POINT(1 2)'),
+                    ('LINESTRING M (0 0 1,1 1 2)'),
+                    ('POLYGON Z ((0 0 1,1 0 1,1 1 1,0 0 1))'),
+                    ('GEOMETRYCOLLECTION ZM (POINT ZM (1 2 3 4),LINESTRING ZM (0 0 0 0,1 1 1 1))'),
+                    ('GEOMETRYCOLLECTION EMPTY')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let batch = &batches[0];
+        let dimension = batch.column(0).as_primitive::<UInt8Type>();
+        let zm_flag = batch.column(1).as_primitive::<UInt8Type>();
+        let bools = |i: usize| {
+            batch
+                .column(i)
+                .as_boolean()
+                .iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(dimension.values().as_ref(), &[0, 1, 2, 1, 0]);
+        assert_eq!(zm_flag.values().as_ref(), &[0, 1, 2, 3, 0]);
+        assert_eq!(bools(2), vec![false, false, true, true, false]);
+        assert_eq!(bools(3), vec![false, true, false, true, false]);
+        assert_eq!(bools(4), vec![false, false, false, true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_native_type_is_scalar() {
+        let ctx = ctx();
+
+        let point = wkt::wkt! { POINT Z (1.0 2.0 3.0) };
+        let point_arr = PointBuilder::from_points(
+            [point.clone(), point].iter(),
+            PointType::new(geoarrow_schema::Dimension::XYZ, Default::default()),
+        )
+        .finish();
+        let schema = Schema::new([Arc::new(point_arr.data_type().to_field("geometry", true))]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![point_arr.to_array_ref()]).unwrap();
+        ctx.register_batch("t", batch).unwrap();
+
+        let df = ctx
+            .sql("SELECT ST_Dimension(geometry), ST_Zmflag(geometry), ST_HasZ(geometry), ST_HasM(geometry), ST_IsCollection(geometry) FROM t;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column(0).as_primitive::<UInt8Type>().value(1), 0);
+        assert_eq!(batch.column(1).as_primitive::<UInt8Type>().value(1), 2);
+        assert!(batch.column(2).as_boolean().value(1));
+        assert!(!batch.column(3).as_boolean().value(1));
+        assert!(!batch.column(4).as_boolean().value(1));
+    }
+}
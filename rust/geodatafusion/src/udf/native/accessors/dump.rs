@@ -9,9 +9,10 @@ use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
     ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
 };
+use geo_traits::to_geo::{ToGeoLine, ToGeoRect, ToGeoTriangle};
 use geo_traits::{
-    GeometryCollectionTrait, GeometryTrait, MultiLineStringTrait, MultiPointTrait,
-    MultiPolygonTrait,
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
 };
 use geoarrow_array::array::from_arrow_array;
 use geoarrow_array::builder::GeometryBuilder;
@@ -21,6 +22,7 @@ use geoarrow_schema::{CoordType, GeometryType, Metadata};
 use crate::data_types::any_single_geometry_type_input;
 use crate::error::GeoDataFusionResult;
 use crate::udf::native::accessors::is_empty::is_geometry_topologically_empty;
+use crate::udf::native::accessors::util::{coord_to_point, to_wkt_coord};
 
 /// Decomposes a geometry into its atomic components (POINT, LINESTRING, POLYGON).
 #[derive(Debug, Eq, PartialEq, Hash)]
@@ -58,14 +60,11 @@ impl ScalarUDFImpl for Dump {
     }
 
     fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
-        let metadata =
-            Arc::new(Metadata::try_from(args.arg_fields[0].as_ref()).unwrap_or_default());
-        let output_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
-        Ok(output_field(&output_type))
+        Ok(return_field_impl(args, self.coord_type))
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        Ok(dump_impl(args, self.coord_type)?)
+        Ok(dump_impl(args, self.coord_type, DumpKind::Components)?)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -87,6 +86,205 @@ impl ScalarUDFImpl for Dump {
     }
 }
 
+/// Extracts the vertices of a geometry as POINTs.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct DumpPoints {
+    coord_type: CoordType,
+}
+
+impl DumpPoints {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for DumpPoints {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DUMP_POINTS_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for DumpPoints {
+    fn name(&self) -> &str {
+        "st_dumppoints"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal(
+            "Return field is computed from metadata in return_field_from_args.".to_string(),
+        ))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(dump_impl(args, self.coord_type, DumpKind::Points)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DUMP_POINTS_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Extracts the coordinates (vertices) of a geometry as POINTs. \
+                 Returns, per input row, a list of `(path, geom)` structs where \
+                 `path` is the 1-based navigation path to the vertex: \
+                 the index of the vertex in a LineString, the ring and then the vertex index in a Polygon, \
+                 prefixed by the index of the part in multi-geometries and GeometryCollections. \
+                 A Point has the path `[1]`. Empty inputs produce zero points.",
+                "ST_DumpPoints(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// Extracts the segments of a geometry as two-point LINESTRINGs.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct DumpSegments {
+    coord_type: CoordType,
+}
+
+impl DumpSegments {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for DumpSegments {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DUMP_SEGMENTS_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for DumpSegments {
+    fn name(&self) -> &str {
+        "st_dumpsegments"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal(
+            "Return field is computed from metadata in return_field_from_args.".to_string(),
+        ))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(dump_impl(args, self.coord_type, DumpKind::Segments)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DUMP_SEGMENTS_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Extracts the segments of a geometry as two-point LINESTRINGs. \
+                 Returns, per input row, a list of `(path, geom)` structs where \
+                 `path` has the same structure as for ST_DumpPoints, with the index of the start vertex of each segment. \
+                 Points produce zero segments.",
+                "ST_DumpSegments(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// Extracts the rings of polygons as POLYGONs.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct DumpRings {
+    coord_type: CoordType,
+}
+
+impl DumpRings {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for DumpRings {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DUMP_RINGS_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for DumpRings {
+    fn name(&self) -> &str {
+        "st_dumprings"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal(
+            "Return field is computed from metadata in return_field_from_args.".to_string(),
+        ))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(dump_impl(args, self.coord_type, DumpKind::Rings)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DUMP_RINGS_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Extracts the rings of a polygon as single-ring POLYGONs. \
+                 Returns, per input row, a list of `(path, geom)` structs where \
+                 `path` is `[0]` for the exterior ring and `[n]` for the nth interior ring, \
+                 prefixed by the 1-based index of the part in multi-geometries and GeometryCollections. \
+                 Non-polygonal geometries produce zero rings.",
+                "ST_DumpRings(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+/// The level at which [`dump_geometry`] decomposes geometries.
+#[derive(Debug, Clone, Copy)]
+enum DumpKind {
+    /// Atomic components (Point, LineString, Polygon)
+    Components,
+    /// Vertices, as Points
+    Points,
+    /// Segments between consecutive vertices, as two-point LineStrings
+    Segments,
+    /// Polygon rings, as single-ring Polygons
+    Rings,
+}
+
+fn return_field_impl(args: ReturnFieldArgs, coord_type: CoordType) -> FieldRef {
+    let metadata = Arc::new(Metadata::try_from(args.arg_fields[0].as_ref()).unwrap_or_default());
+    let output_type = GeometryType::new(metadata).with_coord_type(coord_type);
+    output_field(&output_type)
+}
+
 fn path_values_field() -> FieldRef {
     Arc::new(Field::new("item", DataType::Int32, false))
 }
@@ -114,6 +312,7 @@ fn output_field(geom_type: &GeometryType) -> FieldRef {
 fn dump_impl(
     args: ScalarFunctionArgs,
     coord_type: CoordType,
+    kind: DumpKind,
 ) -> GeoDataFusionResult<ColumnarValue> {
     let array = ColumnarValue::values_to_arrays(&args.args)?
         .into_iter()
@@ -124,7 +323,7 @@ fn dump_impl(
         GeometryType::new(geo_array.data_type().metadata().clone()).with_coord_type(coord_type);
 
     let geo_array_ref = geo_array.as_ref();
-    let list_array = downcast_geoarrow_array!(geo_array_ref, dump_array, &geom_type)?;
+    let list_array = downcast_geoarrow_array!(geo_array_ref, dump_array, &geom_type, kind)?;
 
     Ok(ColumnarValue::Array(Arc::new(list_array)))
 }
@@ -133,6 +332,7 @@ fn dump_impl(
 fn dump_array<'a>(
     array: &'a impl GeoArrowArrayAccessor<'a>,
     geom_type: &GeometryType,
+    kind: DumpKind,
 ) -> GeoDataFusionResult<ListArray> {
     let mut geom_builder = GeometryBuilder::new(geom_type.clone());
     let mut path_builder = ListBuilder::new(Int32Builder::new()).with_field(path_values_field());
@@ -147,7 +347,13 @@ fn dump_array<'a>(
                 row_validity.append_non_null();
                 // Topologically empty geometries produce an empty, non-null component list.
                 if !is_geometry_topologically_empty(&geom) {
-                    dump_geometry(&geom, &mut geom_builder, &mut path_builder, &mut path_stack)?;
+                    dump_geometry(
+                        &geom,
+                        kind,
+                        &mut geom_builder,
+                        &mut path_builder,
+                        &mut path_stack,
+                    )?;
                 }
             }
             None => row_validity.append_null(),
@@ -171,30 +377,92 @@ fn dump_array<'a>(
 }
 
 /// Visitor for every non-topologically-empty geometry
-/// which emits atomic geoms directly, and recursively dumps children in containers.
+/// which emits the parts of the given kind, and recursively dumps children in containers.
 fn dump_geometry(
     geom: &impl GeometryTrait<T = f64>,
+    kind: DumpKind,
     geom_builder: &mut GeometryBuilder,
     path_builder: &mut ListBuilder<Int32Builder>,
     path_stack: &mut Vec<i32>,
 ) -> GeoDataFusionResult<()> {
     use geo_traits::GeometryType::*;
 
-    match geom.as_type() {
-        Point(_) | LineString(_) | Polygon(_) | Rect(_) | Triangle(_) | Line(_) => {
-            geom_builder.push_geometry(Some(geom))?;
-            path_builder.append_value(path_stack.iter().map(|&i| Some(i)));
+    match (geom.as_type(), kind) {
+        (MultiPoint(g), _) => {
+            dump_multi_children(g.points(), kind, geom_builder, path_builder, path_stack)?
+        }
+        (MultiLineString(g), _) => dump_multi_children(
+            g.line_strings(),
+            kind,
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?,
+        (MultiPolygon(g), _) => {
+            dump_multi_children(g.polygons(), kind, geom_builder, path_builder, path_stack)?
         }
-        MultiPoint(g) => dump_multi_children(g.points(), geom_builder, path_builder, path_stack)?,
-        MultiLineString(g) => {
-            dump_multi_children(g.line_strings(), geom_builder, path_builder, path_stack)?
+        (GeometryCollection(g), _) => {
+            dump_multi_children(g.geometries(), kind, geom_builder, path_builder, path_stack)?
         }
-        MultiPolygon(g) => {
-            dump_multi_children(g.polygons(), geom_builder, path_builder, path_stack)?
+        (_, DumpKind::Components) => push_part(geom, geom_builder, path_builder, path_stack)?,
+        (Point(g), DumpKind::Points) => dump_points(
+            g.coord().into_iter(),
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?,
+        (LineString(g), DumpKind::Points) => {
+            dump_points(g.coords(), geom_builder, path_builder, path_stack)?
+        }
+        (LineString(g), DumpKind::Segments) => {
+            dump_segments(g.coords(), geom_builder, path_builder, path_stack)?
+        }
+        (Polygon(g), DumpKind::Points | DumpKind::Segments) => {
+            for (i, ring) in g.exterior().into_iter().chain(g.interiors()).enumerate() {
+                path_stack.push(i as i32 + 1);
+                if matches!(kind, DumpKind::Points) {
+                    dump_points(ring.coords(), geom_builder, path_builder, path_stack)?;
+                } else {
+                    dump_segments(ring.coords(), geom_builder, path_builder, path_stack)?;
+                }
+                path_stack.pop();
+            }
         }
-        GeometryCollection(g) => {
-            dump_multi_children(g.geometries(), geom_builder, path_builder, path_stack)?
+        (Polygon(g), DumpKind::Rings) => {
+            // The exterior ring is 0, so that interior rings keep their ST_InteriorRingN index
+            for (i, ring) in g.exterior().into_iter().chain(g.interiors()).enumerate() {
+                let ring =
+                    wkt::types::LineString::from_coords(ring.coords().map(|c| to_wkt_coord(&c)));
+                if let Some(ring) = ring {
+                    let polygon = wkt::types::Polygon::new(vec![ring.clone()], ring.dimension());
+                    path_stack.push(i as i32);
+                    push_part(&polygon, geom_builder, path_builder, path_stack)?;
+                    path_stack.pop();
+                }
+            }
         }
+        (Rect(g), _) => dump_geometry(
+            &g.to_rect().to_polygon(),
+            kind,
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?,
+        (Triangle(g), _) => dump_geometry(
+            &g.to_triangle().to_polygon(),
+            kind,
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?,
+        (Line(g), _) => dump_geometry(
+            &geo::LineString::from(g.to_line()),
+            kind,
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?,
+        (Point(_), DumpKind::Segments | DumpKind::Rings) | (LineString(_), DumpKind::Rings) => {}
     }
     Ok(())
 }
@@ -202,18 +470,69 @@ fn dump_geometry(
 /// Process children of a multi-geometry container.
 fn dump_multi_children(
     children: impl Iterator<Item = impl GeometryTrait<T = f64>>,
+    kind: DumpKind,
     geom_builder: &mut GeometryBuilder,
     path_builder: &mut ListBuilder<Int32Builder>,
     path_stack: &mut Vec<i32>,
 ) -> GeoDataFusionResult<()> {
     for (i, child) in children.enumerate() {
         path_stack.push(i as i32 + 1);
-        dump_geometry(&child, geom_builder, path_builder, path_stack)?;
+        dump_geometry(&child, kind, geom_builder, path_builder, path_stack)?;
+        path_stack.pop();
+    }
+    Ok(())
+}
+
+/// Emit each coord as a Point, with its 1-based index appended to the path.
+fn dump_points(
+    coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+    geom_builder: &mut GeometryBuilder,
+    path_builder: &mut ListBuilder<Int32Builder>,
+    path_stack: &mut Vec<i32>,
+) -> GeoDataFusionResult<()> {
+    for (i, coord) in coords.enumerate() {
+        path_stack.push(i as i32 + 1);
+        push_part(
+            &coord_to_point(coord),
+            geom_builder,
+            path_builder,
+            path_stack,
+        )?;
+        path_stack.pop();
+    }
+    Ok(())
+}
+
+/// Emit each pair of consecutive coords as a LineString, with the 1-based index of its start
+/// coord appended to the path.
+fn dump_segments(
+    coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+    geom_builder: &mut GeometryBuilder,
+    path_builder: &mut ListBuilder<Int32Builder>,
+    path_stack: &mut Vec<i32>,
+) -> GeoDataFusionResult<()> {
+    let coords = coords.map(|c| to_wkt_coord(&c)).collect::<Vec<_>>();
+    for (i, segment) in coords.windows(2).enumerate() {
+        let segment = wkt::types::LineString::new(segment.to_vec(), segment[0].dimension());
+        path_stack.push(i as i32 + 1);
+        push_part(&segment, geom_builder, path_builder, path_stack)?;
         path_stack.pop();
     }
     Ok(())
 }
 
+/// Emit a single part at the current path.
+fn push_part(
+    geom: &impl GeometryTrait<T = f64>,
+    geom_builder: &mut GeometryBuilder,
+    path_builder: &mut ListBuilder<Int32Builder>,
+    path_stack: &[i32],
+) -> GeoDataFusionResult<()> {
+    geom_builder.push_geometry(Some(geom))?;
+    path_builder.append_value(path_stack.iter().map(|&i| Some(i)));
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use arrow_array::Array;
@@ -229,6 +548,9 @@ mod test {
     fn ctx() -> SessionContext {
         let ctx = SessionContext::new();
         ctx.register_udf(Dump::default().into());
+        ctx.register_udf(DumpPoints::default().into());
+        ctx.register_udf(DumpSegments::default().into());
+        ctx.register_udf(DumpRings::default().into());
         ctx.register_udf(GeomFromText::default().into());
         ctx.register_udf(AsText.into());
         ctx
@@ -236,7 +558,16 @@ mod test {
 
     /// Run `ST_Dump` on a single WKT input and return the result decoded as WKT.
     async fn dump_rows(ctx: &SessionContext, wkt: &str) -> Vec<(Vec<i32>, String)> {
-        let sql = format!("SELECT ST_Dump(ST_GeomFromText('{wkt}'))");
+        dump_rows_with(ctx, "ST_Dump", wkt).await
+    }
+
+    /// Run a dump function on a single WKT input and return the result decoded as WKT.
+    async fn dump_rows_with(
+        ctx: &SessionContext,
+        function: &str,
+        wkt: &str,
+    ) -> Vec<(Vec<i32>, String)> {
+        let sql = format!("SELECT {function}(ST_GeomFromText('{wkt}'))");
         let df = ctx.sql(&sql).await.unwrap();
         let batch = df.collect().await.unwrap().into_iter().next().unwrap();
         let list = batch.column(0).as_list::<i32>();
@@ -460,4 +791,75 @@ mod test {
         assert_eq!(path.value(0).as_primitive::<Int32Type>().values(), &[1]);
         assert_eq!(path.value(1).as_primitive::<Int32Type>().values(), &[2]);
     }
+
+    #[tokio::test]
+    async fn test_dump_points() {
+        let ctx = ctx();
+        assert_eq!(
+            dump_rows_with(&ctx, "ST_DumpPoints", "POINT Z(1 2 3)").await,
+            vec![(vec![1], "POINT Z(1 2 3)".to_string())],
+        );
+
+        assert_eq!(
+            dump_rows_with(
+                &ctx,
+                "ST_DumpPoints",
+                "GEOMETRYCOLLECTION(LINESTRING(0 0,1 1),POLYGON((0 0,0 1,1 0,0 0)))"
+            )
+            .await,
+            vec![
+                (vec![1, 1], "POINT(0 0)".to_string()),
+                (vec![1, 2], "POINT(1 1)".to_string()),
+                (vec![2, 1, 1], "POINT(0 0)".to_string()),
+                (vec![2, 1, 2], "POINT(0 1)".to_string()),
+                (vec![2, 1, 3], "POINT(1 0)".to_string()),
+                (vec![2, 1, 4], "POINT(0 0)".to_string()),
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dump_segments() {
+        let ctx = ctx();
+        assert_eq!(
+            dump_rows_with(
+                &ctx,
+                "ST_DumpSegments",
+                "MULTILINESTRING((0 0,1 1,2 2),(5 5,6 6))"
+            )
+            .await,
+            vec![
+                (vec![1, 1], "LINESTRING(0 0,1 1)".to_string()),
+                (vec![1, 2], "LINESTRING(1 1,2 2)".to_string()),
+                (vec![2, 1], "LINESTRING(5 5,6 6)".to_string()),
+            ],
+        );
+
+        assert_eq!(
+            dump_rows_with(&ctx, "ST_DumpSegments", "POINT(0 0)").await,
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dump_rings() {
+        let ctx = ctx();
+        assert_eq!(
+            dump_rows_with(
+                &ctx,
+                "ST_DumpRings",
+                "POLYGON((0 0,0 3,3 3,3 0,0 0),(1 1,1 2,2 2,2 1,1 1))"
+            )
+            .await,
+            vec![
+                (vec![0], "POLYGON((0 0,0 3,3 3,3 0,0 0))".to_string()),
+                (vec![1], "POLYGON((1 1,1 2,2 2,2 1,1 1))".to_string()),
+            ],
+        );
+
+        assert_eq!(
+            dump_rows_with(&ctx, "ST_DumpRings", "LINESTRING(0 0,1 1)").await,
+            vec![]
+        );
+    }
 }
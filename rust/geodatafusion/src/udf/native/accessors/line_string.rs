@@ -2,11 +2,13 @@
 
 use std::sync::{Arc, OnceLock};
 
+use arrow_array::Int64Array;
 use arrow_schema::{DataType, FieldRef};
 use datafusion::error::{DataFusionError, Result};
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
     ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
 };
 use geo_traits::{CoordTrait, GeometryTrait, LineStringTrait, PointTrait};
 use geoarrow_array::array::{GeometryArray, LineStringArray, PointArray, from_arrow_array};
@@ -17,6 +19,7 @@ use geoarrow_schema::{CoordType, GeoArrowType, GeometryType, PointType};
 
 use crate::data_types::any_single_geometry_type_input;
 use crate::error::GeoDataFusionResult;
+use crate::udf::native::accessors::util::{coord_to_point, index_arg, resolve_index};
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 pub struct StartPoint {
@@ -55,7 +58,8 @@ impl ScalarUDFImpl for StartPoint {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        Ok(point_impl(args, self.coord_type, Mode::Start)?)
+        let indices = Int64Array::from_value(1, args.number_rows);
+        Ok(point_impl(args, self.coord_type, indices)?)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -104,7 +108,8 @@ impl ScalarUDFImpl for EndPoint {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        Ok(point_impl(args, self.coord_type, Mode::End)?)
+        let indices = Int64Array::from_value(-1, args.number_rows);
+        Ok(point_impl(args, self.coord_type, indices)?)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -116,18 +121,18 @@ impl ScalarUDFImpl for EndPoint {
     }
 }
 
-// Not yet exported because we don't handle the nth point second argument yet
 #[derive(Debug, Eq, PartialEq, Hash)]
-#[expect(dead_code)]
-struct PointN {
+pub struct PointN {
+    signature: Signature,
     coord_type: CoordType,
 }
 
 impl PointN {
-    // Not yet exported because we don't handle the nth point second argument yet
-    #[expect(dead_code)]
     pub fn new(coord_type: CoordType) -> Self {
-        Self { coord_type }
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+            coord_type,
+        }
     }
 }
 
@@ -137,8 +142,6 @@ impl Default for PointN {
     }
 }
 
-// Not yet exported because we don't handle the nth point second argument yet
-#[expect(dead_code)]
 static POINT_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
 
 impl ScalarUDFImpl for PointN {
@@ -147,7 +150,7 @@ impl ScalarUDFImpl for PointN {
     }
 
     fn signature(&self) -> &Signature {
-        any_single_geometry_type_input()
+        &self.signature
     }
 
     fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
@@ -159,13 +162,15 @@ impl ScalarUDFImpl for PointN {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        Ok(point_impl(args, self.coord_type, Mode::Start)?)
+        let indices = index_arg(&args, 1)?;
+        Ok(point_impl(args, self.coord_type, indices)?)
     }
 
     fn documentation(&self) -> Option<&Documentation> {
         Some(POINT_N_DOCUMENTATION.get_or_init(|| {
             Documentation::builder(DOC_SECTION_OTHER, "Return the Nth point in a single linestring or circular linestring in the geometry. Negative values are counted backwards from the end of the LineString, so that -1 is the last point. Returns NULL if there is no linestring in the geometry.", "ST_PointN(line_string, 1)" )
                 .with_argument("g1", "geometry")
+                .with_argument("n", "integer, 1-based")
                 .build()
         }))
     }
@@ -192,20 +197,14 @@ fn return_field_impl(
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Mode {
-    Start,
-    End,
-    #[allow(dead_code)]
-    N(usize),
-}
-
+/// Return the point of each LineString at the 1-based index of the same row, where negative
+/// indices count backwards from the end.
 fn point_impl(
     args: ScalarFunctionArgs,
     coord_type: CoordType,
-    mode: Mode,
+    indices: Int64Array,
 ) -> GeoDataFusionResult<ColumnarValue> {
-    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let arrays = ColumnarValue::values_to_arrays(&args.args[..1])?;
     let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
     let geo_array_ref = geo_array.as_ref();
 
@@ -213,13 +212,13 @@ fn point_impl(
         GeoArrowType::LineString(_) => Arc::new(impl_fixed_dim(
             geo_array.as_line_string(),
             coord_type,
-            mode,
+            &indices,
         )?),
         _ => Arc::new(downcast_geoarrow_array!(
             geo_array_ref,
             impl_variable_dim,
             coord_type,
-            mode
+            &indices
         )?),
     };
 
@@ -229,7 +228,7 @@ fn point_impl(
 fn impl_fixed_dim(
     array: &LineStringArray,
     coord_type: CoordType,
-    mode: Mode,
+    indices: &Int64Array,
 ) -> GeoDataFusionResult<PointArray> {
     let typ = PointType::new(
         array.extension_type().dimension(),
@@ -238,9 +237,9 @@ fn impl_fixed_dim(
     .with_coord_type(coord_type);
     let mut output_builder = PointBuilder::with_capacity(typ, array.len());
 
-    for geom in array.iter() {
-        if let Some(ls) = geom {
-            output_builder.push_coord(get_linestring_coord(&ls?, mode).as_ref());
+    for (geom, n) in array.iter().zip(indices.iter()) {
+        if let (Some(ls), Some(n)) = (geom, n) {
+            output_builder.push_coord(get_linestring_coord(&ls?, n).as_ref());
         } else {
             output_builder.push_null();
         }
@@ -252,14 +251,14 @@ fn impl_fixed_dim(
 fn impl_variable_dim<'a>(
     array: &'a impl GeoArrowArrayAccessor<'a>,
     coord_type: CoordType,
-    mode: Mode,
+    indices: &Int64Array,
 ) -> GeoDataFusionResult<GeometryArray> {
     let typ = GeometryType::new(array.data_type().metadata().clone()).with_coord_type(coord_type);
     let mut output_builder = GeometryBuilder::new(typ);
 
-    for geom in array.iter() {
-        if let Some(ls) = geom {
-            output_builder.push_geometry(get_geometry_coord(&ls?, mode).as_ref())?;
+    for (geom, n) in array.iter().zip(indices.iter()) {
+        if let (Some(ls), Some(n)) = (geom, n) {
+            output_builder.push_geometry(get_geometry_coord(&ls?, n).as_ref())?;
         } else {
             output_builder.push_null();
         }
@@ -270,42 +269,68 @@ fn impl_variable_dim<'a>(
 
 fn get_geometry_coord(
     geom: &impl GeometryTrait<T = f64>,
-    mode: Mode,
+    n: i64,
 ) -> Option<impl PointTrait<T = f64>> {
     match geom.as_type() {
-        geo_traits::GeometryType::LineString(ls) => {
-            get_linestring_coord(ls, mode).map(coord_to_point)
-        }
+        geo_traits::GeometryType::LineString(ls) => get_linestring_coord(ls, n).map(coord_to_point),
         _ => None,
     }
 }
 
 fn get_linestring_coord(
     geom: &impl LineStringTrait<T = f64>,
-    mode: Mode,
+    n: i64,
 ) -> Option<impl CoordTrait<T = f64>> {
-    match mode {
-        Mode::Start => geom.coord(0),
-        Mode::End => geom.coord(geom.num_coords() - 1),
-        Mode::N(n) => {
-            if n < geom.num_coords() {
-                // Index is 1-based as for OGC specs since version 0.8.0
-                // https://postgis.net/docs/ST_PointN.html
-                geom.coord(n - 1)
-            } else {
-                None
-            }
-        }
-    }
+    // Index is 1-based as for OGC specs since version 0.8.0
+    // https://postgis.net/docs/ST_PointN.html
+    resolve_index(n, geom.num_coords(), true).and_then(|i| geom.coord(i))
 }
 
-/// Convert an arbitrary coord to a Point
-fn coord_to_point(coord: impl CoordTrait<T = f64>) -> wkt::types::Point {
-    let coord = wkt::types::Coord {
-        x: coord.x(),
-        y: coord.y(),
-        z: coord.nth(2),
-        m: coord.nth(3),
-    };
-    wkt::types::Point::from_coord(coord)
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_point_n() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(PointN::default().into());
+        ctx.register_udf(EndPoint::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_PointN(ST_GeomFromText(wkt), n)),
+                    ST_AsText(ST_EndPoint(ST_GeomFromText(wkt)))
+                FROM (VALUES
+                    ('LINESTRING(0 0,1 1,2 2)', 1),
+                    ('LINESTRING(0 0,1 1,2 2)', 3),
+                    ('LINESTRING(0 0,1 1,2 2)', -2),
+                    ('LINESTRING(0 0,1 1,2 2)', 4),
+                    ('LINESTRING(0 0,1 1,2 2)', 0),
+                    ('LINESTRING EMPTY', 1),
+                    ('POINT(0 0)', 1)
+                ) AS t(wkt, n);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let point_n = batches[0].column(0).as_string::<i32>();
+        let end_point = batches[0].column(1).as_string::<i32>();
+        assert_eq!(point_n.value(0), "POINT(0 0)");
+        assert_eq!(point_n.value(1), "POINT(2 2)");
+        assert_eq!(point_n.value(2), "POINT(1 1)");
+        assert!(point_n.is_null(3));
+        assert!(point_n.is_null(4));
+        assert!(point_n.is_null(5));
+        assert!(point_n.is_null(6));
+        assert_eq!(end_point.value(0), "POINT(2 2)");
+        assert!(end_point.is_null(5));
+    }
 }
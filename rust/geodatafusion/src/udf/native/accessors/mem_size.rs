@@ -0,0 +1,266 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::Int64Builder;
+use arrow_array::cast::AsArray;
+use arrow_array::{ArrayRef, Int64Array};
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::GeoArrowType;
+use geoarrow_schema::error::GeoArrowResult;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+/// The size of an offset in native GeoArrow arrays, which use `i32` offsets
+const OFFSET_SIZE: usize = 4;
+
+/// The size of a type id and offset of each value of a mixed geometry array
+const UNION_VALUE_SIZE: usize = 1 + OFFSET_SIZE;
+
+/// The size of a view in a binary or string view array, which stores values of up to 12 bytes
+/// inline
+const VIEW_SIZE: usize = 16;
+const MAX_INLINE_VIEW_LENGTH: usize = 12;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct MemSize;
+
+impl MemSize {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for MemSize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for MemSize {
+    fn name(&self) -> &str {
+        "st_memsize"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(mem_size_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the number of bytes of the GeoArrow buffers attributable to the geometry. For native arrays, this is the size of its coordinates, and of its geometry, part and ring offsets and union type ids. For WKB and WKT arrays, this is the size of the value and its offset or view. Validity bitmaps are not included.",
+                "ST_MemSize(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn mem_size_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let array = &arrays[0];
+    let geo_array = from_arrow_array(array, &args.arg_fields[0])?;
+
+    let result = match geo_array.data_type() {
+        GeoArrowType::Wkb(_) => serialized_sizes(
+            array.as_binary::<i32>().iter().map(|v| v.map(<[u8]>::len)),
+            |len| len + 4,
+        ),
+        GeoArrowType::LargeWkb(_) => serialized_sizes(
+            array.as_binary::<i64>().iter().map(|v| v.map(<[u8]>::len)),
+            |len| len + 8,
+        ),
+        GeoArrowType::WkbView(_) => serialized_sizes(
+            array.as_binary_view().iter().map(|v| v.map(<[u8]>::len)),
+            view_size,
+        ),
+        GeoArrowType::Wkt(_) => serialized_sizes(
+            array.as_string::<i32>().iter().map(|v| v.map(str::len)),
+            |len| len + 4,
+        ),
+        GeoArrowType::LargeWkt(_) => serialized_sizes(
+            array.as_string::<i64>().iter().map(|v| v.map(str::len)),
+            |len| len + 8,
+        ),
+        GeoArrowType::WktView(_) => serialized_sizes(
+            array.as_string_view().iter().map(|v| v.map(str::len)),
+            view_size,
+        ),
+        GeoArrowType::Geometry(_) => native_sizes(&geo_array, UNION_VALUE_SIZE)?,
+        _ => native_sizes(&geo_array, 0)?,
+    };
+    Ok(ColumnarValue::Array(Arc::new(result) as ArrayRef))
+}
+
+fn view_size(len: usize) -> usize {
+    if len <= MAX_INLINE_VIEW_LENGTH {
+        VIEW_SIZE
+    } else {
+        VIEW_SIZE + len
+    }
+}
+
+fn serialized_sizes(
+    lengths: impl Iterator<Item = Option<usize>>,
+    size: impl Fn(usize) -> usize,
+) -> Int64Array {
+    lengths.map(|len| len.map(|len| size(len) as i64)).collect()
+}
+
+fn native_sizes(array: &dyn GeoArrowArray, row_overhead: usize) -> GeoArrowResult<Int64Array> {
+    downcast_geoarrow_array!(array, _native_sizes_impl, row_overhead)
+}
+
+fn _native_sizes_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    row_overhead: usize,
+) -> GeoArrowResult<Int64Array> {
+    let mut builder = Int64Builder::with_capacity(array.len());
+    for item in array.iter() {
+        if let Some(geom) = item {
+            builder.append_value((row_overhead + geometry_size(&geom?)) as i64);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The size of the coordinates and offsets of a geometry in a native array
+fn geometry_size(geom: &impl GeometryTrait<T = f64>) -> usize {
+    use geo_traits::GeometryType::*;
+
+    let coord_size = geom.dim().size() * size_of::<f64>();
+    match geom.as_type() {
+        // Empty points are stored as NaN coordinates
+        Point(_) => coord_size,
+        LineString(ls) => OFFSET_SIZE + ls.num_coords() * coord_size,
+        Polygon(polygon) => OFFSET_SIZE + polygon_size(polygon, coord_size),
+        MultiPoint(mp) => OFFSET_SIZE + mp.num_points() * coord_size,
+        MultiLineString(mls) => {
+            OFFSET_SIZE
+                + mls
+                    .line_strings()
+                    .map(|ls| OFFSET_SIZE + ls.num_coords() * coord_size)
+                    .sum::<usize>()
+        }
+        MultiPolygon(mp) => {
+            OFFSET_SIZE
+                + mp.polygons()
+                    .map(|polygon| OFFSET_SIZE + polygon_size(&polygon, coord_size))
+                    .sum::<usize>()
+        }
+        // The members of a collection are stored in a mixed geometry array
+        GeometryCollection(gc) => {
+            OFFSET_SIZE
+                + gc.geometries()
+                    .map(|g| UNION_VALUE_SIZE + geometry_size(&g))
+                    .sum::<usize>()
+        }
+        Rect(_) => 2 * coord_size,
+        // Lines and triangles are stored as line strings and polygons
+        Line(_) => OFFSET_SIZE + 2 * coord_size,
+        Triangle(_) => 2 * OFFSET_SIZE + 4 * coord_size,
+    }
+}
+
+/// The size of the ring offsets and coordinates of a polygon
+fn polygon_size(polygon: &impl PolygonTrait<T = f64>, coord_size: usize) -> usize {
+    polygon
+        .exterior()
+        .into_iter()
+        .chain(polygon.interiors())
+        .map(|ring| OFFSET_SIZE + ring.num_coords() * coord_size)
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::types::Int64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsBinary, GeomFromText};
+
+    #[tokio::test]
+    async fn test_mem_size() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(MemSize.into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsBinary.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_MemSize(ST_GeomFromText(wkt)), ST_MemSize(ST_AsBinary(ST_GeomFromText(wkt))), ST_MemSize(arrow_cast(wkt, 'Utf8View'))
+                FROM (VALUES
+                    ('POINT(1 2)'),
+                    ('LINESTRING(0 0,1 1)'),
+                    ('POLYGON((0 0,1 0,1 1,0 0))'),
+                    ('MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))'),
+                    ('GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))'),
+                    (NULL)
+                ) AS t(wkt);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let native = batches[0].column(0).as_primitive::<Int64Type>();
+        let wkb = batches[0].column(1).as_primitive::<Int64Type>();
+        let wkt_view = batches[0].column(2).as_primitive::<Int64Type>();
+        assert_eq!(
+            native.iter().collect::<Vec<_>>(),
+            vec![
+                Some(5 + 16),
+                Some(5 + 4 + 32),
+                Some(5 + 4 + 4 + 64),
+                Some(5 + 4 + 2 * (4 + 4 + 64)),
+                Some(5 + 4 + (5 + 16) + (5 + 4 + 32)),
+                None
+            ]
+        );
+        assert_eq!(
+            wkb.iter().collect::<Vec<_>>(),
+            vec![
+                Some(21 + 4),
+                Some(41 + 4),
+                Some(77 + 4),
+                Some(163 + 4),
+                Some(71 + 4),
+                None
+            ]
+        );
+        // Values of up to 12 bytes are stored inline in the view
+        assert_eq!(
+            wkt_view.iter().collect::<Vec<_>>(),
+            vec![
+                Some(16),
+                Some(16 + 19),
+                Some(16 + 26),
+                Some(16 + 53),
+                Some(16 + 50),
+                None
+            ]
+        );
+    }
+}
@@ -1,37 +1,63 @@
+mod collection;
 mod coord_dim;
+mod dimension;
 mod dump;
 mod geometry_type;
 mod is_closed;
 mod is_empty;
 mod line_string;
+mod mem_size;
 mod npoints;
 mod num_interior_rings;
 mod point;
+mod polygon;
+mod summary;
+mod util;
 
+pub use collection::{GeometryN, NumGeometries};
 pub use coord_dim::{CoordDim, NDims};
-pub use dump::Dump;
+pub use dimension::{Dimension, HasM, HasZ, IsCollection, ZmFlag};
+pub use dump::{Dump, DumpPoints, DumpRings, DumpSegments};
 pub use geometry_type::{GeometryType, ST_GeometryType};
 pub use is_closed::IsClosed;
 pub use is_empty::IsEmpty;
-pub use line_string::{EndPoint, StartPoint};
+pub use line_string::{EndPoint, PointN, StartPoint};
+pub use mem_size::MemSize;
 pub use npoints::NPoints;
 pub use num_interior_rings::NumInteriorRings;
 pub use point::{M, X, Y, Z};
+pub use polygon::{ExteriorRing, InteriorRingN};
+pub use summary::Summary;
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(NumGeometries.into());
+    session_context.register_udf(GeometryN::default().into());
     session_context.register_udf(CoordDim.into());
     session_context.register_udf(NDims.into());
+    session_context.register_udf(Dimension.into());
+    session_context.register_udf(ZmFlag.into());
+    session_context.register_udf(HasZ.into());
+    session_context.register_udf(HasM.into());
+    session_context.register_udf(IsCollection.into());
     session_context.register_udf(GeometryType.into());
     session_context.register_udf(ST_GeometryType.into());
     session_context.register_udf(IsClosed.into());
     session_context.register_udf(IsEmpty.into());
     session_context.register_udf(Dump::default().into());
+    session_context.register_udf(DumpPoints::default().into());
+    session_context.register_udf(DumpSegments::default().into());
+    session_context.register_udf(DumpRings::default().into());
     session_context.register_udf(EndPoint::default().into());
+    session_context.register_udf(PointN::default().into());
     session_context.register_udf(StartPoint::default().into());
+    session_context.register_udf(MemSize.into());
     session_context.register_udf(NPoints.into());
     session_context.register_udf(NumInteriorRings.into());
     session_context.register_udf(M::default().into());
     session_context.register_udf(X::default().into());
     session_context.register_udf(Y::default().into());
     session_context.register_udf(Z::default().into());
+    session_context.register_udf(ExteriorRing::default().into());
+    session_context.register_udf(InteriorRingN::default().into());
+    session_context.register_udf(Summary.into());
 }
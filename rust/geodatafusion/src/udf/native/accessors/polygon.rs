@@ -0,0 +1,326 @@
+//! Accessors from Polygon geometries
+
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::{Array, Int64Array};
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geo_traits::{GeometryTrait, PolygonTrait};
+use geoarrow_array::array::{GeometryArray, LineStringArray, PolygonArray, from_arrow_array};
+use geoarrow_array::builder::{GeometryBuilder, LineStringBuilder};
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, IntoArrow, downcast_geoarrow_array};
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType, LineStringType};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::accessors::util::{index_arg, resolve_index};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct ExteriorRing {
+    coord_type: CoordType,
+}
+
+impl ExteriorRing {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for ExteriorRing {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static EXTERIOR_RING_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for ExteriorRing {
+    fn name(&self) -> &str {
+        "st_exteriorring"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(ring_impl(args, self.coord_type, Ring::Exterior)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(EXTERIOR_RING_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a LINESTRING representing the exterior ring (shell) of a POLYGON. Returns NULL if the geometry is not a polygon.",
+                "ST_ExteriorRing(polygon)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct InteriorRingN {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl InteriorRingN {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for InteriorRingN {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static INTERIOR_RING_N_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for InteriorRingN {
+    fn name(&self) -> &str {
+        "st_interiorringn"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let indices = index_arg(&args, 1)?;
+        Ok(ring_impl(args, self.coord_type, Ring::Interior(indices))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(INTERIOR_RING_N_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Nth interior ring (hole) of a POLYGON geometry as a LINESTRING. The index starts at 1. Returns NULL if the geometry is not a polygon or the index is out of range.",
+                "ST_InteriorRingN(polygon, 1)",
+            )
+            .with_argument("g1", "geometry")
+            .with_argument("n", "integer, 1-based")
+            .build()
+        }))
+    }
+}
+
+/// Which ring of each polygon to return
+enum Ring {
+    Exterior,
+    /// The 1-based index of the interior ring for each row
+    Interior(Int64Array),
+}
+
+impl Ring {
+    fn get<'a, P: PolygonTrait<T = f64>>(
+        &self,
+        polygon: &'a P,
+        row: usize,
+    ) -> Option<P::RingType<'a>> {
+        match self {
+            Self::Exterior => polygon.exterior(),
+            Self::Interior(indices) => {
+                if indices.is_null(row) {
+                    return None;
+                }
+                resolve_index(indices.value(row), polygon.num_interiors(), false)
+                    .and_then(|i| polygon.interior(i))
+            }
+        }
+    }
+}
+
+/// For Polygon input, return fixed-dim LineString output
+/// For other geometry input, return variable-dim Geometry output
+fn return_field_impl(
+    args: ReturnFieldArgs,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<FieldRef> {
+    let input_field = GeoArrowType::from_arrow_field(args.arg_fields[0].as_ref())?;
+    match input_field {
+        GeoArrowType::Polygon(typ) => {
+            let output_type = LineStringType::new(typ.dimension(), typ.metadata().clone())
+                .with_coord_type(coord_type);
+            Ok(Arc::new(output_type.to_field("", true)))
+        }
+        _ => {
+            let output_type =
+                GeometryType::new(input_field.metadata().clone()).with_coord_type(coord_type);
+            Ok(Arc::new(output_type.to_field("", true)))
+        }
+    }
+}
+
+fn ring_impl(
+    args: ScalarFunctionArgs,
+    coord_type: CoordType,
+    ring: Ring,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args[..1])?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+
+    let out: Arc<dyn GeoArrowArray> = match geo_array.data_type() {
+        GeoArrowType::Polygon(_) => {
+            Arc::new(impl_fixed_dim(geo_array.as_polygon(), coord_type, &ring)?)
+        }
+        _ => Arc::new(downcast_geoarrow_array!(
+            geo_array_ref,
+            impl_variable_dim,
+            coord_type,
+            &ring
+        )?),
+    };
+
+    Ok(out.into_array_ref().into())
+}
+
+fn impl_fixed_dim(
+    array: &PolygonArray,
+    coord_type: CoordType,
+    ring: &Ring,
+) -> GeoDataFusionResult<LineStringArray> {
+    let typ = LineStringType::new(
+        array.extension_type().dimension(),
+        array.extension_type().metadata().clone(),
+    )
+    .with_coord_type(coord_type);
+    let mut output_builder = LineStringBuilder::new(typ);
+
+    for (row, geom) in array.iter().enumerate() {
+        if let Some(polygon) = geom {
+            output_builder.push_line_string(ring.get(&polygon?, row).as_ref())?;
+        } else {
+            output_builder.push_line_string(None::<&geo::LineString>)?;
+        }
+    }
+
+    Ok(output_builder.finish())
+}
+
+fn impl_variable_dim<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    coord_type: CoordType,
+    ring: &Ring,
+) -> GeoDataFusionResult<GeometryArray> {
+    let typ = GeometryType::new(array.data_type().metadata().clone()).with_coord_type(coord_type);
+    let mut output_builder = GeometryBuilder::new(typ);
+
+    for (row, geom) in array.iter().enumerate() {
+        if let Some(geom) = geom {
+            match geom?.as_type() {
+                geo_traits::GeometryType::Polygon(polygon) => {
+                    output_builder.push_geometry(ring.get(polygon, row).as_ref())?
+                }
+                _ => output_builder.push_null(),
+            }
+        } else {
+            output_builder.push_null();
+        }
+    }
+
+    Ok(output_builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use arrow_array::cast::AsArray;
+    use arrow_schema::Schema;
+    use datafusion::prelude::SessionContext;
+    use geo_traits::LineStringTrait;
+    use geoarrow_array::builder::PolygonBuilder;
+    use geoarrow_schema::{Dimension, PolygonType};
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_rings() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(ExteriorRing::default().into());
+        ctx.register_udf(InteriorRingN::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_ExteriorRing(ST_GeomFromText(wkt))),
+                    ST_AsText(ST_InteriorRingN(ST_GeomFromText(wkt), n))
+                FROM (VALUES
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 1),(5 5,6 5,6 6,5 5))', 2),
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 1))', 2),
+                    ('POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 1))', -1),
+                    ('LINESTRING(0 0,1 1)', 1)
+                ) AS t(wkt, n);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let exterior = batches[0].column(0).as_string::<i32>();
+        let interior = batches[0].column(1).as_string::<i32>();
+        assert_eq!(exterior.value(0), "LINESTRING(0 0,10 0,10 10,0 10,0 0)");
+        assert_eq!(interior.value(0), "LINESTRING(5 5,6 5,6 6,5 5)");
+        assert!(interior.is_null(1));
+        assert!(interior.is_null(2));
+        assert!(exterior.is_null(3));
+        assert!(interior.is_null(3));
+    }
+
+    #[tokio::test]
+    async fn test_exterior_ring_of_polygon_array() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(ExteriorRing::default().into());
+
+        let polygon = wkt::wkt! { POLYGON((0.0 0.0,1.0 0.0,1.0 1.0,0.0 0.0)) };
+        let polygon_arr = PolygonBuilder::from_polygons(
+            &[polygon],
+            PolygonType::new(Dimension::XY, Default::default()),
+        )
+        .finish();
+
+        let schema = Schema::new([Arc::new(polygon_arr.data_type().to_field("geometry", true))]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![polygon_arr.to_array_ref()]).unwrap();
+        ctx.register_batch("t", batch).unwrap();
+
+        let df = ctx
+            .sql("SELECT ST_ExteriorRing(geometry) FROM t;")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let ring_arr =
+            LineStringArray::try_from((batch.column(0).as_ref(), batch.schema().field(0))).unwrap();
+        assert_eq!(ring_arr.value(0).unwrap().num_coords(), 4);
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt::Write;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_array::builder::StringBuilder;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+};
+use geo_traits::{
+    Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::Metadata;
+use geoarrow_schema::error::GeoArrowResult;
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Summary;
+
+impl Summary {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Summary {
+    fn name(&self) -> &str {
+        "st_summary"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(summary_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                r#"Returns a text summary of the contents of the geometry: its type, flags, and the number of points, rings or elements of each part, one per line.
+
+The flags in square brackets are:
+- Z: has Z coordinates
+- M: has M coordinates
+- B: has a bounding box, which as with PostGIS is any non-empty geometry other than a point
+- G: has geodesic edges
+- S: has a spatial reference system"#,
+                "ST_Summary(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn summary_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geo_array_ref = geo_array.as_ref();
+    let metadata = geo_array.data_type().metadata().clone();
+    let result = downcast_geoarrow_array!(geo_array_ref, summary_array, &metadata)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn summary_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    metadata: &Metadata,
+) -> GeoArrowResult<StringArray> {
+    let mut builder = StringBuilder::with_capacity(array.len(), 0);
+    let mut summary = String::new();
+    for item in array.iter() {
+        if let Some(geom) = item {
+            summary.clear();
+            // Writing to a String cannot fail
+            let _ = write_summary(&geom?, Some(metadata), 0, &mut summary);
+            builder.append_value(&summary);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The PostGIS flag characters of a geometry. The array-level flags are only set at the top level.
+fn flags(geom: &impl GeometryTrait<T = f64>, metadata: Option<&Metadata>) -> String {
+    let mut flags = String::new();
+    if matches!(geom.dim(), Dimensions::Xyz | Dimensions::Xyzm) {
+        flags.push('Z');
+    }
+    if matches!(geom.dim(), Dimensions::Xym | Dimensions::Xyzm) {
+        flags.push('M');
+    }
+    if let Some(metadata) = metadata {
+        if has_bbox(geom) {
+            flags.push('B');
+        }
+        if metadata.edges().is_some() {
+            flags.push('G');
+        }
+        if metadata.crs().crs_value().is_some() {
+            flags.push('S');
+        }
+    }
+    flags
+}
+
+fn has_bbox(geom: &impl GeometryTrait<T = f64>) -> bool {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(_) => false,
+        LineString(ls) => ls.num_coords() > 0,
+        Polygon(polygon) => polygon.exterior().is_some_and(|ring| ring.num_coords() > 0),
+        MultiPoint(mp) => mp.num_points() > 0,
+        MultiLineString(mls) => mls.num_line_strings() > 0,
+        MultiPolygon(mp) => mp.num_polygons() > 0,
+        GeometryCollection(gc) => gc.num_geometries() > 0,
+        Rect(_) | Triangle(_) | Line(_) => true,
+    }
+}
+
+fn write_summary(
+    geom: &impl GeometryTrait<T = f64>,
+    metadata: Option<&Metadata>,
+    indent: usize,
+    out: &mut String,
+) -> std::fmt::Result {
+    use geo_traits::GeometryType::*;
+
+    let flags = flags(geom, metadata);
+    let pad = " ".repeat(indent);
+    match geom.as_type() {
+        Point(point) => {
+            write!(out, "{pad}Point[{flags}]")?;
+            if point.coord().is_none() {
+                write!(out, " with 0 points")?;
+            }
+        }
+        LineString(ls) => write!(
+            out,
+            "{pad}LineString[{flags}] with {} points",
+            ls.num_coords()
+        )?,
+        Line(_) => write!(out, "{pad}LineString[{flags}] with 2 points")?,
+        Polygon(polygon) => {
+            let rings = polygon
+                .exterior()
+                .into_iter()
+                .chain(polygon.interiors())
+                .map(|ring| ring.num_coords())
+                .collect::<Vec<_>>();
+            write_rings(out, &pad, "Polygon", &flags, &rings)?;
+        }
+        // Boxes are summarized as the polygon they represent
+        Rect(_) => write_rings(out, &pad, "Polygon", &flags, &[5])?,
+        Triangle(_) => write_rings(out, &pad, "Triangle", &flags, &[4])?,
+        MultiPoint(mp) => {
+            write!(
+                out,
+                "{pad}MultiPoint[{flags}] with {} elements",
+                mp.num_points()
+            )?;
+            for point in mp.points() {
+                out.push('\n');
+                write_summary(&point, None, indent + 2, out)?;
+            }
+        }
+        MultiLineString(mls) => {
+            let n = mls.num_line_strings();
+            write!(out, "{pad}MultiLineString[{flags}] with {n} elements")?;
+            for ls in mls.line_strings() {
+                out.push('\n');
+                write_summary(&ls, None, indent + 2, out)?;
+            }
+        }
+        MultiPolygon(mp) => {
+            write!(
+                out,
+                "{pad}MultiPolygon[{flags}] with {} elements",
+                mp.num_polygons()
+            )?;
+            for polygon in mp.polygons() {
+                out.push('\n');
+                write_summary(&polygon, None, indent + 2, out)?;
+            }
+        }
+        GeometryCollection(gc) => {
+            let n = gc.num_geometries();
+            write!(out, "{pad}GeometryCollection[{flags}] with {n} elements")?;
+            for g in gc.geometries() {
+                out.push('\n');
+                write_summary(&g, None, indent + 2, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_rings(
+    out: &mut String,
+    pad: &str,
+    name: &str,
+    flags: &str,
+    rings: &[usize],
+) -> std::fmt::Result {
+    write!(out, "{pad}{name}[{flags}] with {} rings", rings.len())?;
+    for (i, num_points) in rings.iter().enumerate() {
+        write!(out, "\n{pad}   ring {i} has {num_points} points")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_summary() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Summary.into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_Summary(ST_GeomFromText(wkt)) FROM (VALUES
+                    ('POINT(1 2)'),
+                    ('LINESTRING Z(0 0 0,1 1 1)'),
+                    ('POLYGON((0 0,1 0,1 1,0 0),(0.1 0.1,0.2 0.1,0.2 0.2,0.1 0.1))'),
+                    ('MULTIPOINT(0 0,1 1)'),
+                    ('GEOMETRYCOLLECTION(POINT(0 0),LINESTRING(0 0,1 1))'),
+                    ('LINESTRING EMPTY')
+                ) AS t(wkt);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let summaries = batches[0]
+            .column(0)
+            .as_string::<i32>()
+            .iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summaries,
+            vec![
+                "Point[]",
+                "LineString[ZB] with 2 points",
+                "Polygon[B] with 2 rings\n   ring 0 has 4 points\n   ring 1 has 4 points",
+                "MultiPoint[B] with 2 elements\n  Point[]\n  Point[]",
+                "GeometryCollection[B] with 2 elements\n  Point[]\n  LineString[] with 2 points",
+                "LineString[] with 0 points",
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use arrow_array::Int64Array;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::ScalarFunctionArgs;
+use geo_traits::{CoordTrait, Dimensions};
+
+/// The integer argument at `index` as one value per row, cast from any integer type.
+pub(super) fn index_arg(args: &ScalarFunctionArgs, index: usize) -> Result<Int64Array> {
+    let array = args.args[index]
+        .cast_to(&DataType::Int64, None)?
+        .into_array(args.number_rows)?;
+    Ok(array.as_primitive::<Int64Type>().clone())
+}
+
+/// Resolve a 1-based index into a sequence of `len` items to a 0-based offset.
+///
+/// If `allow_negative`, negative indices count backwards from the end, so that -1 is the last
+/// item. Returns `None` if the index is out of range.
+pub(super) fn resolve_index(n: i64, len: usize, allow_negative: bool) -> Option<usize> {
+    let len = len as i64;
+    if (1..=len).contains(&n) {
+        Some((n - 1) as usize)
+    } else if allow_negative && (-len..=-1).contains(&n) {
+        Some((len + n) as usize)
+    } else {
+        None
+    }
+}
+
+/// Convert an arbitrary coord to a WKT coord, keeping its Z and M values
+pub(super) fn to_wkt_coord(coord: &impl CoordTrait<T = f64>) -> wkt::types::Coord {
+    let (z, m) = match coord.dim() {
+        Dimensions::Xyz => (coord.nth(2), None),
+        Dimensions::Xym => (None, coord.nth(2)),
+        Dimensions::Xyzm => (coord.nth(2), coord.nth(3)),
+        _ => (None, None),
+    };
+    wkt::types::Coord {
+        x: coord.x(),
+        y: coord.y(),
+        z,
+        m,
+    }
+}
+
+/// Convert an arbitrary coord to a Point
+pub(super) fn coord_to_point(coord: impl CoordTrait<T = f64>) -> wkt::types::Point {
+    wkt::types::Point::from_coord(to_wkt_coord(&coord))
+}
@@ -3,12 +3,14 @@ mod r#box;
 mod extent;
 mod extrema;
 mod make_box;
+mod sort_key;
 pub mod util;
 
 pub use r#box::{Box2D, Box3D};
 pub use extent::Extent;
 pub use extrema::{XMax, XMin, YMax, YMin, ZMax, ZMin};
 pub use make_box::{MakeBox2D, MakeBox3D};
+pub use sort_key::{HilbertKey, ZOrderKey};
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
     session_context.register_udf(Box2D.into());
@@ -22,4 +24,6 @@ pub fn register(session_context: &datafusion::prelude::SessionContext) {
     session_context.register_udf(ZMin.into());
     session_context.register_udf(MakeBox2D.into());
     session_context.register_udf(MakeBox3D.into());
+    session_context.register_udf(HilbertKey::default().into());
+    session_context.register_udf(ZOrderKey::default().into());
 }
@@ -0,0 +1,301 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::UInt64Array;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo_traits::RectTrait;
+use geo_traits::to_geo::ToGeoCoord;
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_schema::{BoxType, Dimension};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::bounding_box::util::bounding_rect;
+
+/// The largest grid position along each axis of the reference extent.
+const MAX_POSITION: f64 = u32::MAX as f64;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct HilbertKey {
+    signature: Signature,
+}
+
+impl HilbertKey {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for HilbertKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static HILBERT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for HilbertKey {
+    fn name(&self) -> &str {
+        "st_hilbertkey"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(sort_key_impl(args, hilbert_index)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(HILBERT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the position along a Hilbert curve of the center of the bounding box of a geometry, on a 2^32 by 2^32 grid spanning a reference extent, such as the result of ST_Extent. Centers outside the extent are clamped to its edges. Ordering rows by this key clusters nearby geometries together. Returns NULL for empty geometries.",
+                "ST_HilbertKey(geom, extent)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("extent", "box2d or geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct ZOrderKey {
+    signature: Signature,
+}
+
+impl ZOrderKey {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for ZOrderKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static ZORDER_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for ZOrderKey {
+    fn name(&self) -> &str {
+        "st_zorderkey"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(sort_key_impl(args, z_order_index)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(ZORDER_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Morton code (Z-order curve position) of the center of the bounding box of a geometry, on a 2^32 by 2^32 grid spanning a reference extent, such as the result of ST_Extent. Centers outside the extent are clamped to its edges. Returns NULL for empty geometries.",
+                "ST_ZOrderKey(geom, extent)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("extent", "box2d or geometry")
+            .build()
+        }))
+    }
+}
+
+fn sort_key_impl(
+    args: ScalarFunctionArgs,
+    curve_index: fn(u32, u32) -> u64,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    // A scalar subquery of ST_Extent loses the extension metadata of its box
+    let extent_field = match (
+        args.arg_fields[1].extension_type_name(),
+        arrays[1].data_type(),
+    ) {
+        (None, DataType::Struct(_)) => {
+            Arc::new(BoxType::new(Dimension::XY, Default::default()).to_field("", true))
+        }
+        _ => args.arg_fields[1].clone(),
+    };
+    let extent_array = from_arrow_array(&arrays[1], &extent_field)?;
+    let rect_array = bounding_rect(&geo_array, false)?;
+    let extent_array = bounding_rect(&extent_array, false)?;
+
+    let result = rect_array
+        .iter()
+        .zip(extent_array.iter())
+        .map(|(rect, extent)| {
+            let (Some(rect), Some(extent)) = (rect, extent) else {
+                return Ok(None);
+            };
+            let (rect, extent) = (rect?, extent?);
+            let (min, max) = (rect.min().to_coord(), rect.max().to_coord());
+            let (extent_min, extent_max) = (extent.min().to_coord(), extent.max().to_coord());
+            // The bounding box of an empty geometry is inverted
+            if min.x > max.x || min.y > max.y || extent_min.x > extent_max.x {
+                return Ok(None);
+            }
+            let x = grid_position((min.x + max.x) / 2.0, extent_min.x, extent_max.x);
+            let y = grid_position((min.y + max.y) / 2.0, extent_min.y, extent_max.y);
+            Ok(Some(curve_index(x, y)))
+        })
+        .collect::<GeoDataFusionResult<UInt64Array>>()?;
+
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+/// The position of a coordinate on the grid spanning `min..=max`.
+fn grid_position(value: f64, min: f64, max: f64) -> u32 {
+    if max <= min {
+        return 0;
+    }
+    ((value - min) / (max - min) * MAX_POSITION).clamp(0.0, MAX_POSITION) as u32
+}
+
+/// The distance along the Hilbert curve filling the 2^32 by 2^32 grid of a grid position.
+fn hilbert_index(x: u32, y: u32) -> u64 {
+    let (mut x, mut y) = (x as u64, y as u64);
+    let mut index = 0;
+    let mut s = 1u64 << 31;
+    while s > 0 {
+        let rx = (x & s != 0) as u64;
+        let ry = (y & s != 0) as u64;
+        index += s * s * ((3 * rx) ^ ry);
+        // Rotate the quadrant so that the curve within it has the standard orientation
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - (x & (s - 1));
+                y = s - 1 - (y & (s - 1));
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x &= s - 1;
+        y &= s - 1;
+        s >>= 1;
+    }
+    index
+}
+
+/// The Morton code of a grid position, with the bits of x in the even positions.
+fn z_order_index(x: u32, y: u32) -> u64 {
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+/// Spread the 32 bits of a value over the even bit positions of a u64.
+fn spread_bits(value: u32) -> u64 {
+    let mut value = value as u64;
+    value = (value | (value << 16)) & 0x0000_FFFF_0000_FFFF;
+    value = (value | (value << 8)) & 0x00FF_00FF_00FF_00FF;
+    value = (value | (value << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+    value = (value | (value << 1)) & 0x5555_5555_5555_5555;
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int64Type, UInt64Type};
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::bounding_box::{Extent, MakeBox2D};
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_sort_keys() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(HilbertKey::default().into());
+        ctx.register_udf(ZOrderKey::default().into());
+        ctx.register_udf(MakeBox2D.into());
+        ctx.register_udf(Point::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_HilbertKey(ST_Point(x, y), ST_MakeBox2D(ST_Point(0, 0), ST_Point(10, 10))),
+                    ST_ZOrderKey(ST_Point(x, y), ST_MakeBox2D(ST_Point(0, 0), ST_Point(10, 10)))
+                FROM (VALUES (0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0), (20.0, -5.0)) AS t(x, y);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let hilbert = batches[0].column(0).as_primitive::<UInt64Type>();
+        let z_order = batches[0].column(1).as_primitive::<UInt64Type>();
+        // The Hilbert curve visits the four corners in order, and clamps points outside the extent
+        assert_eq!(
+            hilbert.values().as_ref(),
+            &[
+                0,
+                0x5555_5555_5555_5555,
+                0xAAAA_AAAA_AAAA_AAAA,
+                u64::MAX,
+                u64::MAX
+            ]
+        );
+        assert_eq!(
+            z_order.values().as_ref(),
+            &[
+                0,
+                0xAAAA_AAAA_AAAA_AAAA,
+                u64::MAX,
+                0x5555_5555_5555_5555,
+                0x5555_5555_5555_5555
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hilbert_key_with_extent() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(HilbertKey::default().into());
+        ctx.register_udaf(Extent.into());
+        ctx.register_udf(Point::default().into());
+
+        let df = ctx
+            .sql(
+                "WITH t AS (SELECT ST_Point(x, y) AS geom, id
+                    FROM (VALUES (1, 0.0, 0.0), (2, 9.0, 9.0), (3, 1.0, 1.0), (4, 8.0, 9.0)) AS v(id, x, y))
+                SELECT id, ST_HilbertKey(geom, (SELECT ST_Extent(geom) FROM t)) FROM t
+                ORDER BY ST_HilbertKey(geom, (SELECT ST_Extent(geom) FROM t));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let ids = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 3, 4, 2]);
+    }
+}
@@ -307,7 +307,7 @@ impl GeometryTrait for BoundingRect {
 ///
 /// Note that this is fully planar and **does not** handle the antimeridian for geographic
 /// coordinates.
-pub(crate) fn bounding_rect(arr: &dyn GeoArrowArray, include_z: bool) -> GeoArrowResult<RectArray> {
+pub fn bounding_rect(arr: &dyn GeoArrowArray, include_z: bool) -> GeoArrowResult<RectArray> {
     if let Some(rect_arr) = arr.as_rect_opt() {
         Ok(rect_arr.clone())
     } else {
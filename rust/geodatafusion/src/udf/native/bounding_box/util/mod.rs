@@ -1,3 +1,3 @@
 pub(super) mod bounds;
 
-pub use bounds::total_bounds;
+pub use bounds::{bounding_rect, total_bounds};
@@ -1,6 +1,8 @@
 mod point;
+mod tile_envelope;
 
 pub use point::{MakePoint, MakePointM, Point, PointM, PointZ, PointZM};
+pub use tile_envelope::TileEnvelope;
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
     session_context.register_udf(MakePoint::default().into());
@@ -9,4 +11,5 @@ pub fn register(session_context: &datafusion::prelude::SessionContext) {
     session_context.register_udf(PointM::default().into());
     session_context.register_udf(PointZ::default().into());
     session_context.register_udf(PointZM::default().into());
+    session_context.register_udf(TileEnvelope::default().into());
 }
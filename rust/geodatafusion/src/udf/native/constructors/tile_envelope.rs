@@ -0,0 +1,222 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PolygonType};
+
+use crate::crs::crs_from_srid;
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::bounding_box::util::total_bounds;
+
+/// Half the width of the Web Mercator (EPSG:3857) world extent.
+const WEB_MERCATOR_HALF_WIDTH: f64 = 20037508.342789244;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct TileEnvelope {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl TileEnvelope {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Any(3),
+                    TypeSignature::Any(4),
+                    TypeSignature::Any(5),
+                ],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for TileEnvelope {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileEnvelope {
+    fn name(&self) -> &str {
+        "st_tileenvelope"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        // Tiles of custom bounds are in the CRS of the bounds
+        let metadata = match args.arg_fields.get(3) {
+            Some(bounds_field) => Arc::new(Metadata::try_from(bounds_field.as_ref())?),
+            None => Arc::new(Metadata::new(crs_from_srid(3857), None)),
+        };
+        let output_type =
+            PolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tile_envelope_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a rectangular Polygon giving the extent of a tile in the XYZ tile system. The tile is specified by the zoom level Z and the XY index of the tile in the grid at that level. By default the tile bounds are in Web Mercator (EPSG:3857), using the standard range of the Web Mercator system (-20037508.342789, 20037508.342789). Optionally, the bounds of the tiling may be given as a geometry whose bounding box is used. The margin expands the tile by the given fraction of its size in each direction.",
+                "ST_TileEnvelope(tileZoom, tileX, tileY, bounds, margin)",
+            )
+            .with_argument("tileZoom", "integer")
+            .with_argument("tileX", "integer")
+            .with_argument("tileY", "integer")
+            .with_argument("bounds", "geometry, default the Web Mercator world")
+            .with_argument("margin", "float, default 0.0")
+            .build()
+        }))
+    }
+}
+
+fn tile_envelope_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let zxy = args.args[..3]
+        .iter()
+        .map(|arg| arg.cast_to(&DataType::Int64, None))
+        .collect::<Result<Vec<_>>>()?;
+    let zxy = ColumnarValue::values_to_arrays(&zxy)?;
+
+    let bounds = match args.args.get(3) {
+        Some(ColumnarValue::Scalar(scalar)) => {
+            let array = scalar.to_array()?;
+            let bounds = total_bounds(from_arrow_array(&array, &args.arg_fields[3])?.as_ref())?;
+            [bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()]
+        }
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized tile bounds not yet implemented".to_string(),
+            )
+            .into());
+        }
+        None => [
+            -WEB_MERCATOR_HALF_WIDTH,
+            -WEB_MERCATOR_HALF_WIDTH,
+            WEB_MERCATOR_HALF_WIDTH,
+            WEB_MERCATOR_HALF_WIDTH,
+        ],
+    };
+    let margin = match args.args.get(4) {
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Float64)? {
+            ScalarValue::Float64(Some(margin)) if margin >= -0.5 => margin,
+            ScalarValue::Float64(None) => 0.0,
+            _ => {
+                return Err(DataFusionError::Execution(
+                    "ST_TileEnvelope margin must be at least -0.5".to_string(),
+                )
+                .into());
+            }
+        },
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized tile margin not yet implemented".to_string(),
+            )
+            .into());
+        }
+        None => 0.0,
+    };
+
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let mut builder = PolygonBuilder::new(typ);
+    let z = zxy[0].as_primitive::<Int64Type>();
+    let x = zxy[1].as_primitive::<Int64Type>();
+    let y = zxy[2].as_primitive::<Int64Type>();
+    for ((z, x), y) in z.iter().zip(x.iter()).zip(y.iter()) {
+        if let (Some(z), Some(x), Some(y)) = (z, x, y) {
+            let rect = tile_rect(z, x, y, bounds, margin)?;
+            builder.push_rect(Some(&rect))?;
+        } else {
+            builder.push_polygon(None::<&geo::Polygon>)?;
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+/// Compute the envelope of an XYZ tile within the given bounds.
+fn tile_rect(z: i64, x: i64, y: i64, bounds: [f64; 4], margin: f64) -> Result<geo::Rect> {
+    if !(0..=31).contains(&z) {
+        return Err(DataFusionError::Execution(format!(
+            "Invalid tile zoom level {z}"
+        )));
+    }
+    let num_tiles = 1i64 << z;
+    if !(0..num_tiles).contains(&x) || !(0..num_tiles).contains(&y) {
+        return Err(DataFusionError::Execution(format!(
+            "Invalid tile {z}/{x}/{y}"
+        )));
+    }
+
+    let [xmin, ymin, xmax, ymax] = bounds;
+    let tile_width = (xmax - xmin) / num_tiles as f64;
+    let tile_height = (ymax - ymin) / num_tiles as f64;
+    // Tile rows are counted from the top of the bounds
+    Ok(geo::Rect::new(
+        geo::coord! {
+            x: xmin + tile_width * (x as f64 - margin),
+            y: ymax - tile_height * (y as f64 + 1.0 + margin),
+        },
+        geo::coord! {
+            x: xmin + tile_width * (x as f64 + 1.0 + margin),
+            y: ymax - tile_height * (y as f64 - margin),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_tile_envelope() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(TileEnvelope::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql("SELECT ST_AsText(ST_TileEnvelope(2, 1, 1)), ST_AsText(ST_TileEnvelope(3, 1, 1, ST_GeomFromText('LINESTRING(-180 -90,180 90)')));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((-10018754.171394622 0,-10018754.171394622 10018754.171394622,0 10018754.171394622,0 0,-10018754.171394622 0))"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "POLYGON((-135 45,-135 67.5,-90 67.5,-90 45,-135 45))"
+        );
+    }
+}
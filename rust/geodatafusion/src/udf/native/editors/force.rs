@@ -0,0 +1,676 @@
+//! Coerce the coordinate dimension of geometries
+
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::{
+    GeometryBuilder, GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder,
+    MultiPointBuilder, MultiPolygonBuilder, PointBuilder, PolygonBuilder,
+};
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{
+    CoordType, Dimension, GeoArrowType, GeometryCollectionType, GeometryType, LineStringType,
+    MultiLineStringType, MultiPointType, MultiPolygonType, PointType, PolygonType,
+};
+use wkt::Wkt;
+use wkt::types::{self, Coord};
+
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Force2D {
+    coord_type: CoordType,
+}
+
+impl Force2D {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+}
+
+impl Default for Force2D {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static FORCE_2D_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Force2D {
+    fn name(&self) -> &str {
+        "st_force2d"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, Dimension::XY, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(force_impl(args, Force::new(Dimension::XY))?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(FORCE_2D_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Forces the geometries into a \"2-dimensional mode\" so that all output representations will only have the X and Y coordinates.",
+                "ST_Force2D(geom)",
+            )
+            .with_argument("geom", "geometry")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Force3DZ {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Force3DZ {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for Force3DZ {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static FORCE_3DZ_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+static FORCE_3DZ_ALIASES: LazyLock<Vec<String>> = LazyLock::new(|| vec!["st_force3d".to_string()]);
+
+impl ScalarUDFImpl for Force3DZ {
+    fn name(&self) -> &str {
+        "st_force3dz"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn aliases(&self) -> &[String] {
+        &FORCE_3DZ_ALIASES
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, Dimension::XYZ, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let Some(z) = fill_value_arg(&args, 1)? else {
+            return Ok(null_result(&args)?);
+        };
+        let force = Force {
+            z,
+            ..Force::new(Dimension::XYZ)
+        };
+        Ok(force_impl(args, force)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(FORCE_3DZ_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Forces the geometries into XYZ mode. If a geometry has no Z component, then a Z value of zvalue is added, and any M values are dropped.",
+                "ST_Force3DZ(geom, 0.0)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zvalue", "float, default 0.0")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Force3DM {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Force3DM {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for Force3DM {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static FORCE_3DM_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Force3DM {
+    fn name(&self) -> &str {
+        "st_force3dm"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, Dimension::XYM, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let Some(m) = fill_value_arg(&args, 1)? else {
+            return Ok(null_result(&args)?);
+        };
+        let force = Force {
+            m,
+            ..Force::new(Dimension::XYM)
+        };
+        Ok(force_impl(args, force)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(FORCE_3DM_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Forces the geometries into XYM mode. If a geometry has no M component, then an M value of mvalue is added, and any Z values are dropped.",
+                "ST_Force3DM(geom, 0.0)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("mvalue", "float, default 0.0")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Force4D {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl Force4D {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=3).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for Force4D {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static FORCE_4D_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Force4D {
+    fn name(&self) -> &str {
+        "st_force4d"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(return_field_impl(args, Dimension::XYZM, self.coord_type)?)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let (Some(z), Some(m)) = (fill_value_arg(&args, 1)?, fill_value_arg(&args, 2)?) else {
+            return Ok(null_result(&args)?);
+        };
+        let force = Force {
+            z,
+            m,
+            ..Force::new(Dimension::XYZM)
+        };
+        Ok(force_impl(args, force)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(FORCE_4D_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Forces the geometries into XYZM mode. Z and M values of zvalue and mvalue are added to geometries without them.",
+                "ST_Force4D(geom, 0.0, 0.0)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zvalue", "float, default 0.0")
+            .with_argument("mvalue", "float, default 0.0")
+            .build()
+        }))
+    }
+}
+
+/// The optional fill value argument at `index`, which defaults to 0, or `None` if it is NULL
+fn fill_value_arg(args: &ScalarFunctionArgs, index: usize) -> GeoDataFusionResult<Option<f64>> {
+    match args.args.get(index) {
+        None => Ok(Some(0.0)),
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Float64)? {
+            ScalarValue::Float64(value) => Ok(value),
+            _ => unreachable!("cast to Float64 yields a Float64 scalar"),
+        },
+        Some(ColumnarValue::Array(_)) => Err(DataFusionError::NotImplemented(
+            "Vectorized fill values not yet implemented".to_string(),
+        )
+        .into()),
+    }
+}
+
+/// The output type with the same geometry type as the input and the new dimension.
+///
+/// Inputs without a single dimension, such as WKB or mixed geometry arrays, return a mixed
+/// geometry array, and boxes return polygons.
+fn output_type(input: &GeoArrowType, dim: Dimension, coord_type: CoordType) -> GeoArrowType {
+    let metadata = input.metadata().clone();
+    let coord_type = input.coord_type().unwrap_or(coord_type);
+    match input {
+        GeoArrowType::Point(_) => {
+            GeoArrowType::Point(PointType::new(dim, metadata).with_coord_type(coord_type))
+        }
+        GeoArrowType::LineString(_) => {
+            GeoArrowType::LineString(LineStringType::new(dim, metadata).with_coord_type(coord_type))
+        }
+        GeoArrowType::Polygon(_) | GeoArrowType::Rect(_) => {
+            GeoArrowType::Polygon(PolygonType::new(dim, metadata).with_coord_type(coord_type))
+        }
+        GeoArrowType::MultiPoint(_) => {
+            GeoArrowType::MultiPoint(MultiPointType::new(dim, metadata).with_coord_type(coord_type))
+        }
+        GeoArrowType::MultiLineString(_) => GeoArrowType::MultiLineString(
+            MultiLineStringType::new(dim, metadata).with_coord_type(coord_type),
+        ),
+        GeoArrowType::MultiPolygon(_) => GeoArrowType::MultiPolygon(
+            MultiPolygonType::new(dim, metadata).with_coord_type(coord_type),
+        ),
+        GeoArrowType::GeometryCollection(_) => GeoArrowType::GeometryCollection(
+            GeometryCollectionType::new(dim, metadata).with_coord_type(coord_type),
+        ),
+        _ => GeoArrowType::Geometry(GeometryType::new(metadata).with_coord_type(coord_type)),
+    }
+}
+
+fn return_field_impl(
+    args: ReturnFieldArgs,
+    dim: Dimension,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<FieldRef> {
+    let input_type = GeoArrowType::from_arrow_field(args.arg_fields[0].as_ref())?;
+    Ok(Arc::new(
+        output_type(&input_type, dim, coord_type).to_field("", true),
+    ))
+}
+
+fn force_impl(args: ScalarFunctionArgs, force: Force) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args.args[..1])?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+
+    let geo_array_ref = geo_array.as_ref();
+    let geoms = downcast_geoarrow_array!(geo_array_ref, force_array, &force)?;
+    let result = build_array(&geoms, to_type)?;
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+/// The all-NULL result of a NULL fill value
+fn null_result(args: &ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+    let result = build_array(&vec![None; args.number_rows], to_type)?;
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+fn force_array<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    force: &Force,
+) -> GeoArrowResult<Vec<Option<Wkt>>> {
+    array
+        .iter()
+        .map(|item| item.map(|geom| Ok(force.geometry(&geom?))).transpose())
+        .collect()
+}
+
+fn build_array(
+    geoms: &[Option<Wkt>],
+    to_type: GeoArrowType,
+) -> GeoArrowResult<Arc<dyn GeoArrowArray>> {
+    Ok(match to_type {
+        GeoArrowType::Point(typ) => {
+            Arc::new(PointBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::LineString(typ) => {
+            Arc::new(LineStringBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::Polygon(typ) => {
+            Arc::new(PolygonBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiPoint(typ) => {
+            Arc::new(MultiPointBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiLineString(typ) => {
+            Arc::new(MultiLineStringBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiPolygon(typ) => {
+            Arc::new(MultiPolygonBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::GeometryCollection(typ) => {
+            Arc::new(GeometryCollectionBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        GeoArrowType::Geometry(typ) => {
+            Arc::new(GeometryBuilder::from_nullable_geometries(geoms, typ)?.finish())
+        }
+        _ => unreachable!("output_type only returns native types"),
+    })
+}
+
+/// The target dimension, with fill values for missing Z and M ordinates
+struct Force {
+    dim: Dimension,
+    z: f64,
+    m: f64,
+}
+
+impl Force {
+    fn new(dim: Dimension) -> Self {
+        Self {
+            dim,
+            z: 0.0,
+            m: 0.0,
+        }
+    }
+
+    fn wkt_dim(&self) -> types::Dimension {
+        match self.dim {
+            Dimension::XY => types::Dimension::XY,
+            Dimension::XYZ => types::Dimension::XYZ,
+            Dimension::XYM => types::Dimension::XYM,
+            Dimension::XYZM => types::Dimension::XYZM,
+        }
+    }
+
+    fn coord(&self, coord: &impl CoordTrait<T = f64>) -> Coord {
+        let (z, m) = match coord.dim() {
+            Dimensions::Xyz => (coord.nth(2), None),
+            Dimensions::Xym => (None, coord.nth(2)),
+            Dimensions::Xyzm => (coord.nth(2), coord.nth(3)),
+            _ => (None, None),
+        };
+        let has_z = matches!(self.dim, Dimension::XYZ | Dimension::XYZM);
+        let has_m = matches!(self.dim, Dimension::XYM | Dimension::XYZM);
+        Coord {
+            x: coord.x(),
+            y: coord.y(),
+            z: has_z.then(|| z.unwrap_or(self.z)),
+            m: has_m.then(|| m.unwrap_or(self.m)),
+        }
+    }
+
+    fn point(&self, point: &impl PointTrait<T = f64>) -> types::Point {
+        types::Point::new(point.coord().map(|c| self.coord(&c)), self.wkt_dim())
+    }
+
+    fn line_string(&self, ls: &impl LineStringTrait<T = f64>) -> types::LineString {
+        types::LineString::new(
+            ls.coords().map(|c| self.coord(&c)).collect(),
+            self.wkt_dim(),
+        )
+    }
+
+    fn polygon(&self, polygon: &impl PolygonTrait<T = f64>) -> types::Polygon {
+        let rings = polygon
+            .exterior()
+            .into_iter()
+            .chain(polygon.interiors())
+            .map(|ring| self.line_string(&ring))
+            .collect();
+        types::Polygon::new(rings, self.wkt_dim())
+    }
+
+    fn geometry(&self, geom: &impl GeometryTrait<T = f64>) -> Wkt {
+        use geo_traits::GeometryType::*;
+
+        let dim = self.wkt_dim();
+        match geom.as_type() {
+            Point(point) => Wkt::Point(self.point(point)),
+            LineString(ls) => Wkt::LineString(self.line_string(ls)),
+            Polygon(polygon) => Wkt::Polygon(self.polygon(polygon)),
+            MultiPoint(mp) => Wkt::MultiPoint(types::MultiPoint::new(
+                mp.points().map(|p| self.point(&p)).collect(),
+                dim,
+            )),
+            MultiLineString(mls) => Wkt::MultiLineString(types::MultiLineString::new(
+                mls.line_strings().map(|ls| self.line_string(&ls)).collect(),
+                dim,
+            )),
+            MultiPolygon(mp) => Wkt::MultiPolygon(types::MultiPolygon::new(
+                mp.polygons().map(|p| self.polygon(&p)).collect(),
+                dim,
+            )),
+            GeometryCollection(gc) => Wkt::GeometryCollection(types::GeometryCollection::new(
+                gc.geometries().map(|g| self.geometry(&g)).collect(),
+                dim,
+            )),
+            Rect(rect) => {
+                // The polygon of a box takes its Z and M values from the minimum corner
+                let (min, max) = (self.coord(&rect.min()), self.coord(&rect.max()));
+                let corner = |x, y| Coord { x, y, ..min };
+                let ring = vec![
+                    corner(min.x, min.y),
+                    corner(max.x, min.y),
+                    corner(max.x, max.y),
+                    corner(min.x, max.y),
+                    corner(min.x, min.y),
+                ];
+                Wkt::Polygon(types::Polygon::new(
+                    vec![types::LineString::new(ring, dim)],
+                    dim,
+                ))
+            }
+            Line(line) => Wkt::LineString(types::LineString::new(
+                vec![self.coord(&line.start()), self.coord(&line.end())],
+                dim,
+            )),
+            Triangle(triangle) => {
+                let mut ring = triangle.coords().map(|c| self.coord(&c)).to_vec();
+                ring.push(ring[0]);
+                Wkt::Polygon(types::Polygon::new(
+                    vec![types::LineString::new(ring, dim)],
+                    dim,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::RecordBatch;
+    use arrow_array::cast::AsArray;
+    use arrow_schema::Schema;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_schema::{Crs, Metadata};
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_force() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Force2D::default().into());
+        ctx.register_udf(Force3DZ::default().into());
+        ctx.register_udf(Force3DM::default().into());
+        ctx.register_udf(Force4D::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql(
+                "SELECT
+                    ST_AsText(ST_Force2D(g)),
+                    ST_AsText(ST_Force3D(g)),
+                    ST_AsText(ST_Force3DM(g, 5)),
+                    ST_AsText(ST_Force4D(g, 7, 9))
+                FROM (SELECT ST_GeomFromText(wkt) AS g FROM (VALUES
+                    ('LINESTRING Z(0 0 1,1 1 2)'),
+                    ('POINT M(1 2 3)'),
+                    ('POLYGON((0 0,1 0,1 1,0 0))')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let column = |i: usize| {
+            batches[0]
+                .column(i)
+                .as_string::<i32>()
+                .iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            column(0),
+            vec![
+                "LINESTRING(0 0,1 1)",
+                "POINT(1 2)",
+                "POLYGON((0 0,1 0,1 1,0 0))"
+            ]
+        );
+        assert_eq!(
+            column(1),
+            vec![
+                "LINESTRING Z(0 0 1,1 1 2)",
+                "POINT Z(1 2 0)",
+                "POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))"
+            ]
+        );
+        assert_eq!(
+            column(2),
+            vec![
+                "LINESTRING M(0 0 5,1 1 5)",
+                "POINT M(1 2 3)",
+                "POLYGON M((0 0 5,1 0 5,1 1 5,0 0 5))"
+            ]
+        );
+        assert_eq!(
+            column(3),
+            vec![
+                "LINESTRING ZM(0 0 1 9,1 1 2 9)",
+                "POINT ZM(1 2 7 3)",
+                "POLYGON ZM((0 0 7 9,1 0 7 9,1 1 7 9,0 0 7 9))"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_force_null_fill_value() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Force3DZ::default().into());
+        ctx.register_udf(Force3DM::default().into());
+        ctx.register_udf(Force4D::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_Force3D(g, NULL), ST_Force3DM(g, NULL), ST_Force4D(g, 1, NULL)
+                FROM (SELECT ST_GeomFromText(wkt) AS g FROM (VALUES
+                    ('POINT(1 2)'),
+                    ('LINESTRING(0 0,1 1)')
+                ) AS t(wkt));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        for column in batches[0].columns() {
+            assert_eq!(column.len(), 2);
+            assert_eq!(column.logical_null_count(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_force_2d_rebuilds_field_type() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Force2D::default().into());
+
+        let metadata = Arc::new(Metadata::new(
+            Crs::from_authority_code("EPSG:4326".to_string()),
+            None,
+        ));
+        let point_type = PointType::new(Dimension::XYZ, metadata.clone());
+        let points = PointBuilder::from_points(
+            [wkt::wkt! { POINT Z(1.0 2.0 3.0) }].iter(),
+            point_type.clone(),
+        )
+        .finish();
+
+        let schema = Schema::new([Arc::new(point_type.to_field("geometry", true))]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![points.to_array_ref()]).unwrap();
+        ctx.register_batch("t", batch).unwrap();
+
+        let df = ctx
+            .sql("SELECT ST_Force2D(geometry) FROM t;")
+            .await
+            .unwrap();
+        let batch = df.collect().await.unwrap().into_iter().next().unwrap();
+        let output_type = GeoArrowType::from_arrow_field(batch.schema().field(0)).unwrap();
+        let GeoArrowType::Point(output_type) = output_type else {
+            panic!("expected point output, got {output_type:?}");
+        };
+        assert_eq!(output_type.dimension(), Dimension::XY);
+        assert_eq!(output_type.metadata(), &metadata);
+    }
+}
@@ -0,0 +1,10 @@
+mod force;
+
+pub use force::{Force2D, Force3DM, Force3DZ, Force4D};
+
+pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(Force2D::default().into());
+    session_context.register_udf(Force3DZ::default().into());
+    session_context.register_udf(Force3DM::default().into());
+    session_context.register_udf(Force4D::default().into());
+}
@@ -0,0 +1,354 @@
+//! Polygon building from closed ring linework.
+
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geo::{Area, Contains};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::{MultiPolygonBuilder, PolygonBuilder};
+use geoarrow_schema::{CoordType, Dimension, Metadata, MultiPolygonType, PolygonType};
+
+use super::util::to_simple_geometry;
+use crate::crs::{crs_from_srid, srid_arg};
+use crate::error::GeoDataFusionResult;
+
+fn bd_poly_signature() -> Signature {
+    let mut variants = vec![];
+    for input_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+        variants.push(TypeSignature::Exact(vec![input_type.clone()]));
+        variants.push(TypeSignature::Exact(vec![
+            input_type.clone(),
+            DataType::Int64,
+        ]));
+        // Keeps a NULL SRID a literal rather than a cast, so it is treated as absent
+        variants.push(TypeSignature::Exact(vec![input_type, DataType::Null]));
+    }
+    Signature::one_of(variants, Volatility::Immutable)
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct BdPolyFromText {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl BdPolyFromText {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: bd_poly_signature(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for BdPolyFromText {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static BD_POLY_FROM_TEXT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for BdPolyFromText {
+    fn name(&self) -> &str {
+        "st_bdpolyfromtext"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let metadata = output_metadata("ST_BdPolyFromText", &args)?;
+        let output_type =
+            PolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(bd_poly_from_text_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(BD_POLY_FROM_TEXT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a Polygon from the WKT of a MultiLineString of closed rings. Rings inside another ring become its holes. Raises an error if the rings do not form exactly one polygon; use ST_BdMPolyFromText in that case.",
+                "ST_BdPolyFromText(WKT, srid)",
+            )
+            .with_argument("WKT", "text, a MultiLineString of closed rings")
+            .with_argument("srid", "integer SRID value")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct BdMPolyFromText {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl BdMPolyFromText {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: bd_poly_signature(),
+            coord_type,
+        }
+    }
+}
+
+impl Default for BdMPolyFromText {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static BD_MPOLY_FROM_TEXT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for BdMPolyFromText {
+    fn name(&self) -> &str {
+        "st_bdmpolyfromtext"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let metadata = output_metadata("ST_BdMPolyFromText", &args)?;
+        let output_type =
+            MultiPolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(bd_mpoly_from_text_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(BD_MPOLY_FROM_TEXT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a MultiPolygon from the WKT of a MultiLineString of closed rings. Rings inside another ring become its holes, and rings inside a hole become new polygons.",
+                "ST_BdMPolyFromText(WKT, srid)",
+            )
+            .with_argument("WKT", "text, a MultiLineString of closed rings")
+            .with_argument("srid", "integer SRID value")
+            .build()
+        }))
+    }
+}
+
+fn output_metadata(function: &str, args: &ReturnFieldArgs) -> Result<Arc<Metadata>> {
+    match srid_arg(function, args, 1)? {
+        Some(srid) => Ok(Arc::new(Metadata::new(crs_from_srid(srid), None))),
+        None => Ok(Arc::new(Metadata::try_from(args.arg_fields[0].as_ref())?)),
+    }
+}
+
+fn bd_poly_from_text_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let polygons = build_rows("ST_BdPolyFromText", &args, |polygons| {
+        let mut polygons = polygons.into_iter();
+        match (polygons.next(), polygons.next()) {
+            (Some(polygon), None) => Ok(polygon),
+            _ => Err(DataFusionError::Execution(
+                "ST_BdPolyFromText input does not form a single polygon, try ST_BdMPolyFromText instead"
+                    .to_string(),
+            )),
+        }
+    })?;
+    let result = PolygonBuilder::from_nullable_polygons(&polygons, typ).finish();
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+fn bd_mpoly_from_text_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let typ = args.return_field.extension_type::<MultiPolygonType>();
+    let multi_polygons = build_rows("ST_BdMPolyFromText", &args, |polygons| {
+        Ok(geo::MultiPolygon::new(polygons))
+    })?;
+    let result = MultiPolygonBuilder::from_nullable_multi_polygons(&multi_polygons, typ).finish();
+    Ok(ColumnarValue::Array(result.into_array_ref()))
+}
+
+/// Parse each row as a MultiLineString of rings, build its polygons and map them to the output.
+fn build_rows<T>(
+    function: &str,
+    args: &ScalarFunctionArgs,
+    output: impl Fn(Vec<geo::Polygon>) -> Result<T>,
+) -> Result<Vec<Option<T>>> {
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+    array
+        .as_string::<i32>()
+        .iter()
+        .map(|text| {
+            text.map(|text| {
+                let wkt = wkt::Wkt::<f64>::from_str(text)
+                    .map_err(|err| DataFusionError::Execution(format!("Invalid WKT: {err}")))?;
+                let geo::Geometry::MultiLineString(rings) = to_simple_geometry(&wkt)
+                    .map_err(|err| DataFusionError::External(Box::new(err)))?
+                else {
+                    return Err(DataFusionError::Execution(format!(
+                        "{function} input must be a MultiLineString"
+                    )));
+                };
+                output(build_polygons(function, rings)?)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+/// Build polygons from closed rings.
+///
+/// Each ring is nested inside the smallest ring that contains it. Rings at an even depth are
+/// shells, and rings at an odd depth are holes of the shell that contains them.
+fn build_polygons(function: &str, rings: geo::MultiLineString) -> Result<Vec<geo::Polygon>> {
+    let mut rings = rings
+        .into_iter()
+        .map(|ring| {
+            if !ring.is_closed() || ring.0.len() < 4 {
+                return Err(DataFusionError::Execution(format!(
+                    "{function} input rings must be closed"
+                )));
+            }
+            Ok(geo::Polygon::new(ring, vec![]))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // A ring can only be contained by a larger ring, so visit rings from largest to smallest
+    rings.sort_by(|a, b| b.unsigned_area().total_cmp(&a.unsigned_area()));
+
+    let mut depths: Vec<usize> = Vec::with_capacity(rings.len());
+    let mut shell_indices: Vec<Option<usize>> = Vec::with_capacity(rings.len());
+    let mut polygons: Vec<geo::Polygon> = vec![];
+    for (i, ring) in rings.iter().enumerate() {
+        // The most recently visited containing ring is the smallest one
+        let parent = (0..i).rev().find(|&j| rings[j].contains(ring));
+        let depth = parent.map_or(0, |j| depths[j] + 1);
+        depths.push(depth);
+        if depth % 2 == 0 {
+            shell_indices.push(Some(polygons.len()));
+            polygons.push(ring.clone());
+        } else {
+            // The parent of a hole is always a shell
+            let shell = shell_indices[parent.unwrap()].unwrap();
+            polygons[shell].interiors_push(ring.exterior().clone());
+            shell_indices.push(None);
+        }
+    }
+    Ok(polygons)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::AsText;
+
+    #[tokio::test]
+    async fn test_bd_poly_from_text() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(BdPolyFromText::default().into());
+        ctx.register_udf(BdMPolyFromText::default().into());
+        ctx.register_udf(AsText.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_BdPolyFromText('MULTILINESTRING((2 2,2 3,3 3,3 2,2 2),(0 0,0 10,10 10,10 0,0 0))', 4326)),
+                    ST_AsText(ST_BdMPolyFromText('MULTILINESTRING((0 0,0 10,10 10,10 0,0 0),(1 1,1 9,9 9,9 1,1 1),(2 2,2 3,3 3,3 2,2 2),(20 20,20 21,21 21,20 20))'));",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 3,3 3,3 2,2 2))"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(1 1,1 9,9 9,9 1,1 1)),((2 2,2 3,3 3,3 2,2 2)),((20 20,20 21,21 21,20 20)))"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bd_poly_from_text_multiple_shells() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(BdPolyFromText::default().into());
+
+        let result = ctx
+            .sql(
+                "SELECT ST_BdPolyFromText('MULTILINESTRING((0 0,0 1,1 1,0 0),(5 5,5 6,6 6,5 5))');",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bd_mpoly_from_text_srid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(BdMPolyFromText::default().into());
+
+        // A NULL SRID is ignored, and SRID 0 means no CRS
+        let df = ctx
+            .sql(
+                "SELECT ST_BdMPolyFromText('MULTILINESTRING((0 0,0 1,1 1,0 0))', NULL) AS a,
+                    ST_BdMPolyFromText('MULTILINESTRING((0 0,0 1,1 1,0 0))', 0) AS b;",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        for field in schema.fields() {
+            let typ = field.extension_type::<MultiPolygonType>();
+            assert_eq!(typ.metadata().crs(), &Default::default());
+        }
+
+        let err = ctx
+            .sql(
+                "SELECT ST_BdMPolyFromText('MULTILINESTRING((0 0,0 1,1 1,0 0))', s) FROM (VALUES (4326)) AS t(s);",
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err.find_root(), DataFusionError::Plan(_)),
+            "unexpected error: {err}"
+        );
+
+        let err = ctx
+            .sql("SELECT ST_BdMPolyFromText('MULTILINESTRING((0 0,0 1,1 1))');")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("ST_BdMPolyFromText input rings must be closed"),
+            "unexpected error: {err}"
+        );
+    }
+}
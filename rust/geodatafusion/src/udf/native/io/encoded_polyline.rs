@@ -0,0 +1,412 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::{CoordTrait, GeometryTrait, LineStringTrait, MultiPointTrait, PointTrait};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::LineStringBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowError;
+use geoarrow_schema::{CoordType, Dimension, LineStringType, Metadata};
+
+use super::util::quantize;
+use crate::crs::crs_from_srid;
+use crate::error::GeoDataFusionResult;
+
+const DEFAULT_PRECISION: i64 = 5;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsEncodedPolyline {
+    signature: Signature,
+}
+
+impl AsEncodedPolyline {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsEncodedPolyline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_ENCODED_POLYLINE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsEncodedPolyline {
+    fn name(&self) -> &str {
+        "st_asencodedpolyline"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_encoded_polyline_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_ENCODED_POLYLINE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry as an Encoded Polyline. This format is used by Google Maps and OSRM, with precision 5 and 6 respectively. Only LineString and MultiPoint geometries are supported, and their coordinates are expected to be in longitude/latitude order.",
+                "ST_AsEncodedPolyline(geom, precision)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("precision", "integer, default 5")
+            .build()
+        }))
+    }
+}
+
+fn as_encoded_polyline_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let precision = precision_arg(&args)?;
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let result = encode_polylines(&geo_array, 10f64.powi(precision))?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn precision_arg(args: &ScalarFunctionArgs) -> Result<i32> {
+    match args.args.get(1) {
+        None => Ok(DEFAULT_PRECISION as i32),
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(precision)) if (0..=10).contains(&precision) => {
+                Ok(precision as i32)
+            }
+            ScalarValue::Int64(None) => Ok(DEFAULT_PRECISION as i32),
+            _ => Err(DataFusionError::Execution(
+                "Encoded polyline precision must be between 0 and 10".to_string(),
+            )),
+        },
+        Some(ColumnarValue::Array(_)) => Err(DataFusionError::NotImplemented(
+            "Vectorized encoded polyline precision not yet implemented".to_string(),
+        )),
+    }
+}
+
+fn encode_polylines(array: &dyn GeoArrowArray, factor: f64) -> GeoDataFusionResult<StringArray> {
+    downcast_geoarrow_array!(array, _encode_polylines_impl, factor)
+}
+
+fn _encode_polylines_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    factor: f64,
+) -> GeoDataFusionResult<StringArray> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let geom = geom?;
+                let mut encoder = PolylineEncoder {
+                    factor,
+                    last: (0, 0),
+                    out: String::new(),
+                };
+                match geom.as_type() {
+                    geo_traits::GeometryType::LineString(line_string) => {
+                        for coord in line_string.coords() {
+                            encoder.push_coord(&coord)?;
+                        }
+                    }
+                    geo_traits::GeometryType::MultiPoint(multi_point) => {
+                        for point in multi_point.points() {
+                            if let Some(coord) = point.coord() {
+                                encoder.push_coord(&coord)?;
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(GeoArrowError::IncorrectGeometryType(
+                            "ST_AsEncodedPolyline only supports LineString and MultiPoint geometries"
+                                .to_string(),
+                        )
+                        .into());
+                    }
+                }
+                Ok(encoder.out)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+struct PolylineEncoder {
+    factor: f64,
+    last: (i64, i64),
+    out: String,
+}
+
+impl PolylineEncoder {
+    /// Append a coordinate, which is encoded in latitude/longitude order.
+    fn push_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
+        let lat = quantize("ST_AsEncodedPolyline", coord.y(), self.factor)?;
+        let lon = quantize("ST_AsEncodedPolyline", coord.x(), self.factor)?;
+        self.push_value(lat.checked_sub(self.last.0).ok_or_else(delta_overflow)?);
+        self.push_value(lon.checked_sub(self.last.1).ok_or_else(delta_overflow)?);
+        self.last = (lat, lon);
+        Ok(())
+    }
+
+    fn push_value(&mut self, value: i64) {
+        // Equivalent to inverting the shifted negative values, without overflowing
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x20 {
+            self.out
+                .push(char::from((0x20 | (value & 0x1f)) as u8 + 63));
+            value >>= 5;
+        }
+        self.out.push(char::from(value as u8 + 63));
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct LineFromEncodedPolyline {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl LineFromEncodedPolyline {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for LineFromEncodedPolyline {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static LINE_FROM_ENCODED_POLYLINE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for LineFromEncodedPolyline {
+    fn name(&self) -> &str {
+        "st_linefromencodedpolyline"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        // Encoded polylines are always in WGS84 longitude/latitude
+        let metadata = Arc::new(Metadata::new(crs_from_srid(4326), None));
+        let output_type =
+            LineStringType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field(
+            args.arg_fields[0].name(),
+            args.arg_fields[0].is_nullable(),
+        )))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(line_from_encoded_polyline_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(LINE_FROM_ENCODED_POLYLINE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a LineString from an Encoded Polyline. The output has the EPSG:4326 CRS. Use a precision of 5 for Google Maps polylines and 6 for OSRM.",
+                "ST_LineFromEncodedPolyline(polyline, precision)",
+            )
+            .with_argument("polyline", "text")
+            .with_argument("precision", "integer, default 5")
+            .build()
+        }))
+    }
+}
+
+fn line_from_encoded_polyline_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let factor = 10f64.powi(precision_arg(&args)?);
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+
+    let typ = args.return_field.extension_type::<LineStringType>();
+    let mut builder = LineStringBuilder::new(typ);
+    for polyline in array.as_string::<i32>() {
+        if let Some(polyline) = polyline {
+            let line_string = decode_polyline(polyline, factor)?;
+            builder.push_line_string(Some(&line_string))?;
+        } else {
+            builder.push_line_string(None::<&geo::LineString>)?;
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+fn decode_polyline(polyline: &str, factor: f64) -> Result<geo::LineString> {
+    let invalid = || DataFusionError::Execution(format!("Invalid encoded polyline: {polyline}"));
+
+    let mut bytes = polyline.bytes();
+    let mut next_value = || -> Result<Option<i64>> {
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let Some(byte) = bytes.next() else {
+                return if shift == 0 { Ok(None) } else { Err(invalid()) };
+            };
+            if !(63..=126).contains(&byte) || shift > 60 {
+                return Err(invalid());
+            }
+            let chunk = u64::from(byte - 63);
+            // The last chunk of a 64-bit value holds its 4 highest bits
+            if shift == 60 && chunk & 0x1f > 0xf {
+                return Err(invalid());
+            }
+            result |= (chunk & 0x1f) << shift;
+            shift += 5;
+            if chunk < 0x20 {
+                break;
+            }
+        }
+        Ok(Some(((result >> 1) as i64) ^ -((result & 1) as i64)))
+    };
+
+    let (mut lat, mut lon) = (0i64, 0i64);
+    let mut coords = vec![];
+    while let Some(lat_delta) = next_value()? {
+        let lon_delta = next_value()?.ok_or_else(invalid)?;
+        lat = lat.checked_add(lat_delta).ok_or_else(delta_overflow)?;
+        lon = lon.checked_add(lon_delta).ok_or_else(delta_overflow)?;
+        coords.push(geo::coord! { x: lon as f64 / factor, y: lat as f64 / factor });
+    }
+    Ok(geo::LineString::new(coords))
+}
+
+fn delta_overflow() -> DataFusionError {
+    DataFusionError::Execution(
+        "Encoded polyline coordinate delta overflows a 64-bit integer".to_string(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_schema::Crs;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_line_from_encoded_polyline() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(LineFromEncodedPolyline::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        // Example from the Google polyline algorithm documentation
+        let df = ctx
+            .sql("SELECT ST_LineFromEncodedPolyline('_p~iF~ps|U_ulLnnqC_mqNvxq`@') AS geom, ST_AsText(ST_LineFromEncodedPolyline('_p~iF~ps|U_ulLnnqC_mqNvxq`@'));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        let line_string_type = schema.field(0).extension_type::<LineStringType>();
+        assert_eq!(
+            line_string_type.metadata().crs(),
+            &Crs::from_authority_code("EPSG:4326".to_string())
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "LINESTRING(-120.2 38.5,-120.95 40.7,-126.453 43.252)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_as_encoded_polyline() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsEncodedPolyline::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsEncodedPolyline(ST_GeomFromText('LINESTRING(-120.2 38.5,-120.95 40.7,-126.453 43.252)')), ST_AsEncodedPolyline(ST_GeomFromText('MULTIPOINT(-120.2 38.5,-120.95 40.7)'), 6);")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "_p~iF~ps|U_ulLnnqC_mqNvxq`@"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "_izlhA~rlgdF_{geC~ywl@"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_as_encoded_polyline_out_of_range() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsEncodedPolyline::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        for (wkt, message) in [
+            ("LINESTRING(1e300 0,0 0)", "out of range"),
+            ("LINESTRING(-9e13 0,9e13 0)", "overflows"),
+        ] {
+            let err = ctx
+                .sql(&format!(
+                    "SELECT ST_AsEncodedPolyline(ST_GeomFromText('{wkt}'));"
+                ))
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains(message),
+                "unexpected error for {wkt}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_polyline_overflow() {
+        // A latitude of i64::MAX followed by a delta of one
+        let mut encoder = PolylineEncoder {
+            factor: 1e5,
+            last: (0, 0),
+            out: String::new(),
+        };
+        for value in [i64::MAX, 0, 1, 0] {
+            encoder.push_value(value);
+        }
+        let err = decode_polyline(&encoder.out, 1e5).unwrap_err();
+        assert!(
+            err.to_string().contains("overflows"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -0,0 +1,562 @@
+use std::fmt::Write;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{BinaryBuilder, StringBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, BinaryArray};
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::cast::{from_wkb, to_wkb};
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType, Metadata};
+
+use crate::crs::{crs_from_srid, srid_arg, srid_from_crs};
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::io::ewkt::check_srid;
+
+const EWKB_Z_FLAG: u32 = 0x80000000;
+const EWKB_M_FLAG: u32 = 0x40000000;
+const EWKB_SRID_FLAG: u32 = 0x20000000;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsEWKB {
+    signature: Signature,
+}
+
+impl AsEWKB {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsEWKB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_EWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsEWKB {
+    fn name(&self) -> &str {
+        "st_asewkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(ColumnarValue::Array(Arc::new(as_ewkb_impl(args)?)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_EWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Extended Well-Known Binary (EWKB) representation of the geometry, including the SRID of the input's CRS if it refers to an EPSG code. The byte order is little-endian ('NDR', the default) or big-endian ('XDR').",
+                "ST_AsEWKB(geometry, 'XDR')",
+            )
+            .with_argument("g1", "geometry")
+            .with_argument("NDR_or_XDR", "text")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsHEXEWKB {
+    signature: Signature,
+}
+
+impl AsHEXEWKB {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsHEXEWKB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_HEXEWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsHEXEWKB {
+    fn name(&self) -> &str {
+        "st_ashexewkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ewkb_arr = as_ewkb_impl(args)?;
+        let mut builder =
+            StringBuilder::with_capacity(ewkb_arr.len(), ewkb_arr.value_data().len() * 2);
+        for ewkb in ewkb_arr.iter() {
+            if let Some(ewkb) = ewkb {
+                let mut hex = String::with_capacity(ewkb.len() * 2);
+                for byte in ewkb {
+                    write!(hex, "{byte:02X}").unwrap();
+                }
+                builder.append_value(hex);
+            } else {
+                builder.append_null();
+            }
+        }
+        Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_HEXEWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a geometry in HEXEWKB format (as text), the hex encoding of its Extended Well-Known Binary representation. The byte order is little-endian ('NDR', the default) or big-endian ('XDR').",
+                "ST_AsHEXEWKB(geometry, 'XDR')",
+            )
+            .with_argument("g1", "geometry")
+            .with_argument("NDR_or_XDR", "text")
+            .build()
+        }))
+    }
+}
+
+fn as_ewkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<BinaryArray> {
+    let big_endian = match args.args.get(1) {
+        None => false,
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.try_as_str().flatten() {
+            Some(encoding) if encoding.eq_ignore_ascii_case("NDR") => false,
+            Some(encoding) if encoding.eq_ignore_ascii_case("XDR") => true,
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid byte order {scalar}, expected 'NDR' or 'XDR'"
+                ))
+                .into());
+            }
+        },
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized byte order not yet implemented".to_string(),
+            )
+            .into());
+        }
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let field = &args.arg_fields[0];
+    let geo_array = from_arrow_array(&array, field.as_ref())?;
+    let srid = srid_from_crs(geo_array.data_type().metadata().crs());
+    let wkb_arr = to_wkb::<i32>(geo_array.as_ref())?;
+    let wkb_arr = wkb_arr.inner();
+
+    let mut builder = BinaryBuilder::with_capacity(wkb_arr.len(), wkb_arr.value_data().len());
+    let mut buf = Vec::new();
+    for wkb in wkb_arr.iter() {
+        if let Some(wkb) = wkb {
+            buf.clear();
+            let mut writer = EwkbWriter {
+                reader: WkbReader { buf: wkb, pos: 0 },
+                out: &mut buf,
+                big_endian,
+            };
+            writer.write_geometry(srid)?;
+            builder.append_value(&buf);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Reads the primitive values of a WKB buffer in the buffer's own byte order.
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    fn read<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| DataFusionError::Execution("Unexpected end of WKB".to_string()))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_byte_order(&mut self) -> Result<bool> {
+        match self.read::<1>()? {
+            [0] => Ok(true),
+            [1] => Ok(false),
+            [b] => Err(DataFusionError::Execution(format!(
+                "Invalid WKB byte order {b}"
+            ))),
+        }
+    }
+
+    fn read_u32(&mut self, big_endian: bool) -> Result<u32> {
+        let bytes = self.read::<4>()?;
+        Ok(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, big_endian: bool) -> Result<f64> {
+        let bytes = self.read::<8>()?;
+        Ok(if big_endian {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        })
+    }
+}
+
+/// Rewrites ISO WKB as EWKB, converting to the requested byte order.
+///
+/// ISO WKB encodes dimensions by adding 1000 (Z), 2000 (M) or 3000 (ZM) to the geometry type,
+/// whereas EWKB sets high bits in the type, plus one more flag if an SRID follows the type.
+struct EwkbWriter<'a, 'b> {
+    reader: WkbReader<'a>,
+    out: &'b mut Vec<u8>,
+    big_endian: bool,
+}
+
+impl EwkbWriter<'_, '_> {
+    fn write_u32(&mut self, value: u32) {
+        if self.big_endian {
+            self.out.extend_from_slice(&value.to_be_bytes());
+        } else {
+            self.out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        if self.big_endian {
+            self.out.extend_from_slice(&value.to_be_bytes());
+        } else {
+            self.out.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    fn copy_u32(&mut self, input_big_endian: bool) -> Result<u32> {
+        let value = self.reader.read_u32(input_big_endian)?;
+        self.write_u32(value);
+        Ok(value)
+    }
+
+    fn copy_coords(&mut self, input_big_endian: bool, num_ordinates: usize) -> Result<()> {
+        let num_coords = self.copy_u32(input_big_endian)?;
+        for _ in 0..num_coords as usize * num_ordinates {
+            let value = self.reader.read_f64(input_big_endian)?;
+            self.write_f64(value);
+        }
+        Ok(())
+    }
+
+    /// Write one geometry. Only the top-level geometry carries the SRID.
+    fn write_geometry(&mut self, srid: Option<i32>) -> Result<()> {
+        let input_big_endian = self.reader.read_byte_order()?;
+        let iso_type = self.reader.read_u32(input_big_endian)?;
+        let (base_type, dim) = (iso_type % 1000, iso_type / 1000);
+        let (has_z, has_m) = match dim {
+            0 => (false, false),
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid WKB geometry type {iso_type}"
+                )));
+            }
+        };
+        let num_ordinates = 2 + has_z as usize + has_m as usize;
+
+        let mut ewkb_type = base_type;
+        if has_z {
+            ewkb_type |= EWKB_Z_FLAG;
+        }
+        if has_m {
+            ewkb_type |= EWKB_M_FLAG;
+        }
+        if srid.is_some() {
+            ewkb_type |= EWKB_SRID_FLAG;
+        }
+        self.out.push(if self.big_endian { 0 } else { 1 });
+        self.write_u32(ewkb_type);
+        if let Some(srid) = srid {
+            self.write_u32(srid as u32);
+        }
+
+        match base_type {
+            // Point
+            1 => {
+                for _ in 0..num_ordinates {
+                    let value = self.reader.read_f64(input_big_endian)?;
+                    self.write_f64(value);
+                }
+            }
+            // LineString
+            2 => self.copy_coords(input_big_endian, num_ordinates)?,
+            // Polygon
+            3 => {
+                let num_rings = self.copy_u32(input_big_endian)?;
+                for _ in 0..num_rings {
+                    self.copy_coords(input_big_endian, num_ordinates)?;
+                }
+            }
+            // MultiPoint, MultiLineString, MultiPolygon, GeometryCollection
+            4..=7 => {
+                let num_geometries = self.copy_u32(input_big_endian)?;
+                for _ in 0..num_geometries {
+                    self.write_geometry(None)?;
+                }
+            }
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Unsupported WKB geometry type {iso_type}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read the SRID from the header of an EWKB buffer, if it has one.
+fn read_ewkb_srid(ewkb: &[u8]) -> Result<Option<i32>> {
+    let mut reader = WkbReader { buf: ewkb, pos: 0 };
+    let big_endian = reader.read_byte_order()?;
+    let geometry_type = reader.read_u32(big_endian)?;
+    if geometry_type & EWKB_SRID_FLAG != 0 {
+        Ok(Some(reader.read_u32(big_endian)? as i32))
+    } else {
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromEWKB {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromEWKB {
+    pub fn new(coord_type: CoordType) -> Self {
+        let mut variants = vec![];
+        for binary_type in [
+            DataType::Binary,
+            DataType::LargeBinary,
+            DataType::BinaryView,
+        ] {
+            variants.push(TypeSignature::Exact(vec![binary_type.clone()]));
+            variants.push(TypeSignature::Exact(vec![binary_type, DataType::Int64]));
+        }
+        Self {
+            signature: Signature::one_of(variants, Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromEWKB {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_EWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromEWKB {
+    fn name(&self) -> &str {
+        "st_geomfromewkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let mut metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+
+        // The SRID of a literal is known when planning, but that of a column must be passed in
+        if let Some(srid) = srid_arg("ST_GeomFromEWKB", &args, 1)? {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        } else if let Some(Some(
+            ScalarValue::Binary(Some(ewkb))
+            | ScalarValue::LargeBinary(Some(ewkb))
+            | ScalarValue::BinaryView(Some(ewkb)),
+        )) = args.scalar_arguments.first()
+            && let Some(srid) = read_ewkb_srid(ewkb)?
+        {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        }
+
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_ewkb_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_EWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a geometry object from the Extended Well-Known Binary (EWKB) representation. The CRS of the output is the srid argument if given, or else the SRID of a literal input. As GeoArrow stores a single CRS per column, which is fixed when planning, the CRS of a column input is otherwise taken from its field metadata. All SRIDs in the column must match the CRS of the output, and a column with SRIDs but no CRS is an error.",
+                "ST_GeomFromEWKB(EWKB) or ST_GeomFromEWKB(EWKB, 4326)",
+            )
+            .with_argument("EWKB", "bytea")
+            .with_argument("srid", "integer SRID value")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_ewkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Binary, None)?
+        .into_array(args.number_rows)?;
+    let array = array.as_binary::<i32>();
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+    let crs = to_type.metadata().crs();
+
+    for ewkb in array.iter().flatten() {
+        check_srid("ST_GeomFromEWKB", read_ewkb_srid(ewkb)?, crs)?;
+    }
+
+    let wkb_arr = WkbArray::new(array.clone(), Default::default());
+    let geom_arr = from_wkb(&wkb_arr, to_type)?;
+    Ok(ColumnarValue::Array(geom_arr.to_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_schema::Crs;
+
+    use super::*;
+    use crate::udf::native::io::{AsEWKT, AsText, GeomFromEWKT};
+
+    #[tokio::test]
+    async fn test_hexewkb() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromEWKT::default().into());
+        ctx.register_udf(AsHEXEWKB::new().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsHEXEWKB(ST_GeomFromEWKT('SRID=4326;POINT(1 2)')), ST_AsHEXEWKB(ST_GeomFromEWKT('SRID=4326;POINT(1 2)'), 'XDR');")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "0101000020E6100000000000000000F03F0000000000000040"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "0020000001000010E63FF00000000000004000000000000000"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ewkb_round_trip() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromEWKT::default().into());
+        ctx.register_udf(GeomFromEWKB::default().into());
+        ctx.register_udf(AsEWKB::new().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql("SELECT ST_GeomFromEWKB(ST_AsEWKB(ST_GeomFromEWKT('SRID=3857;MULTILINESTRING Z((1 2 3,4 5 6))'))) AS geom;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        let geom_type = schema.field(0).extension_type::<GeometryType>();
+        assert_eq!(
+            geom_type.metadata().crs(),
+            &Crs::from_authority_code("EPSG:3857".to_string())
+        );
+
+        let df = ctx
+            .sql("SELECT ST_AsText(ST_GeomFromEWKB(X'0101000020E6100000000000000000F03F0000000000000040'));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POINT(1 2)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ewkb_column_srid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromEWKB::default().into());
+        ctx.register_udf(AsEWKT::new().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsEWKT(ST_GeomFromEWKB(b, 4326)) FROM (VALUES
+                    (X'0101000020E6100000000000000000F03F0000000000000040')
+                ) AS t(b);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "SRID=4326;POINT(1 2)"
+        );
+
+        let result = ctx
+            .sql("SELECT ST_GeomFromEWKB(b) FROM (VALUES (X'0101000020E6100000000000000000F03F0000000000000040')) AS t(b);")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        let err = result.unwrap_err();
+        assert!(
+            err.to_string().contains("without a CRS"),
+            "unexpected error: {err}"
+        );
+    }
+}
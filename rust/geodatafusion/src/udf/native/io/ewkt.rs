@@ -0,0 +1,309 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, StringArray};
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::{WktArray, from_arrow_array};
+use geoarrow_array::cast::{from_wkt, to_wkt};
+use geoarrow_schema::{CoordType, Crs, GeoArrowType, GeometryType, Metadata};
+
+use crate::crs::{crs_from_srid, srid_arg, srid_from_crs};
+use crate::data_types::any_single_geometry_type_input;
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsEWKT;
+
+impl AsEWKT {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for AsEWKT {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_EWKT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsEWKT {
+    fn name(&self) -> &str {
+        "st_asewkt"
+    }
+
+    fn signature(&self) -> &Signature {
+        any_single_geometry_type_input()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_ewkt_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_EWKT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Extended Well-Known Text (EWKT) representation of the geometry, prefixed with the SRID of the input's CRS if it refers to an EPSG code.",
+                "ST_AsEWKT(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn as_ewkt_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+    let field = &args.arg_fields[0];
+    let geo_array = from_arrow_array(&array, field.as_ref())?;
+    let wkt_arr = to_wkt::<i32>(geo_array.as_ref())?;
+    let wkt_arr = wkt_arr.inner();
+
+    let Some(srid) = srid_from_crs(geo_array.data_type().metadata().crs()) else {
+        return Ok(ColumnarValue::Array(Arc::new(wkt_arr.clone())));
+    };
+
+    let mut builder = StringBuilder::with_capacity(wkt_arr.len(), wkt_arr.value_data().len());
+    for wkt in wkt_arr.iter() {
+        builder.append_option(wkt.map(|wkt| format!("SRID={srid};{wkt}")));
+    }
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromEWKT {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromEWKT {
+    pub fn new(coord_type: CoordType) -> Self {
+        let mut variants = vec![];
+        for text_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            variants.push(TypeSignature::Exact(vec![text_type.clone()]));
+            variants.push(TypeSignature::Exact(vec![text_type, DataType::Int64]));
+        }
+        Self {
+            signature: Signature::one_of(variants, Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromEWKT {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_EWKT_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromEWKT {
+    fn name(&self) -> &str {
+        "st_geomfromewkt"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let mut metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+
+        // The SRID of a literal is known when planning, but that of a column must be passed in
+        if let Some(srid) = srid_arg("ST_GeomFromEWKT", &args, 1)? {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        } else if let Some(Some(scalar)) = args.scalar_arguments.first()
+            && let Some(Some(ewkt)) = scalar.try_as_str()
+            && let (Some(srid), _) = split_srid(ewkt)?
+        {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        }
+
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_ewkt_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_EWKT_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a geometry object from the Extended Well-Known Text (EWKT) representation. The CRS of the output is the srid argument if given, or else the SRID of a literal input. As GeoArrow stores a single CRS per column, which is fixed when planning, the CRS of a column input is otherwise taken from its field metadata. All SRIDs in the column must match the CRS of the output, and a column with SRIDs but no CRS is an error.",
+                "ST_GeomFromEWKT(EWKT) or ST_GeomFromEWKT(EWKT, 4326)",
+            )
+            .with_argument("EWKT", "text")
+            .with_argument("srid", "integer SRID value")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_ewkt_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+    let crs = to_type.metadata().crs();
+
+    let wkt_arr = array
+        .as_string::<i32>()
+        .iter()
+        .map(|ewkt| {
+            ewkt.map(|ewkt| {
+                let (srid, wkt) = split_srid(ewkt)?;
+                check_srid("ST_GeomFromEWKT", srid, crs)?;
+                Ok(wkt)
+            })
+            .transpose()
+        })
+        .collect::<Result<StringArray>>()?;
+
+    let wkt_arr = WktArray::new(wkt_arr, Default::default());
+    let geom_arr = from_wkt(&wkt_arr, to_type)?;
+    Ok(ColumnarValue::Array(geom_arr.to_array_ref()))
+}
+
+/// Split an EWKT string into its optional `SRID=xxxx;` prefix and the WKT remainder.
+fn split_srid(ewkt: &str) -> Result<(Option<i32>, &str)> {
+    let trimmed = ewkt.trim_start();
+    let has_prefix = trimmed
+        .get(..5)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("SRID="));
+    if !has_prefix {
+        return Ok((None, ewkt));
+    }
+
+    let (srid, wkt) = trimmed[5..].split_once(';').ok_or_else(|| {
+        DataFusionError::Execution(format!("Invalid EWKT, missing ';' after SRID: {ewkt}"))
+    })?;
+    let srid = srid
+        .trim()
+        .parse()
+        .map_err(|_| DataFusionError::Execution(format!("Invalid SRID in EWKT: {srid}")))?;
+    Ok((Some(srid), wkt))
+}
+
+/// Validate that the SRID of a row is consistent with the CRS of the output column.
+///
+/// The CRS of the output is fixed when planning, so a row SRID cannot be kept if the column has
+/// no CRS. SRID 0 is an unknown spatial reference and always passes.
+pub(super) fn check_srid(function: &str, srid: Option<i32>, crs: &Crs) -> Result<()> {
+    let Some(srid) = srid.filter(|srid| *srid != 0) else {
+        return Ok(());
+    };
+    if crs.crs_value().is_none() {
+        return Err(DataFusionError::Execution(format!(
+            "Found SRID {srid} in a column without a CRS. Pass the SRID as the second argument of {function} to keep it"
+        )));
+    }
+    match srid_from_crs(crs) {
+        Some(expected) if srid != expected => Err(DataFusionError::Execution(format!(
+            "Mixed SRIDs: found {srid} in a column with SRID {expected}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+    use geoarrow_schema::Crs;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ewkt_round_trip() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromEWKT::default().into());
+        ctx.register_udf(AsEWKT::new().into());
+
+        let df = ctx
+            .sql("SELECT ST_GeomFromEWKT('SRID=4326;POINT(30 10)') AS geom;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        let geom_type = schema.field(0).extension_type::<GeometryType>();
+        assert_eq!(
+            geom_type.metadata().crs(),
+            &Crs::from_authority_code("EPSG:4326".to_string())
+        );
+
+        let df = ctx
+            .sql("SELECT ST_AsEWKT(ST_GeomFromEWKT('SRID=3857;POINT(30 10)'));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "SRID=3857;POINT(30 10)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ewkt_column_srid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromEWKT::default().into());
+        ctx.register_udf(AsEWKT::new().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsEWKT(ST_GeomFromEWKT(w, 4326)) FROM (VALUES
+                    ('SRID=4326;POINT(1 2)'),
+                    ('POINT(3 4)')
+                ) AS t(w);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let ewkt = batches[0].column(0).as_string::<i32>();
+        assert_eq!(ewkt.value(0), "SRID=4326;POINT(1 2)");
+        assert_eq!(ewkt.value(1), "SRID=4326;POINT(3 4)");
+
+        // Without a CRS, the SRIDs of a column would be lost
+        for sql in [
+            "SELECT ST_GeomFromEWKT(w) FROM (VALUES ('SRID=4326;POINT(1 2)')) AS t(w);",
+            "SELECT ST_GeomFromEWKT(w, 3857) FROM (VALUES ('SRID=4326;POINT(1 2)')) AS t(w);",
+        ] {
+            let err = ctx.sql(sql).await.unwrap().collect().await.unwrap_err();
+            assert!(err.to_string().contains("SRID"), "unexpected error: {err}");
+        }
+
+        // SRID 0 is an unknown spatial reference
+        let df = ctx
+            .sql("SELECT ST_AsEWKT(ST_GeomFromEWKT('SRID=0;POINT(1 2)')) AS geom;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POINT(1 2)"
+        );
+    }
+}
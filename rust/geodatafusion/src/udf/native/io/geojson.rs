@@ -0,0 +1,698 @@
+use std::fmt::Write;
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::{RecordBatch, StructArray};
+use arrow_schema::{DataType, Field, Schema};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Crs, GeometryType, Metadata};
+
+use crate::data_types::any_geometry_type;
+use crate::error::GeoDataFusionResult;
+
+/// The default number of decimal digits written by `ST_AsGeoJSON`, matching PostGIS.
+const DEFAULT_MAX_DECIMAL_DIGITS: usize = 9;
+
+/// A single geometry argument, optionally followed by `maxdecimaldigits` and a properties struct.
+///
+/// The struct type cannot be enumerated, so the geometry type of the three-argument form is
+/// checked in `return_type` instead.
+static AS_GEOJSON_SIGNATURE: LazyLock<Signature> = LazyLock::new(|| {
+    let geometry_types = any_geometry_type();
+    let mut variants = Vec::with_capacity(geometry_types.len() + 2);
+    variants.push(TypeSignature::Uniform(1, geometry_types.clone()));
+    for geometry_type in geometry_types {
+        variants.push(TypeSignature::Exact(vec![geometry_type, DataType::Int64]));
+    }
+    variants.push(TypeSignature::Any(3));
+    Signature::one_of(variants, Volatility::Immutable)
+});
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsGeoJSON;
+
+impl AsGeoJSON {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for AsGeoJSON {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_GEOJSON_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsGeoJSON {
+    fn name(&self) -> &str {
+        "st_asgeojson"
+    }
+
+    fn signature(&self) -> &Signature {
+        &AS_GEOJSON_SIGNATURE
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if !any_geometry_type().contains(&arg_types[0]) {
+            return Err(DataFusionError::Plan(format!(
+                "ST_AsGeoJSON requires a geometry argument, got {}",
+                arg_types[0]
+            )));
+        }
+        if let Some(properties_type) = arg_types.get(2)
+            && !matches!(properties_type, DataType::Struct(_))
+        {
+            return Err(DataFusionError::Plan(
+                "ST_AsGeoJSON properties must be a struct".to_string(),
+            ));
+        }
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_geojson_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_GEOJSON_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a geometry as a GeoJSON geometry object. Coordinates are written with at most maxdecimaldigits decimal places (default 9), with trailing zeros removed. If a struct of properties is passed, a GeoJSON Feature object is returned instead, with the struct fields as its properties.",
+                "ST_AsGeoJSON(geometry, maxdecimaldigits, properties)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("maxdecimaldigits", "integer")
+            .with_argument("properties", "struct")
+            .build()
+        }))
+    }
+}
+
+fn as_geojson_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let max_decimal_digits = match args.args.get(1) {
+        Some(ColumnarValue::Scalar(scalar)) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(digits)) => usize::try_from(digits).map_err(|_| {
+                DataFusionError::Execution(
+                    "ST_AsGeoJSON maxdecimaldigits must not be negative".to_string(),
+                )
+            })?,
+            ScalarValue::Int64(None) => DEFAULT_MAX_DECIMAL_DIGITS,
+            _ => unreachable!(),
+        },
+        Some(ColumnarValue::Array(_)) => {
+            return Err(DataFusionError::NotImplemented(
+                "Vectorized maxdecimaldigits not yet implemented".to_string(),
+            )
+            .into());
+        }
+        None => DEFAULT_MAX_DECIMAL_DIGITS,
+    };
+
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let geo_array = from_arrow_array(&arrays[0], &args.arg_fields[0])?;
+    let geometries = to_geojson(&geo_array, max_decimal_digits)?;
+
+    let result = if let Some(properties) = arrays.get(2) {
+        let properties = properties_to_json(properties.as_struct())?;
+        let mut builder = StringBuilder::with_capacity(geometries.len(), 0);
+        for (geometry, properties) in geometries.iter().zip(properties) {
+            builder.append_value(format!(
+                r#"{{"type":"Feature","geometry":{},"properties":{}}}"#,
+                geometry.as_deref().unwrap_or("null"),
+                properties.as_deref().unwrap_or("null")
+            ));
+        }
+        builder.finish()
+    } else {
+        geometries.into_iter().collect()
+    };
+
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+/// Serialize each non-null row of a struct array to a JSON object.
+fn properties_to_json(properties: &StructArray) -> GeoDataFusionResult<Vec<Option<String>>> {
+    let (fields, columns, nulls) = properties.clone().into_parts();
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+
+    let mut writer = arrow_json::WriterBuilder::new()
+        .with_explicit_nulls(true)
+        .build::<_, arrow_json::writer::LineDelimited>(Vec::new());
+    writer.write(&batch)?;
+    writer.finish()?;
+    let buf = writer.into_inner();
+
+    // JSON strings cannot contain unescaped newlines, so each line is one row
+    let lines = std::str::from_utf8(&buf)
+        .map_err(|err| DataFusionError::Execution(err.to_string()))?
+        .lines();
+    Ok(lines
+        .enumerate()
+        .map(|(i, line)| {
+            if nulls.as_ref().is_some_and(|nulls| nulls.is_null(i)) {
+                None
+            } else {
+                Some(line.to_string())
+            }
+        })
+        .collect())
+}
+
+fn to_geojson(
+    array: &dyn GeoArrowArray,
+    max_decimal_digits: usize,
+) -> GeoArrowResult<Vec<Option<String>>> {
+    downcast_geoarrow_array!(array, _to_geojson_impl, max_decimal_digits)
+}
+
+fn _to_geojson_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    max_decimal_digits: usize,
+) -> GeoArrowResult<Vec<Option<String>>> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let mut out = String::new();
+                write_geometry(&geom?, max_decimal_digits, &mut out);
+                Ok(out)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+fn write_geometry(geom: &impl GeometryTrait<T = f64>, precision: usize, out: &mut String) {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(g) => {
+            out.push_str(r#"{"type":"Point","coordinates":"#);
+            match g.coord() {
+                Some(coord) => write_coord(&coord, precision, out),
+                None => out.push_str("[]"),
+            }
+        }
+        LineString(g) => {
+            out.push_str(r#"{"type":"LineString","coordinates":"#);
+            write_coords(g.coords(), precision, out);
+        }
+        Polygon(g) => {
+            out.push_str(r#"{"type":"Polygon","coordinates":"#);
+            write_polygon_rings(g, precision, out);
+        }
+        MultiPoint(g) => {
+            out.push_str(r#"{"type":"MultiPoint","coordinates":["#);
+            for (i, point) in g.points().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                match point.coord() {
+                    Some(coord) => write_coord(&coord, precision, out),
+                    None => out.push_str("[]"),
+                }
+            }
+            out.push(']');
+        }
+        MultiLineString(g) => {
+            out.push_str(r#"{"type":"MultiLineString","coordinates":["#);
+            for (i, line_string) in g.line_strings().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_coords(line_string.coords(), precision, out);
+            }
+            out.push(']');
+        }
+        MultiPolygon(g) => {
+            out.push_str(r#"{"type":"MultiPolygon","coordinates":["#);
+            for (i, polygon) in g.polygons().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_polygon_rings(&polygon, precision, out);
+            }
+            out.push(']');
+        }
+        GeometryCollection(g) => {
+            out.push_str(r#"{"type":"GeometryCollection","geometries":["#);
+            for (i, geometry) in g.geometries().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_geometry(&geometry, precision, out);
+            }
+            out.push(']');
+        }
+        Rect(g) => {
+            // GeoJSON has no box type, so write the equivalent polygon
+            let (min, max) = (g.min(), g.max());
+            let (minx, miny, maxx, maxy) = (min.x(), min.y(), max.x(), max.y());
+            out.push_str(r#"{"type":"Polygon","coordinates":[["#);
+            for (i, (x, y)) in [
+                (minx, miny),
+                (maxx, miny),
+                (maxx, maxy),
+                (minx, maxy),
+                (minx, miny),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                if i > 0 {
+                    out.push(',');
+                }
+                write!(
+                    out,
+                    "[{},{}]",
+                    format_ordinate(x, precision),
+                    format_ordinate(y, precision)
+                )
+                .unwrap();
+            }
+            out.push_str("]]");
+        }
+        Triangle(g) => {
+            out.push_str(r#"{"type":"Polygon","coordinates":[["#);
+            for (i, coord) in [g.first(), g.second(), g.third(), g.first()]
+                .iter()
+                .enumerate()
+            {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_coord(coord, precision, out);
+            }
+            out.push_str("]]");
+        }
+        Line(g) => {
+            out.push_str(r#"{"type":"LineString","coordinates":"#);
+            write_coords([g.start(), g.end()].into_iter(), precision, out);
+        }
+    }
+    out.push('}');
+}
+
+fn write_polygon_rings(polygon: &impl PolygonTrait<T = f64>, precision: usize, out: &mut String) {
+    out.push('[');
+    if let Some(exterior) = polygon.exterior() {
+        write_coords(exterior.coords(), precision, out);
+        for interior in polygon.interiors() {
+            out.push(',');
+            write_coords(interior.coords(), precision, out);
+        }
+    }
+    out.push(']');
+}
+
+fn write_coords(
+    coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+    precision: usize,
+    out: &mut String,
+) {
+    out.push('[');
+    for (i, coord) in coords.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_coord(&coord, precision, out);
+    }
+    out.push(']');
+}
+
+/// Write a GeoJSON position. M values are dropped, as GeoJSON only supports an optional Z.
+fn write_coord(coord: &impl CoordTrait<T = f64>, precision: usize, out: &mut String) {
+    write!(
+        out,
+        "[{},{}",
+        format_ordinate(coord.x(), precision),
+        format_ordinate(coord.y(), precision)
+    )
+    .unwrap();
+    if matches!(coord.dim(), Dimensions::Xyz | Dimensions::Xyzm) {
+        write!(
+            out,
+            ",{}",
+            format_ordinate(coord.nth_or_panic(2), precision)
+        )
+        .unwrap();
+    }
+    out.push(']');
+}
+
+/// Format a number with at most `precision` decimal places, removing trailing zeros.
+pub(super) fn format_ordinate(value: f64, precision: usize) -> String {
+    let mut s = format!("{value:.precision$}");
+    if s.contains('.') {
+        let trimmed_len = s.trim_end_matches('0').trim_end_matches('.').len();
+        s.truncate(trimmed_len);
+    }
+    if s == "-0" {
+        s.remove(0);
+    }
+    s
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromGeoJSON {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromGeoJSON {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromGeoJSON {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_GEOJSON_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromGeoJSON {
+    fn name(&self) -> &str {
+        "st_geomfromgeojson"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        // GeoJSON coordinates are always WGS84 longitude and latitude
+        let metadata = Arc::new(Metadata::new(
+            Crs::from_authority_code("EPSG:4326".to_string()),
+            None,
+        ));
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_geojson_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_GEOJSON_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Takes as input a GeoJSON representation of a geometry and outputs a geometry object. The geometry of a GeoJSON Feature is also accepted. Z coordinates are preserved, and the output CRS is EPSG:4326.",
+                "ST_GeomFromGeoJSON(geomjson)",
+            )
+            .with_argument("geomjson", "text")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_geojson_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+    let typ = args.return_field.try_extension_type::<GeometryType>()?;
+    let mut builder = GeometryBuilder::new(typ);
+
+    for item in array.as_string::<i32>() {
+        if let Some(text) = item {
+            let geojson = text
+                .parse::<geojson::GeoJson>()
+                .map_err(|err| DataFusionError::External(Box::new(err)))?;
+            let geometry = match geojson {
+                geojson::GeoJson::Geometry(geometry) => Some(geometry),
+                geojson::GeoJson::Feature(feature) => feature.geometry,
+                geojson::GeoJson::FeatureCollection(_) => {
+                    return Err(DataFusionError::Execution(
+                        "ST_GeomFromGeoJSON does not support FeatureCollection input".to_string(),
+                    )
+                    .into());
+                }
+            };
+            match geometry {
+                Some(geometry) => {
+                    let dim = dimension(&geometry.value)?.unwrap_or(wkt::types::Dimension::XY);
+                    builder.push_geometry(Some(&geojson_to_wkt(&geometry.value, dim)))?
+                }
+                None => builder.push_null(),
+            }
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+/// Convert a GeoJSON geometry to [`wkt::Wkt`], which implements the geo-traits and, unlike
+/// geo-types, retains Z values.
+///
+/// The geometry is written with the dimension `dim`, from [`dimension`].
+fn geojson_to_wkt(value: &geojson::Value, dim: wkt::types::Dimension) -> wkt::Wkt<f64> {
+    use geojson::Value;
+
+    let coord = |position: &geojson::Position| wkt::types::Coord {
+        x: position[0],
+        y: position[1],
+        z: if dim == wkt::types::Dimension::XYZ {
+            Some(position[2])
+        } else {
+            None
+        },
+        m: None,
+    };
+    let line_string = |positions: &Vec<geojson::Position>| {
+        wkt::types::LineString::new(positions.iter().map(coord).collect(), dim)
+    };
+    let polygon = |rings: &Vec<Vec<geojson::Position>>| {
+        wkt::types::Polygon::new(rings.iter().map(line_string).collect(), dim)
+    };
+
+    match value {
+        Value::Point(position) => {
+            let point = if position.is_empty() {
+                wkt::types::Point::empty(dim)
+            } else {
+                wkt::types::Point::new(Some(coord(position)), dim)
+            };
+            wkt::Wkt::Point(point)
+        }
+        Value::MultiPoint(positions) => wkt::Wkt::MultiPoint(wkt::types::MultiPoint::new(
+            positions
+                .iter()
+                .map(|position| wkt::types::Point::new(Some(coord(position)), dim))
+                .collect(),
+            dim,
+        )),
+        Value::LineString(positions) => wkt::Wkt::LineString(line_string(positions)),
+        Value::MultiLineString(lines) => wkt::Wkt::MultiLineString(
+            wkt::types::MultiLineString::new(lines.iter().map(line_string).collect(), dim),
+        ),
+        Value::Polygon(rings) => wkt::Wkt::Polygon(polygon(rings)),
+        Value::MultiPolygon(polygons) => wkt::Wkt::MultiPolygon(wkt::types::MultiPolygon::new(
+            polygons.iter().map(polygon).collect(),
+            dim,
+        )),
+        Value::GeometryCollection(geometries) => {
+            wkt::Wkt::GeometryCollection(wkt::types::GeometryCollection::new(
+                geometries
+                    .iter()
+                    .map(|geometry| geojson_to_wkt(&geometry.value, dim))
+                    .collect(),
+                dim,
+            ))
+        }
+    }
+}
+
+/// The dimension of a GeoJSON geometry, or `None` if it has no positions.
+///
+/// A geometry has a Z dimension if all of its positions have at least three values. The members
+/// of a collection must share a dimension, which empty members take on.
+fn dimension(value: &geojson::Value) -> GeoDataFusionResult<Option<wkt::types::Dimension>> {
+    use geojson::Value;
+
+    fn positions_dimension<'a>(
+        mut positions: impl Iterator<Item = &'a geojson::Position>,
+    ) -> Option<wkt::types::Dimension> {
+        let first = positions.next()?;
+        if first.len() >= 3 && positions.all(|position| position.len() >= 3) {
+            Some(wkt::types::Dimension::XYZ)
+        } else {
+            Some(wkt::types::Dimension::XY)
+        }
+    }
+
+    Ok(match value {
+        Value::Point(position) if position.is_empty() => None,
+        Value::Point(position) => positions_dimension(std::iter::once(position)),
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            positions_dimension(positions.iter())
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            positions_dimension(lines.iter().flatten())
+        }
+        Value::MultiPolygon(polygons) => positions_dimension(polygons.iter().flatten().flatten()),
+        Value::GeometryCollection(geometries) => {
+            let mut dim = None;
+            for geometry in geometries {
+                match (dim, dimension(&geometry.value)?) {
+                    (_, None) => {}
+                    (None, member_dim) => dim = member_dim,
+                    (Some(dim), Some(member_dim)) if dim != member_dim => {
+                        return Err(DataFusionError::Execution(
+                            "ST_GeomFromGeoJSON does not support GeometryCollection members with and without Z coordinates".to_string(),
+                        )
+                        .into());
+                    }
+                    _ => {}
+                }
+            }
+            dim
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_as_geojson() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGeoJSON::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsGeoJSON(ST_GeomFromText('POLYGON((0 0, 1.123456 0, 1 1, 0 0))'), 3);")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            r#"{"type":"Polygon","coordinates":[[[0,0],[1.123,0],[1,1],[0,0]]]}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_as_geojson_rejects_non_geometry() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGeoJSON::new().into());
+
+        for sql in [
+            "SELECT ST_AsGeoJSON(named_struct('a', 1));",
+            "SELECT ST_AsGeoJSON(named_struct('a', 1), 3, named_struct('a', 1));",
+        ] {
+            let err = match ctx.sql(sql).await {
+                Ok(df) => df.collect().await.unwrap_err(),
+                Err(err) => err,
+            };
+            assert!(
+                matches!(err.find_root(), DataFusionError::Plan(_)),
+                "unexpected error for {sql}: {err}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_as_geojson_feature() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGeoJSON::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsGeoJSON(ST_GeomFromText('POINT(1 2)'), 9, named_struct('name', 'a', 'value', 1));",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            r#"{"type":"Feature","geometry":{"type":"Point","coordinates":[1,2]},"properties":{"name":"a","value":1}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_geojson() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGeoJSON::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                r#"SELECT ST_AsText(ST_GeomFromGeoJSON('{"type":"LineString","coordinates":[[1,2,3],[4,5,6]]}'));"#,
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "LINESTRING Z(1 2 3,4 5 6)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_geojson_collection_dimension() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGeoJSON::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                r#"SELECT ST_AsText(ST_GeomFromGeoJSON('{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1,2,3]},{"type":"LineString","coordinates":[]}]}'));"#,
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z EMPTY)"
+        );
+
+        let err = ctx
+            .sql(
+                r#"SELECT ST_GeomFromGeoJSON('{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1,2,3]},{"type":"Point","coordinates":[1,2]}]}');"#,
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("with and without Z"), "{err}");
+    }
+}
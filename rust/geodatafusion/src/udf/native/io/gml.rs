@@ -0,0 +1,1038 @@
+use std::fmt::Write;
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, Crs, GeometryType, Metadata};
+use quick_xml::escape::escape;
+use wkt::types::Dimension as WktDimension;
+
+use super::ewkt::check_srid;
+use super::geojson::format_ordinate;
+use super::util::to_simple_geometry;
+use super::xml::{XmlElement, namespace_prefix};
+use crate::crs::{crs_from_srid, srid_arg, srid_from_crs};
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::io::util::scalar_arg;
+
+/// The default number of decimal digits written by `ST_AsGML` and `ST_AsKML`, matching PostGIS.
+pub(super) const DEFAULT_MAX_DECIMAL_DIGITS: usize = 15;
+
+/// Write the srsName as a URN, e.g. `urn:ogc:def:crs:EPSG::4326`.
+const GML_LONG_CRS: i64 = 1;
+/// Omit the srsDimension attribute from GML 3 coordinates.
+const GML_NO_SRS_DIMENSION: i64 = 2;
+/// Write GML 3 linestrings as `<LineString>` rather than `<Curve>`.
+const GML_SHORT_LINE: i64 = 4;
+/// Write GML 3 coordinates in latitude/longitude order.
+const GML_LAT_LON: i64 = 16;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsGML {
+    signature: Signature,
+}
+
+impl AsGML {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=5).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsGML {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_GML_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsGML {
+    fn name(&self) -> &str {
+        "st_asgml"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_gml_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_GML_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry as a Geography Markup Language (GML) element. The version may be 2 (the default) or 3. Coordinates are written with at most maxdecimaldigits decimal places (default 15). The srsName of the element is taken from the EPSG code of the input's CRS. The options bitfield accepts 1 to write the srsName as a URN (urn:ogc:def:crs:EPSG::4326), 2 to omit the srsDimension attribute in GML 3, 4 to write GML 3 linestrings as LineString rather than Curve elements and 16 to write GML 3 coordinates in latitude/longitude order. Elements are qualified with the nprefix namespace prefix, 'gml' by default, or unqualified if it is empty.",
+                "ST_AsGML(version, geometry, maxdecimaldigits, options, nprefix)",
+            )
+            .with_argument("version", "integer, 2 or 3, default 2")
+            .with_argument("geom", "geometry")
+            .with_argument("maxdecimaldigits", "integer, default 15")
+            .with_argument("options", "integer, default 0")
+            .with_argument("nprefix", "text, default 'gml'")
+            .build()
+        }))
+    }
+}
+
+fn as_gml_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    // The version is an optional leading argument, as in PostGIS
+    let offset = usize::from(args.arg_fields[0].data_type().is_integer());
+    let version = if offset == 1 {
+        match scalar_arg("ST_AsGML", &args, 0, &DataType::Int64)? {
+            Some(ScalarValue::Int64(Some(version @ (2 | 3)))) => version as u8,
+            _ => {
+                return Err(DataFusionError::Execution(
+                    "ST_AsGML version must be 2 or 3".to_string(),
+                )
+                .into());
+            }
+        }
+    } else {
+        2
+    };
+    if args.args.len() <= offset {
+        return Err(
+            DataFusionError::Plan("ST_AsGML requires a geometry argument".to_string()).into(),
+        );
+    }
+
+    let precision = match scalar_arg("ST_AsGML", &args, offset + 1, &DataType::Int64)? {
+        Some(ScalarValue::Int64(Some(digits))) => usize::try_from(digits).map_err(|_| {
+            DataFusionError::Execution("ST_AsGML maxdecimaldigits must not be negative".to_string())
+        })?,
+        _ => DEFAULT_MAX_DECIMAL_DIGITS,
+    };
+    let options = match scalar_arg("ST_AsGML", &args, offset + 2, &DataType::Int64)? {
+        Some(ScalarValue::Int64(Some(options))) => options,
+        _ => 0,
+    };
+    let prefix = match scalar_arg("ST_AsGML", &args, offset + 3, &DataType::Utf8)? {
+        Some(ScalarValue::Utf8(Some(prefix))) => namespace_prefix("ST_AsGML", &prefix)?,
+        _ => "gml:".to_string(),
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[offset..=offset])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[offset])?;
+    let srs_name = srid_from_crs(geo_array.data_type().metadata().crs()).map(|srid| {
+        if options & GML_LONG_CRS != 0 {
+            format!("urn:ogc:def:crs:EPSG::{srid}")
+        } else {
+            format!("EPSG:{srid}")
+        }
+    });
+
+    let writer = GmlWriter {
+        version,
+        precision,
+        prefix,
+        srs_dimension: version == 3 && options & GML_NO_SRS_DIMENSION == 0,
+        curve: version == 3 && options & GML_SHORT_LINE == 0,
+        lat_lon: version == 3 && options & GML_LAT_LON != 0,
+    };
+    let result = to_gml(&geo_array, &writer, srs_name.as_deref())?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn to_gml(
+    array: &dyn GeoArrowArray,
+    writer: &GmlWriter,
+    srs_name: Option<&str>,
+) -> GeoArrowResult<StringArray> {
+    downcast_geoarrow_array!(array, _to_gml_impl, writer, srs_name)
+}
+
+fn _to_gml_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    writer: &GmlWriter,
+    srs_name: Option<&str>,
+) -> GeoArrowResult<StringArray> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let mut out = String::new();
+                writer.write_geometry(&geom?, srs_name, &mut out)?;
+                Ok(out)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+struct GmlWriter {
+    version: u8,
+    precision: usize,
+    prefix: String,
+    srs_dimension: bool,
+    curve: bool,
+    lat_lon: bool,
+}
+
+impl GmlWriter {
+    fn write_geometry(
+        &self,
+        geom: &impl GeometryTrait<T = f64>,
+        srs_name: Option<&str>,
+        out: &mut String,
+    ) -> GeoArrowResult<()> {
+        use geo_traits::GeometryType::*;
+
+        let v3 = self.version == 3;
+        match geom.as_type() {
+            Point(g) => self.write_point(g, srs_name, out),
+            LineString(g) => self.write_line_string(g, srs_name, out),
+            Polygon(g) => self.write_polygon(g, srs_name, out),
+            MultiPoint(g) => {
+                self.start("MultiPoint", srs_name, out);
+                for point in g.points() {
+                    self.start("pointMember", None, out);
+                    self.write_point(&point, None, out);
+                    self.end("pointMember", out);
+                }
+                self.end("MultiPoint", out);
+            }
+            MultiLineString(g) => {
+                let (name, member) = if v3 {
+                    ("MultiCurve", "curveMember")
+                } else {
+                    ("MultiLineString", "lineStringMember")
+                };
+                self.start(name, srs_name, out);
+                for line_string in g.line_strings() {
+                    self.start(member, None, out);
+                    self.write_line_string(&line_string, None, out);
+                    self.end(member, out);
+                }
+                self.end(name, out);
+            }
+            MultiPolygon(g) => {
+                let (name, member) = if v3 {
+                    ("MultiSurface", "surfaceMember")
+                } else {
+                    ("MultiPolygon", "polygonMember")
+                };
+                self.start(name, srs_name, out);
+                for polygon in g.polygons() {
+                    self.start(member, None, out);
+                    self.write_polygon(&polygon, None, out);
+                    self.end(member, out);
+                }
+                self.end(name, out);
+            }
+            GeometryCollection(g) => {
+                self.start("MultiGeometry", srs_name, out);
+                for geometry in g.geometries() {
+                    self.start("geometryMember", None, out);
+                    self.write_geometry(&geometry, None, out)?;
+                    self.end("geometryMember", out);
+                }
+                self.end("MultiGeometry", out);
+            }
+            Rect(_) | Triangle(_) | Line(_) => {
+                self.write_geometry(&to_simple_geometry(geom)?, srs_name, out)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_point(
+        &self,
+        point: &impl PointTrait<T = f64>,
+        srs_name: Option<&str>,
+        out: &mut String,
+    ) {
+        self.start("Point", srs_name, out);
+        if let Some(coord) = point.coord() {
+            let name = if self.version == 3 {
+                "pos"
+            } else {
+                "coordinates"
+            };
+            self.write_coords(name, point.dim(), std::iter::once(coord), out);
+        }
+        self.end("Point", out);
+    }
+
+    fn write_line_string(
+        &self,
+        line_string: &impl LineStringTrait<T = f64>,
+        srs_name: Option<&str>,
+        out: &mut String,
+    ) {
+        if self.curve {
+            self.start("Curve", srs_name, out);
+            self.start("segments", None, out);
+            self.start("LineStringSegment", None, out);
+            self.write_coords("posList", line_string.dim(), line_string.coords(), out);
+            self.end("LineStringSegment", out);
+            self.end("segments", out);
+            self.end("Curve", out);
+        } else {
+            self.start("LineString", srs_name, out);
+            self.write_line_coords(line_string, out);
+            self.end("LineString", out);
+        }
+    }
+
+    fn write_polygon(
+        &self,
+        polygon: &impl PolygonTrait<T = f64>,
+        srs_name: Option<&str>,
+        out: &mut String,
+    ) {
+        let (exterior_name, interior_name) = if self.version == 3 {
+            ("exterior", "interior")
+        } else {
+            ("outerBoundaryIs", "innerBoundaryIs")
+        };
+        self.start("Polygon", srs_name, out);
+        if let Some(exterior) = polygon.exterior() {
+            self.write_ring(exterior_name, &exterior, out);
+        }
+        for interior in polygon.interiors() {
+            self.write_ring(interior_name, &interior, out);
+        }
+        self.end("Polygon", out);
+    }
+
+    fn write_ring(&self, name: &str, ring: &impl LineStringTrait<T = f64>, out: &mut String) {
+        self.start(name, None, out);
+        self.start("LinearRing", None, out);
+        self.write_line_coords(ring, out);
+        self.end("LinearRing", out);
+        self.end(name, out);
+    }
+
+    fn write_line_coords(&self, line_string: &impl LineStringTrait<T = f64>, out: &mut String) {
+        let name = if self.version == 3 {
+            "posList"
+        } else {
+            "coordinates"
+        };
+        self.write_coords(name, line_string.dim(), line_string.coords(), out);
+    }
+
+    /// Write a coordinates element: comma separated tuples in GML 2, or a space separated list
+    /// of ordinates in GML 3.
+    fn write_coords(
+        &self,
+        name: &str,
+        dim: Dimensions,
+        coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+        out: &mut String,
+    ) {
+        let has_z = matches!(dim, Dimensions::Xyz | Dimensions::Xyzm);
+        if self.version == 3 && self.srs_dimension {
+            let srs_dimension = if has_z { 3 } else { 2 };
+            write!(
+                out,
+                r#"<{}{name} srsDimension="{srs_dimension}">"#,
+                self.prefix
+            )
+            .unwrap();
+        } else {
+            self.start(name, None, out);
+        }
+
+        let (ordinate_separator, tuple_separator) = if self.version == 3 {
+            (' ', ' ')
+        } else {
+            (',', ' ')
+        };
+        for (i, coord) in coords.enumerate() {
+            if i > 0 {
+                out.push(tuple_separator);
+            }
+            let (x, y) = if self.lat_lon {
+                (coord.y(), coord.x())
+            } else {
+                (coord.x(), coord.y())
+            };
+            out.push_str(&format_ordinate(x, self.precision));
+            out.push(ordinate_separator);
+            out.push_str(&format_ordinate(y, self.precision));
+            if has_z {
+                out.push(ordinate_separator);
+                out.push_str(&format_ordinate(coord.nth_or_panic(2), self.precision));
+            }
+        }
+        self.end(name, out);
+    }
+
+    fn start(&self, name: &str, srs_name: Option<&str>, out: &mut String) {
+        match srs_name {
+            Some(srs_name) => write!(
+                out,
+                r#"<{}{name} srsName="{}">"#,
+                self.prefix,
+                escape(srs_name)
+            )
+            .unwrap(),
+            None => write!(out, "<{}{name}>", self.prefix).unwrap(),
+        }
+    }
+
+    fn end(&self, name: &str, out: &mut String) {
+        write!(out, "</{}{name}>", self.prefix).unwrap();
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromGML {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromGML {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromGML {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_GML_DOC: OnceLock<Documentation> = OnceLock::new();
+static GEOM_FROM_GML_ALIASES: LazyLock<Vec<String>> =
+    LazyLock::new(|| vec!["st_gmltosql".to_string()]);
+
+impl ScalarUDFImpl for GeomFromGML {
+    fn name(&self) -> &str {
+        "st_geomfromgml"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn aliases(&self) -> &[String] {
+        &GEOM_FROM_GML_ALIASES
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let mut metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+
+        // An explicit SRID takes precedence over the srsName of a literal
+        if let Some(srid) = srid_arg("ST_GeomFromGML", &args, 1)? {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        } else if let Some(Some(scalar)) = args.scalar_arguments.first()
+            && let Some(Some(gml)) = scalar.try_as_str()
+            && let Some(srs_name) = XmlElement::parse(gml)?.attribute("srsName")
+        {
+            metadata = Arc::new(Metadata::new(crs_from_srs_name(srs_name), None));
+        }
+
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_gml_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_GML_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a geometry object from its GML 2 or GML 3 representation. Points, linestrings, curves, polygons with LinearRing or Ring boundaries, surfaces and their multi-geometries are supported. The CRS of the output is the srid argument if given, or else the srsName of a literal input. As GeoArrow stores a single CRS per column, which is fixed when planning, the CRS of a column input is otherwise taken from its field metadata. All EPSG srsNames in the column must match the CRS of the output, and a column with srsNames but no CRS is an error.",
+                "ST_GeomFromGML(geomgml, srid)",
+            )
+            .with_argument("geomgml", "text")
+            .with_argument("srid", "integer, overrides the srsName of the input")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_gml_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+    // The return field was planned from an explicit SRID, so the srsName of each row is ignored
+    let has_srid = matches!(
+        scalar_arg("ST_GeomFromGML", &args, 1, &DataType::Int64)?,
+        Some(ScalarValue::Int64(Some(_)))
+    );
+    let typ = args.return_field.try_extension_type::<GeometryType>()?;
+    let crs = typ.metadata().crs().clone();
+    let mut builder = GeometryBuilder::new(typ);
+
+    for item in array.as_string::<i32>() {
+        if let Some(gml) = item {
+            let root = XmlElement::parse(gml)?;
+            if !has_srid {
+                let srid = root.attribute("srsName").and_then(srid_from_srs_name);
+                check_srid("ST_GeomFromGML", srid, &crs)?;
+            }
+            builder.push_geometry(Some(&parse_gml(&root)?))?;
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+/// Extract the EPSG code from a GML srsName, such as `EPSG:4326`,
+/// `urn:ogc:def:crs:EPSG::4326` or `http://www.opengis.net/def/crs/EPSG/0/4326`.
+fn srid_from_srs_name(srs_name: &str) -> Option<i32> {
+    if !srs_name.to_ascii_uppercase().contains("EPSG") {
+        return None;
+    }
+    let code_start = srs_name
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .len();
+    srs_name[code_start..].parse().ok()
+}
+
+fn crs_from_srs_name(srs_name: &str) -> Crs {
+    match srid_from_srs_name(srs_name) {
+        Some(srid) => crs_from_srid(srid),
+        None => Crs::from_unknown_crs_type(srs_name.to_string()),
+    }
+}
+
+fn parse_gml(root: &XmlElement) -> Result<wkt::Wkt<f64>> {
+    let reader = GmlReader {
+        dim: coordinate_dimension(root)?,
+    };
+    reader.parse_geometry(root, None)
+}
+
+/// The dimension of the coordinates of a geometry element, which is XYZ if all of its
+/// coordinates have a Z value.
+pub(super) fn coordinate_dimension(element: &XmlElement) -> Result<WktDimension> {
+    let mut sizes = vec![];
+    coordinate_sizes(element, None, &mut sizes)?;
+    if !sizes.is_empty() && sizes.iter().all(|size| *size >= 3) {
+        Ok(WktDimension::XYZ)
+    } else {
+        Ok(WktDimension::XY)
+    }
+}
+
+fn coordinate_sizes(
+    element: &XmlElement,
+    srs_dimension: Option<usize>,
+    sizes: &mut Vec<usize>,
+) -> Result<()> {
+    let srs_dimension = srs_dimension_of(element, srs_dimension)?;
+    let tuples = coordinate_tuples(element, srs_dimension)?;
+    if tuples.is_empty() {
+        for child in element.children() {
+            coordinate_sizes(child, srs_dimension, sizes)?;
+        }
+    } else {
+        sizes.extend(tuples.iter().map(Vec::len));
+    }
+    Ok(())
+}
+
+/// The srsDimension of an element, which is inherited from its ancestors.
+fn srs_dimension_of(element: &XmlElement, inherited: Option<usize>) -> Result<Option<usize>> {
+    match element.attribute("srsDimension") {
+        Some(srs_dimension) => srs_dimension.trim().parse().map(Some).map_err(|_| {
+            DataFusionError::Execution(format!("Invalid GML srsDimension {srs_dimension}"))
+        }),
+        None => Ok(inherited),
+    }
+}
+
+/// Parse the coordinate tuples of a `coordinates`, `pos`, `posList` or `coord` element.
+fn coordinate_tuples(element: &XmlElement, srs_dimension: Option<usize>) -> Result<Vec<Vec<f64>>> {
+    match element.name() {
+        "coordinates" => parse_coordinates(element.text()),
+        "pos" => Ok(vec![parse_ordinates(element.text())?]),
+        "posList" => {
+            let ordinates = parse_ordinates(element.text())?;
+            let size = srs_dimension.unwrap_or(2);
+            if size < 2 || !ordinates.len().is_multiple_of(size) {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid GML posList with {} ordinates and srsDimension {size}",
+                    ordinates.len()
+                )));
+            }
+            Ok(ordinates.chunks(size).map(<[f64]>::to_vec).collect())
+        }
+        "coord" => {
+            let tuple = ["X", "Y", "Z"]
+                .into_iter()
+                .filter_map(|name| element.child(name))
+                .map(|ordinate| parse_ordinate(ordinate.text()))
+                .collect::<Result<_>>()?;
+            Ok(vec![tuple])
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// Parse coordinate tuples in the `x,y[,z] x,y[,z]` format shared by GML 2 and KML.
+pub(super) fn parse_coordinates(text: &str) -> Result<Vec<Vec<f64>>> {
+    text.split_whitespace()
+        .map(|tuple| tuple.split(',').map(parse_ordinate).collect())
+        .collect()
+}
+
+fn parse_ordinates(text: &str) -> Result<Vec<f64>> {
+    text.split_whitespace().map(parse_ordinate).collect()
+}
+
+fn parse_ordinate(text: &str) -> Result<f64> {
+    text.trim()
+        .parse()
+        .map_err(|_| DataFusionError::Execution(format!("Invalid coordinate value {text}")))
+}
+
+/// Convert a coordinate tuple to a [`wkt::types::Coord`] of the given dimension.
+pub(super) fn tuple_to_coord(tuple: &[f64], dim: WktDimension) -> Result<wkt::types::Coord<f64>> {
+    if tuple.len() < 2 {
+        return Err(DataFusionError::Execution(format!(
+            "Invalid coordinate with {} values",
+            tuple.len()
+        )));
+    }
+    Ok(wkt::types::Coord {
+        x: tuple[0],
+        y: tuple[1],
+        z: (dim == WktDimension::XYZ).then(|| tuple[2]),
+        m: None,
+    })
+}
+
+/// Reads GML geometry elements into [`wkt::Wkt`], which implements the geo-traits and retains Z
+/// values.
+struct GmlReader {
+    dim: WktDimension,
+}
+
+impl GmlReader {
+    fn parse_geometry(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<wkt::Wkt<f64>> {
+        use wkt::types::*;
+
+        let srs_dimension = srs_dimension_of(element, srs_dimension)?;
+        let geometry = match element.name() {
+            "Point" => wkt::Wkt::Point(self.point(element, srs_dimension)?),
+            "LineString" | "LinearRing" | "Curve" => {
+                wkt::Wkt::LineString(self.line_string(element, srs_dimension)?)
+            }
+            "Polygon" | "PolygonPatch" => wkt::Wkt::Polygon(self.polygon(element, srs_dimension)?),
+            "Surface" => {
+                let mut polygons = element
+                    .children_named("patches")
+                    .flat_map(|patches| patches.children())
+                    .map(|patch| self.polygon(patch, srs_dimension_of(patch, srs_dimension)?))
+                    .collect::<Result<Vec<_>>>()?;
+                if polygons.len() == 1 {
+                    wkt::Wkt::Polygon(polygons.remove(0))
+                } else {
+                    wkt::Wkt::MultiPolygon(MultiPolygon::new(polygons, self.dim))
+                }
+            }
+            "MultiPoint" => {
+                let points =
+                    self.typed_members(element, srs_dimension, |geometry| match geometry {
+                        wkt::Wkt::Point(point) => Some(point),
+                        _ => None,
+                    })?;
+                wkt::Wkt::MultiPoint(MultiPoint::new(points, self.dim))
+            }
+            "MultiLineString" | "MultiCurve" => {
+                let line_strings =
+                    self.typed_members(element, srs_dimension, |geometry| match geometry {
+                        wkt::Wkt::LineString(line_string) => Some(line_string),
+                        _ => None,
+                    })?;
+                wkt::Wkt::MultiLineString(MultiLineString::new(line_strings, self.dim))
+            }
+            "MultiPolygon" | "MultiSurface" => {
+                let polygons =
+                    self.typed_members(element, srs_dimension, |geometry| match geometry {
+                        wkt::Wkt::Polygon(polygon) => Some(polygon),
+                        _ => None,
+                    })?;
+                wkt::Wkt::MultiPolygon(MultiPolygon::new(polygons, self.dim))
+            }
+            "MultiGeometry" => wkt::Wkt::GeometryCollection(GeometryCollection::new(
+                self.members(element, srs_dimension)?,
+                self.dim,
+            )),
+            name => {
+                return Err(DataFusionError::Execution(format!(
+                    "Unsupported GML geometry element {name}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+
+    /// Parse the geometries wrapped by the member elements of a multi-geometry, such as
+    /// `pointMember` or `surfaceMembers`.
+    fn members(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<Vec<wkt::Wkt<f64>>> {
+        element
+            .children()
+            .filter(|child| child.name().ends_with("Member") || child.name().ends_with("Members"))
+            .flat_map(|member| member.children())
+            .map(|geometry| self.parse_geometry(geometry, srs_dimension))
+            .collect()
+    }
+
+    fn typed_members<G>(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+        cast: impl Fn(wkt::Wkt<f64>) -> Option<G>,
+    ) -> Result<Vec<G>> {
+        self.members(element, srs_dimension)?
+            .into_iter()
+            .map(|geometry| {
+                cast(geometry).ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Unexpected member geometry type in GML {}",
+                        element.name()
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    fn point(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<wkt::types::Point<f64>> {
+        let mut coords = self.coords(element, srs_dimension)?;
+        match coords.len() {
+            0 => Ok(wkt::types::Point::empty(self.dim)),
+            1 => Ok(wkt::types::Point::new(coords.pop(), self.dim)),
+            _ => Err(DataFusionError::Execution(
+                "GML Point must have a single coordinate".to_string(),
+            )),
+        }
+    }
+
+    fn line_string(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<wkt::types::LineString<f64>> {
+        let coords = match element.name() {
+            "Curve" => {
+                let mut coords = vec![];
+                for segment in element
+                    .children_named("segments")
+                    .flat_map(|segments| segments.children())
+                {
+                    let segment_coords =
+                        self.coords(segment, srs_dimension_of(segment, srs_dimension)?)?;
+                    append_coords(&mut coords, segment_coords);
+                }
+                coords
+            }
+            // A GML 3 ring is made of the curves in its curveMember elements
+            "Ring" => {
+                let mut coords = vec![];
+                for curve in element
+                    .children_named("curveMember")
+                    .flat_map(|member| member.children())
+                {
+                    let curve = self.line_string(curve, srs_dimension_of(curve, srs_dimension)?)?;
+                    append_coords(&mut coords, curve.into_inner().0);
+                }
+                coords
+            }
+            _ => self.coords(element, srs_dimension)?,
+        };
+        Ok(wkt::types::LineString::new(coords, self.dim))
+    }
+
+    fn polygon(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<wkt::types::Polygon<f64>> {
+        let mut rings = vec![];
+        for boundary in element.children().filter(|child| {
+            matches!(
+                child.name(),
+                "outerBoundaryIs" | "exterior" | "innerBoundaryIs" | "interior"
+            )
+        }) {
+            for ring in boundary.children() {
+                if !matches!(ring.name(), "LinearRing" | "Ring") {
+                    return Err(DataFusionError::Execution(format!(
+                        "Unsupported GML ring element {}",
+                        ring.name()
+                    )));
+                }
+                rings.push(self.line_string(ring, srs_dimension_of(ring, srs_dimension)?)?);
+            }
+        }
+        Ok(wkt::types::Polygon::new(rings, self.dim))
+    }
+
+    /// The coordinates held by the child elements of a geometry element.
+    fn coords(
+        &self,
+        element: &XmlElement,
+        srs_dimension: Option<usize>,
+    ) -> Result<Vec<wkt::types::Coord<f64>>> {
+        let mut coords = vec![];
+        for child in element.children() {
+            for tuple in coordinate_tuples(child, srs_dimension_of(child, srs_dimension)?)? {
+                coords.push(tuple_to_coord(&tuple, self.dim)?);
+            }
+        }
+        Ok(coords)
+    }
+}
+
+/// Append the coordinates of a curve segment, skipping the first if it repeats the previous end
+/// point, as consecutive segments share their end points.
+fn append_coords(coords: &mut Vec<wkt::types::Coord<f64>>, segment: Vec<wkt::types::Coord<f64>>) {
+    let skip = usize::from(
+        coords
+            .last()
+            .is_some_and(|last| segment.first() == Some(last)),
+    );
+    coords.extend(segment.into_iter().skip(skip));
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsEWKT, AsText, GeomFromEWKT};
+
+    #[tokio::test]
+    async fn test_as_gml() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGML::new().into());
+        ctx.register_udf(GeomFromEWKT::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsGML(ST_GeomFromEWKT('SRID=4326;POLYGON((0 0,0 1,1 1,1 0,0 0))')),
+                    ST_AsGML(3, ST_GeomFromEWKT('SRID=4326;LINESTRING(1 2,3.123456 4)'), 3, 1);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            r#"<gml:Polygon srsName="EPSG:4326"><gml:outerBoundaryIs><gml:LinearRing><gml:coordinates>0,0 0,1 1,1 1,0 0,0</gml:coordinates></gml:LinearRing></gml:outerBoundaryIs></gml:Polygon>"#
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            r#"<gml:Curve srsName="urn:ogc:def:crs:EPSG::4326"><gml:segments><gml:LineStringSegment><gml:posList srsDimension="2">1 2 3.123 4</gml:posList></gml:LineStringSegment></gml:segments></gml:Curve>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_gml() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGML::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                r#"SELECT ST_GeomFromGML('<gml:MultiSurface xmlns:gml="http://www.opengis.net/gml" srsName="urn:ogc:def:crs:EPSG::3857" srsDimension="3">
+                    <gml:surfaceMember><gml:Polygon><gml:exterior><gml:LinearRing>
+                        <gml:posList>0 0 1 0 1 1 1 1 1 0 0 1</gml:posList>
+                    </gml:LinearRing></gml:exterior></gml:Polygon></gml:surfaceMember>
+                </gml:MultiSurface>') AS geom;"#,
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        let geom_type = schema.field(0).extension_type::<GeometryType>();
+        assert_eq!(geom_type.metadata().crs(), &crs_from_srid(3857));
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_GeomFromGML('<gml:LineString><gml:coordinates>1,2 3,4</gml:coordinates></gml:LineString>'));",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "LINESTRING(1 2,3 4)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_as_gml_prefix() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsGML::new().into());
+        ctx.register_udf(GeomFromEWKT::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsGML(2, ST_GeomFromEWKT('POINT(1 2)'), 15, 0, 'ns');")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "<ns:Point><ns:coordinates>1,2</ns:coordinates></ns:Point>"
+        );
+
+        let err = ctx
+            .sql(r#"SELECT ST_AsGML(2, ST_GeomFromEWKT('POINT(1 2)'), 15, 0, 'a"><b');"#)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("not a valid XML name"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_gml_ring() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGML::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_GeomFromGML('<gml:Polygon><gml:exterior><gml:Ring>
+                    <gml:curveMember><gml:LineString><gml:posList>0 0 1 0 1 1</gml:posList></gml:LineString></gml:curveMember>
+                    <gml:curveMember><gml:Curve><gml:segments><gml:LineStringSegment>
+                        <gml:posList>1 1 0 1 0 0</gml:posList>
+                    </gml:LineStringSegment></gml:segments></gml:Curve></gml:curveMember>
+                </gml:Ring></gml:exterior></gml:Polygon>'));",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((0 0,1 0,1 1,0 1,0 0))"
+        );
+
+        let err = ctx
+            .sql(
+                "SELECT ST_GeomFromGML('<gml:Polygon><gml:exterior><gml:Circle/></gml:exterior></gml:Polygon>');",
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unsupported GML ring element Circle"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_gml_column_srs_name() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGML::default().into());
+        ctx.register_udf(AsEWKT::new().into());
+
+        let df = ctx
+            .sql(
+                r#"SELECT ST_AsEWKT(ST_GeomFromGML(g, 4326)) FROM (VALUES
+                    ('<gml:Point srsName="EPSG:4326"><gml:pos>1 2</gml:pos></gml:Point>')
+                ) AS t(g);"#,
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "SRID=4326;POINT(1 2)"
+        );
+
+        // Without a CRS, the srsNames of a column would be lost
+        let err = ctx
+            .sql(
+                r#"SELECT ST_GeomFromGML(g) FROM (VALUES
+                    ('<gml:Point srsName="EPSG:4326"><gml:pos>1 2</gml:pos></gml:Point>')
+                ) AS t(g);"#,
+            )
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("SRID"), "unexpected error: {err}");
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_gml_nesting_limit() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromGML::default().into());
+
+        let depth = 2000;
+        let gml = format!(
+            "{}<gml:Point><gml:pos>1 2</gml:pos></gml:Point>{}",
+            "<gml:MultiGeometry><gml:geometryMember>".repeat(depth),
+            "</gml:geometryMember></gml:MultiGeometry>".repeat(depth)
+        );
+        // A literal is parsed when planning, to read its srsName
+        let err = match ctx.sql(&format!("SELECT ST_GeomFromGML('{gml}');")).await {
+            Ok(df) => df.collect().await.unwrap_err(),
+            Err(err) => err,
+        };
+        assert!(
+            err.to_string().contains("nested more than"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -0,0 +1,530 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeometryType, Metadata};
+use wkt::types::Dimension as WktDimension;
+
+use super::geojson::format_ordinate;
+use super::gml::{
+    DEFAULT_MAX_DECIMAL_DIGITS, coordinate_dimension, parse_coordinates, tuple_to_coord,
+};
+use super::util::to_simple_geometry;
+use super::xml::{XmlElement, namespace_prefix};
+use crate::crs::{crs_from_srid, srid_from_crs};
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::io::util::scalar_arg;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsKML {
+    signature: Signature,
+}
+
+impl AsKML {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=3).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsKML {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_KML_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsKML {
+    fn name(&self) -> &str {
+        "st_askml"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_kml_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_KML_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry as a Keyhole Markup Language (KML) element. KML coordinates are WGS84 longitude and latitude, so the input must not have a CRS other than EPSG:4326. Coordinates are written with at most maxdecimaldigits decimal places (default 15). Elements are qualified with the nprefix namespace prefix if it is given.",
+                "ST_AsKML(geometry, maxdecimaldigits, nprefix)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("maxdecimaldigits", "integer, default 15")
+            .with_argument("nprefix", "text, default no prefix")
+            .build()
+        }))
+    }
+}
+
+fn as_kml_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let precision = match scalar_arg("ST_AsKML", &args, 1, &DataType::Int64)? {
+        Some(ScalarValue::Int64(Some(digits))) => usize::try_from(digits).map_err(|_| {
+            DataFusionError::Execution("ST_AsKML maxdecimaldigits must not be negative".to_string())
+        })?,
+        _ => DEFAULT_MAX_DECIMAL_DIGITS,
+    };
+    let prefix = match scalar_arg("ST_AsKML", &args, 2, &DataType::Utf8)? {
+        Some(ScalarValue::Utf8(Some(prefix))) => namespace_prefix("ST_AsKML", &prefix)?,
+        _ => String::new(),
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    if let Some(srid) = srid_from_crs(geo_array.data_type().metadata().crs())
+        && srid != 4326
+    {
+        return Err(DataFusionError::Execution(format!(
+            "ST_AsKML requires geometries in EPSG:4326, found SRID {srid}"
+        ))
+        .into());
+    }
+
+    let writer = KmlWriter { precision, prefix };
+    let result = to_kml(&geo_array, &writer)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn to_kml(array: &dyn GeoArrowArray, writer: &KmlWriter) -> GeoArrowResult<StringArray> {
+    downcast_geoarrow_array!(array, _to_kml_impl, writer)
+}
+
+fn _to_kml_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    writer: &KmlWriter,
+) -> GeoArrowResult<StringArray> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let mut out = String::new();
+                writer.write_geometry(&geom?, &mut out)?;
+                Ok(out)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+struct KmlWriter {
+    precision: usize,
+    prefix: String,
+}
+
+impl KmlWriter {
+    fn write_geometry(
+        &self,
+        geom: &impl GeometryTrait<T = f64>,
+        out: &mut String,
+    ) -> GeoArrowResult<()> {
+        use geo_traits::GeometryType::*;
+
+        // KML has no multi-geometry types, so all collections are a MultiGeometry
+        match geom.as_type() {
+            Point(g) => self.write_point(g, out),
+            LineString(g) => self.write_line_string("LineString", g, out),
+            Polygon(g) => self.write_polygon(g, out),
+            MultiPoint(g) => {
+                self.start("MultiGeometry", out);
+                for point in g.points() {
+                    self.write_point(&point, out);
+                }
+                self.end("MultiGeometry", out);
+            }
+            MultiLineString(g) => {
+                self.start("MultiGeometry", out);
+                for line_string in g.line_strings() {
+                    self.write_line_string("LineString", &line_string, out);
+                }
+                self.end("MultiGeometry", out);
+            }
+            MultiPolygon(g) => {
+                self.start("MultiGeometry", out);
+                for polygon in g.polygons() {
+                    self.write_polygon(&polygon, out);
+                }
+                self.end("MultiGeometry", out);
+            }
+            GeometryCollection(g) => {
+                self.start("MultiGeometry", out);
+                for geometry in g.geometries() {
+                    self.write_geometry(&geometry, out)?;
+                }
+                self.end("MultiGeometry", out);
+            }
+            Rect(_) | Triangle(_) | Line(_) => {
+                self.write_geometry(&to_simple_geometry(geom)?, out)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_point(&self, point: &impl PointTrait<T = f64>, out: &mut String) {
+        self.start("Point", out);
+        if let Some(coord) = point.coord() {
+            self.write_coords(std::iter::once(coord), out);
+        }
+        self.end("Point", out);
+    }
+
+    fn write_line_string(
+        &self,
+        name: &str,
+        line_string: &impl LineStringTrait<T = f64>,
+        out: &mut String,
+    ) {
+        self.start(name, out);
+        self.write_coords(line_string.coords(), out);
+        self.end(name, out);
+    }
+
+    fn write_polygon(&self, polygon: &impl PolygonTrait<T = f64>, out: &mut String) {
+        self.start("Polygon", out);
+        if let Some(exterior) = polygon.exterior() {
+            self.start("outerBoundaryIs", out);
+            self.write_line_string("LinearRing", &exterior, out);
+            self.end("outerBoundaryIs", out);
+        }
+        for interior in polygon.interiors() {
+            self.start("innerBoundaryIs", out);
+            self.write_line_string("LinearRing", &interior, out);
+            self.end("innerBoundaryIs", out);
+        }
+        self.end("Polygon", out);
+    }
+
+    /// Write a coordinates element of comma separated tuples. M values are dropped.
+    fn write_coords(
+        &self,
+        coords: impl Iterator<Item = impl CoordTrait<T = f64>>,
+        out: &mut String,
+    ) {
+        self.start("coordinates", out);
+        for (i, coord) in coords.enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            out.push_str(&format_ordinate(coord.x(), self.precision));
+            out.push(',');
+            out.push_str(&format_ordinate(coord.y(), self.precision));
+            if matches!(coord.dim(), Dimensions::Xyz | Dimensions::Xyzm) {
+                out.push(',');
+                out.push_str(&format_ordinate(coord.nth_or_panic(2), self.precision));
+            }
+        }
+        self.end("coordinates", out);
+    }
+
+    fn start(&self, name: &str, out: &mut String) {
+        out.push('<');
+        out.push_str(&self.prefix);
+        out.push_str(name);
+        out.push('>');
+    }
+
+    fn end(&self, name: &str, out: &mut String) {
+        out.push_str("</");
+        out.push_str(&self.prefix);
+        out.push_str(name);
+        out.push('>');
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromKML {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromKML {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromKML {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_KML_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromKML {
+    fn name(&self) -> &str {
+        "st_geomfromkml"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        // KML coordinates are always WGS84 longitude and latitude
+        let metadata = Arc::new(Metadata::new(crs_from_srid(4326), None));
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_kml_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_KML_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Constructs a geometry object from its KML representation. A MultiGeometry whose members all have the same type becomes the corresponding multi-geometry, and a GeometryCollection otherwise. Z coordinates are preserved, and the output CRS is EPSG:4326.",
+                "ST_GeomFromKML(geomkml)",
+            )
+            .with_argument("geomkml", "text")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_kml_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Utf8, None)?
+        .into_array(args.number_rows)?;
+    let typ = args.return_field.try_extension_type::<GeometryType>()?;
+    let mut builder = GeometryBuilder::new(typ);
+
+    for item in array.as_string::<i32>() {
+        if let Some(kml) = item {
+            let root = XmlElement::parse(kml)?;
+            let reader = KmlReader {
+                dim: coordinate_dimension(&root)?,
+            };
+            builder.push_geometry(Some(&reader.parse_geometry(&root)?))?;
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+/// Reads KML geometry elements into [`wkt::Wkt`], which implements the geo-traits and retains Z
+/// values.
+struct KmlReader {
+    dim: WktDimension,
+}
+
+impl KmlReader {
+    fn parse_geometry(&self, element: &XmlElement) -> Result<wkt::Wkt<f64>> {
+        use wkt::types::*;
+
+        let geometry = match element.name() {
+            "Point" => {
+                let mut coords = self.coords(element)?;
+                match coords.len() {
+                    0 => wkt::Wkt::Point(Point::empty(self.dim)),
+                    1 => wkt::Wkt::Point(Point::new(coords.pop(), self.dim)),
+                    _ => {
+                        return Err(DataFusionError::Execution(
+                            "KML Point must have a single coordinate".to_string(),
+                        ));
+                    }
+                }
+            }
+            "LineString" | "LinearRing" => {
+                wkt::Wkt::LineString(LineString::new(self.coords(element)?, self.dim))
+            }
+            "Polygon" => {
+                let mut rings = vec![];
+                for boundary in element
+                    .children()
+                    .filter(|child| matches!(child.name(), "outerBoundaryIs" | "innerBoundaryIs"))
+                {
+                    for ring in boundary.children_named("LinearRing") {
+                        rings.push(LineString::new(self.coords(ring)?, self.dim));
+                    }
+                }
+                wkt::Wkt::Polygon(Polygon::new(rings, self.dim))
+            }
+            "MultiGeometry" => {
+                let geometries = element
+                    .children()
+                    .map(|child| self.parse_geometry(child))
+                    .collect::<Result<Vec<_>>>()?;
+                self.homogenize(geometries)
+            }
+            name => {
+                return Err(DataFusionError::Execution(format!(
+                    "Unsupported KML geometry element {name}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+
+    /// Convert the members of a MultiGeometry to a multi-geometry if they all have the same
+    /// type.
+    fn homogenize(&self, geometries: Vec<wkt::Wkt<f64>>) -> wkt::Wkt<f64> {
+        use wkt::types::*;
+
+        if !geometries.is_empty() {
+            if geometries.iter().all(|g| matches!(g, wkt::Wkt::Point(_))) {
+                let points = geometries
+                    .into_iter()
+                    .filter_map(|g| match g {
+                        wkt::Wkt::Point(point) => Some(point),
+                        _ => None,
+                    })
+                    .collect();
+                return wkt::Wkt::MultiPoint(MultiPoint::new(points, self.dim));
+            }
+            if geometries
+                .iter()
+                .all(|g| matches!(g, wkt::Wkt::LineString(_)))
+            {
+                let line_strings = geometries
+                    .into_iter()
+                    .filter_map(|g| match g {
+                        wkt::Wkt::LineString(line_string) => Some(line_string),
+                        _ => None,
+                    })
+                    .collect();
+                return wkt::Wkt::MultiLineString(MultiLineString::new(line_strings, self.dim));
+            }
+            if geometries.iter().all(|g| matches!(g, wkt::Wkt::Polygon(_))) {
+                let polygons = geometries
+                    .into_iter()
+                    .filter_map(|g| match g {
+                        wkt::Wkt::Polygon(polygon) => Some(polygon),
+                        _ => None,
+                    })
+                    .collect();
+                return wkt::Wkt::MultiPolygon(MultiPolygon::new(polygons, self.dim));
+            }
+        }
+        wkt::Wkt::GeometryCollection(GeometryCollection::new(geometries, self.dim))
+    }
+
+    /// The coordinates of the `coordinates` child of a geometry element.
+    fn coords(&self, element: &XmlElement) -> Result<Vec<wkt::types::Coord<f64>>> {
+        let Some(coordinates) = element.child("coordinates") else {
+            return Ok(vec![]);
+        };
+        parse_coordinates(coordinates.text())?
+            .iter()
+            .map(|tuple| tuple_to_coord(tuple, self.dim))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_as_kml() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsKML::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsKML(ST_GeomFromText('MULTIPOINT(1.123456 2,3 4)'), 2);")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "<MultiGeometry><Point><coordinates>1.12,2</coordinates></Point><Point><coordinates>3,4</coordinates></Point></MultiGeometry>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_kml() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromKML::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsText(ST_GeomFromKML('<Polygon><outerBoundaryIs><LinearRing><coordinates>0,0,1 1,0,1 1,1,1 0,0,1</coordinates></LinearRing></outerBoundaryIs></Polygon>')),
+                    ST_AsText(ST_GeomFromKML('<MultiGeometry><LineString><coordinates>1,2 3,4</coordinates></LineString><LineString><coordinates>5,6 7,8</coordinates></LineString></MultiGeometry>'));",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "MULTILINESTRING((1 2,3 4),(5 6,7 8))"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_geom_from_kml_nesting_limit() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(GeomFromKML::default().into());
+
+        let depth = 4000;
+        let kml = format!(
+            "{}<Point><coordinates>1,2</coordinates></Point>{}",
+            "<MultiGeometry>".repeat(depth),
+            "</MultiGeometry>".repeat(depth)
+        );
+        let err = ctx
+            .sql(&format!("SELECT ST_GeomFromKML('{kml}');"))
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("nested more than"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -1,14 +1,69 @@
 //! Geometry Input and Output
 
+mod bd_poly;
+mod encoded_polyline;
+mod ewkb;
+mod ewkt;
+mod geojson;
+mod gml;
+mod kml;
+mod mvt;
+mod svg;
+mod twkb;
+mod typed;
+pub(crate) mod util;
 mod wkb;
 mod wkt;
+mod xml;
 
+pub use bd_poly::{BdMPolyFromText, BdPolyFromText};
+pub use encoded_polyline::{AsEncodedPolyline, LineFromEncodedPolyline};
+pub use ewkb::{AsEWKB, AsHEXEWKB, GeomFromEWKB};
+pub use ewkt::{AsEWKT, GeomFromEWKT};
+pub use geojson::{AsGeoJSON, GeomFromGeoJSON};
+pub use gml::{AsGML, GeomFromGML};
+pub use kml::{AsKML, GeomFromKML};
+pub use mvt::AsMVT;
+pub use svg::AsSVG;
+pub use twkb::{AsTWKB, GeomFromTWKB};
+pub use typed::{
+    GeomCollFromText, LineFromText, LineFromWKB, MLineFromText, MPointFromText, MPolyFromText,
+    PointFromText, PointFromWKB, PolygonFromText,
+};
 pub use wkb::{AsBinary, GeomFromWKB};
 pub use wkt::{AsText, GeomFromText};
 
 pub fn register(session_context: &datafusion::prelude::SessionContext) {
     session_context.register_udf(AsBinary.into());
+    session_context.register_udf(BdMPolyFromText::default().into());
+    session_context.register_udf(BdPolyFromText::default().into());
+    session_context.register_udf(AsEncodedPolyline::default().into());
+    session_context.register_udf(LineFromEncodedPolyline::default().into());
+    session_context.register_udf(AsEWKB::default().into());
+    session_context.register_udf(AsEWKT.into());
+    session_context.register_udf(AsGeoJSON.into());
+    session_context.register_udf(GeomFromGeoJSON::default().into());
+    session_context.register_udf(AsGML::default().into());
+    session_context.register_udf(GeomFromGML::default().into());
+    session_context.register_udf(AsHEXEWKB::default().into());
+    session_context.register_udf(GeomFromEWKB::default().into());
+    session_context.register_udf(GeomFromEWKT::default().into());
     session_context.register_udf(GeomFromWKB::default().into());
+    session_context.register_udf(AsKML::default().into());
+    session_context.register_udf(GeomFromKML::default().into());
+    session_context.register_udf(AsSVG::default().into());
+    session_context.register_udf(AsTWKB::default().into());
+    session_context.register_udf(GeomFromTWKB::default().into());
+    session_context.register_udaf(AsMVT::default().into());
+    session_context.register_udf(GeomCollFromText::default().into());
+    session_context.register_udf(LineFromText::default().into());
+    session_context.register_udf(LineFromWKB::default().into());
+    session_context.register_udf(MLineFromText::default().into());
+    session_context.register_udf(MPointFromText::default().into());
+    session_context.register_udf(MPolyFromText::default().into());
+    session_context.register_udf(PointFromText::default().into());
+    session_context.register_udf(PointFromWKB::default().into());
+    session_context.register_udf(PolygonFromText::default().into());
     session_context.register_udf(AsText.into());
     session_context.register_udf(GeomFromText::default().into());
 }
@@ -0,0 +1,657 @@
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, StructArray, new_empty_array};
+use arrow_schema::{DataType, Field, FieldRef};
+use datafusion::arrow::compute::concat;
+use datafusion::common::utils::SingleRowListArrayBuilder;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{
+    Accumulator, AggregateUDFImpl, Documentation, Signature, TypeSignature, Volatility,
+};
+use datafusion::physical_expr::expressions::Literal;
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+use geoarrow_schema::{CoordType, GeoArrowType, GeometryType};
+
+use crate::error::GeoDataFusionResult;
+
+const GEOM_TYPE_POINT: u32 = 1;
+const GEOM_TYPE_LINESTRING: u32 = 2;
+const GEOM_TYPE_POLYGON: u32 = 3;
+
+const COMMAND_MOVE_TO: u32 = 1;
+const COMMAND_LINE_TO: u32 = 2;
+const COMMAND_CLOSE_PATH: u32 = 7;
+
+const WIRE_VARINT: u32 = 0;
+const WIRE_FIXED64: u32 = 1;
+const WIRE_LENGTH_DELIMITED: u32 = 2;
+const WIRE_FIXED32: u32 = 5;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsMVT {
+    signature: Signature,
+}
+
+impl AsMVT {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=5).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsMVT {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl AggregateUDFImpl for AsMVT {
+    fn name(&self) -> &str {
+        "st_asmvt"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![
+            Field::new_list(
+                format_state_name(args.name, "rows"),
+                Field::new_list_field(args.input_fields[0].data_type().clone(), true),
+                true,
+            )
+            .into(),
+        ])
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let DataType::Struct(fields) = acc_args.expr_fields[0].data_type() else {
+            return Err(DataFusionError::Plan(
+                "ST_AsMVT expects a struct of the row to encode as its first argument".to_string(),
+            ));
+        };
+
+        let literal_str = |index: usize| -> Result<Option<String>> {
+            match literal_arg(&acc_args, index)? {
+                Some(scalar) => Ok(scalar.try_as_str().flatten().map(|s| s.to_string())),
+                None => Ok(None),
+            }
+        };
+        let name = literal_str(1)?.unwrap_or_else(|| "default".to_string());
+        let extent = match literal_arg(&acc_args, 2)? {
+            Some(scalar) => match scalar.cast_to(&DataType::UInt32)? {
+                ScalarValue::UInt32(Some(extent)) if extent > 0 => extent,
+                ScalarValue::UInt32(None) => 4096,
+                _ => {
+                    return Err(DataFusionError::Plan(
+                        "ST_AsMVT extent must be greater than 0".to_string(),
+                    ));
+                }
+            },
+            None => 4096,
+        };
+        let geom_name = literal_str(3)?;
+        let feature_id_name = literal_str(4)?;
+
+        let geometry_index = match &geom_name {
+            Some(geom_name) => fields.find(geom_name).map(|(index, _)| index),
+            None => fields
+                .iter()
+                .position(|field| geometry_field(field, false).is_some()),
+        }
+        .ok_or_else(|| {
+            DataFusionError::Plan("ST_AsMVT could not find a geometry column".to_string())
+        })?;
+        let geometry_field = geometry_field(&fields[geometry_index], geom_name.is_some())
+            .ok_or_else(|| {
+                DataFusionError::Plan(format!(
+                    "ST_AsMVT column {} is not a geometry",
+                    fields[geometry_index].name()
+                ))
+            })?;
+        let feature_id_index = feature_id_name
+            .map(|feature_id_name| {
+                fields
+                    .find(&feature_id_name)
+                    .map(|(index, _)| index)
+                    .ok_or_else(|| {
+                        DataFusionError::Plan(format!(
+                            "ST_AsMVT could not find feature id column {feature_id_name}"
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        Ok(Box::new(AsMVTAccumulator {
+            options: LayerOptions {
+                name,
+                extent,
+                geometry_index,
+                geometry_field,
+                feature_id_index,
+            },
+            rows_type: acc_args.expr_fields[0].data_type().clone(),
+            rows: vec![],
+        }))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Aggregate function returning a Mapbox Vector Tile (MVT) representation of a set of rows. The rows are passed as a struct, for example with named_struct. Each row becomes a feature of a single tile layer: its geometry column, which should be in tile coordinate space as returned by ST_AsMVTGeom, is the feature geometry, and the other non-null columns are feature attributes. Geometry collections and rows with a NULL geometry are skipped.",
+                "ST_AsMVT(row, name, extent, geom_name, feature_id_name)",
+            )
+            .with_argument("row", "struct")
+            .with_argument("name", "text, name of the layer, default 'default'")
+            .with_argument("extent", "integer, tile extent in screen space, default 4096")
+            .with_argument("geom_name", "text, name of the geometry column, default the first geometry column")
+            .with_argument("feature_id_name", "text, name of an integer column to use as the feature id")
+            .with_related_udf("st_asmvtgeom")
+            .build()
+        }))
+    }
+}
+
+/// The value of a literal argument of the aggregate, if it was passed.
+fn literal_arg(acc_args: &AccumulatorArgs, index: usize) -> Result<Option<ScalarValue>> {
+    let Some(expr) = acc_args.exprs.get(index) else {
+        return Ok(None);
+    };
+    let literal = expr.downcast_ref::<Literal>().ok_or_else(|| {
+        DataFusionError::Plan(format!("ST_AsMVT argument {} must be a literal", index + 1))
+    })?;
+    Ok((!literal.value().is_null()).then(|| literal.value().clone()))
+}
+
+/// The GeoArrow field of a struct member, if it holds geometries.
+///
+/// Struct constructors like `named_struct` drop the GeoArrow extension metadata of their members,
+/// in which case the geometry union type of ST_AsMVTGeom is still recognized. When the
+/// column was requested explicitly, WKB and WKT columns are also accepted.
+fn geometry_field(field: &Field, explicit: bool) -> Option<FieldRef> {
+    if let Ok(Some(_)) = GeoArrowType::from_extension_field(field) {
+        return Some(Arc::new(field.clone()));
+    }
+    for coord_type in [CoordType::Separated, CoordType::Interleaved] {
+        let geometry_type = GeometryType::new(Default::default()).with_coord_type(coord_type);
+        if field.data_type() == &geometry_type.data_type() {
+            return Some(Arc::new(geometry_type.to_field(field.name(), true)));
+        }
+    }
+    if explicit && GeoArrowType::from_arrow_field(field).is_ok() {
+        return Some(Arc::new(field.clone()));
+    }
+    None
+}
+
+#[derive(Debug)]
+struct LayerOptions {
+    name: String,
+    extent: u32,
+    geometry_index: usize,
+    geometry_field: FieldRef,
+    feature_id_index: Option<usize>,
+}
+
+#[derive(Debug)]
+struct AsMVTAccumulator {
+    options: LayerOptions,
+    rows_type: DataType,
+    rows: Vec<StructArray>,
+}
+
+impl Accumulator for AsMVTAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let rows = if self.rows.is_empty() {
+            new_empty_array(&self.rows_type)
+        } else {
+            let rows = self
+                .rows
+                .iter()
+                .map(|rows| rows as &dyn Array)
+                .collect::<Vec<_>>();
+            concat(&rows)?
+        };
+        Ok(vec![
+            SingleRowListArrayBuilder::new(rows).build_list_scalar(),
+        ])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mut layer = LayerBuilder::default();
+        for rows in &self.rows {
+            layer.push_rows(rows, &self.options)?;
+        }
+        if layer.features.is_empty() {
+            return Ok(ScalarValue::Binary(Some(vec![])));
+        }
+        Ok(ScalarValue::Binary(Some(layer.finish(&self.options))))
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.rows.push(values[0].as_struct().clone());
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        for rows in states[0].as_list::<i32>().iter().flatten() {
+            if let Some(rows) = rows.as_struct_opt()
+                && !rows.is_empty()
+            {
+                self.rows.push(rows.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self
+                .rows
+                .iter()
+                .map(|rows| rows.get_array_memory_size())
+                .sum::<usize>()
+    }
+}
+
+/// Builds the features, keys and values of a tile layer.
+#[derive(Default)]
+struct LayerBuilder {
+    features: Vec<Vec<u8>>,
+    keys: Vec<String>,
+    key_indices: HashMap<String, u32>,
+    values: Vec<Vec<u8>>,
+    value_indices: HashMap<Vec<u8>, u32>,
+}
+
+impl LayerBuilder {
+    fn push_rows(&mut self, rows: &StructArray, options: &LayerOptions) -> GeoDataFusionResult<()> {
+        let geometry_array =
+            from_arrow_array(rows.column(options.geometry_index), &options.geometry_field)?;
+        let geometries = encode_geometries(geometry_array.as_ref())?;
+
+        for (row, geometry) in geometries.into_iter().enumerate() {
+            let Some((geom_type, geometry)) = geometry else {
+                continue;
+            };
+            if rows.is_null(row) {
+                continue;
+            }
+
+            let mut id = None;
+            let mut tags = vec![];
+            for (index, (field, column)) in rows.fields().iter().zip(rows.columns()).enumerate() {
+                if index == options.geometry_index || column.is_null(row) {
+                    continue;
+                }
+                let value = ScalarValue::try_from_array(column, row)?;
+                if Some(index) == options.feature_id_index {
+                    id = match value.cast_to(&DataType::UInt64) {
+                        Ok(ScalarValue::UInt64(id)) => id,
+                        _ => None,
+                    };
+                    continue;
+                }
+                tags.push(self.key_index(field.name()));
+                tags.push(self.value_index(&value));
+            }
+
+            let mut feature = vec![];
+            if let Some(id) = id {
+                write_tag(&mut feature, 1, WIRE_VARINT);
+                write_varint(&mut feature, id);
+            }
+            write_packed(&mut feature, 2, &tags);
+            write_tag(&mut feature, 3, WIRE_VARINT);
+            write_varint(&mut feature, geom_type as u64);
+            write_packed(&mut feature, 4, &geometry);
+            self.features.push(feature);
+        }
+        Ok(())
+    }
+
+    fn key_index(&mut self, key: &str) -> u32 {
+        if let Some(index) = self.key_indices.get(key) {
+            return *index;
+        }
+        let index = self.keys.len() as u32;
+        self.keys.push(key.to_string());
+        self.key_indices.insert(key.to_string(), index);
+        index
+    }
+
+    fn value_index(&mut self, value: &ScalarValue) -> u32 {
+        let value = encode_value(value);
+        if let Some(index) = self.value_indices.get(&value) {
+            return *index;
+        }
+        let index = self.values.len() as u32;
+        self.values.push(value.clone());
+        self.value_indices.insert(value, index);
+        index
+    }
+
+    /// Encode a tile with this layer.
+    fn finish(self, options: &LayerOptions) -> Vec<u8> {
+        let mut layer = vec![];
+        write_tag(&mut layer, 15, WIRE_VARINT);
+        write_varint(&mut layer, 2);
+        write_bytes(&mut layer, 1, options.name.as_bytes());
+        for feature in &self.features {
+            write_bytes(&mut layer, 2, feature);
+        }
+        for key in &self.keys {
+            write_bytes(&mut layer, 3, key.as_bytes());
+        }
+        for value in &self.values {
+            write_bytes(&mut layer, 4, value);
+        }
+        write_tag(&mut layer, 5, WIRE_VARINT);
+        write_varint(&mut layer, options.extent as u64);
+
+        let mut tile = vec![];
+        write_bytes(&mut tile, 3, &layer);
+        tile
+    }
+}
+
+/// Encode a `Value` message of the tile.
+fn encode_value(value: &ScalarValue) -> Vec<u8> {
+    let mut out = vec![];
+    match value {
+        ScalarValue::Boolean(Some(v)) => {
+            write_tag(&mut out, 7, WIRE_VARINT);
+            write_varint(&mut out, *v as u64);
+        }
+        ScalarValue::Float32(Some(v)) => {
+            write_tag(&mut out, 2, WIRE_FIXED32);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        ScalarValue::Float64(Some(v)) => {
+            write_tag(&mut out, 3, WIRE_FIXED64);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        ScalarValue::UInt8(Some(_))
+        | ScalarValue::UInt16(Some(_))
+        | ScalarValue::UInt32(Some(_))
+        | ScalarValue::UInt64(Some(_)) => {
+            let Ok(ScalarValue::UInt64(Some(v))) = value.cast_to(&DataType::UInt64) else {
+                unreachable!()
+            };
+            write_tag(&mut out, 5, WIRE_VARINT);
+            write_varint(&mut out, v);
+        }
+        ScalarValue::Int8(Some(_))
+        | ScalarValue::Int16(Some(_))
+        | ScalarValue::Int32(Some(_))
+        | ScalarValue::Int64(Some(_)) => {
+            let Ok(ScalarValue::Int64(Some(v))) = value.cast_to(&DataType::Int64) else {
+                unreachable!()
+            };
+            if v < 0 {
+                write_tag(&mut out, 6, WIRE_VARINT);
+                write_varint(&mut out, zigzag(v));
+            } else {
+                write_tag(&mut out, 5, WIRE_VARINT);
+                write_varint(&mut out, v as u64);
+            }
+        }
+        ScalarValue::Utf8(Some(v))
+        | ScalarValue::LargeUtf8(Some(v))
+        | ScalarValue::Utf8View(Some(v)) => {
+            write_bytes(&mut out, 1, v.as_bytes());
+        }
+        other => write_bytes(&mut out, 1, other.to_string().as_bytes()),
+    }
+    out
+}
+
+/// An MVT geometry type and its encoded commands.
+type EncodedGeometry = (u32, Vec<u32>);
+
+fn encode_geometries(array: &dyn GeoArrowArray) -> GeoArrowResult<Vec<Option<EncodedGeometry>>> {
+    downcast_geoarrow_array!(array, _encode_geometries_impl)
+}
+
+fn _encode_geometries_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+) -> GeoArrowResult<Vec<Option<EncodedGeometry>>> {
+    array
+        .iter()
+        .map(|item| match item {
+            Some(geom) => Ok(encode_geometry(&geom?)),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+/// Encode a geometry into MVT commands, returning `None` for empty or unsupported geometries.
+fn encode_geometry(geom: &impl GeometryTrait<T = f64>) -> Option<EncodedGeometry> {
+    let mut encoder = CommandEncoder::default();
+    let geom_type = match geom.as_type() {
+        geo_traits::GeometryType::Point(point) => {
+            encoder.points(point.coord().into_iter());
+            GEOM_TYPE_POINT
+        }
+        geo_traits::GeometryType::MultiPoint(multi_point) => {
+            let points = multi_point.points().collect::<Vec<_>>();
+            encoder.points(points.iter().filter_map(|point| point.coord()));
+            GEOM_TYPE_POINT
+        }
+        geo_traits::GeometryType::LineString(line_string) => {
+            encoder.line_string(line_string);
+            GEOM_TYPE_LINESTRING
+        }
+        geo_traits::GeometryType::MultiLineString(multi_line_string) => {
+            multi_line_string
+                .line_strings()
+                .for_each(|line_string| encoder.line_string(&line_string));
+            GEOM_TYPE_LINESTRING
+        }
+        geo_traits::GeometryType::Polygon(polygon) => {
+            encoder.polygon(polygon);
+            GEOM_TYPE_POLYGON
+        }
+        geo_traits::GeometryType::MultiPolygon(multi_polygon) => {
+            multi_polygon
+                .polygons()
+                .for_each(|polygon| encoder.polygon(&polygon));
+            GEOM_TYPE_POLYGON
+        }
+        _ => return None,
+    };
+    (!encoder.commands.is_empty()).then_some((geom_type, encoder.commands))
+}
+
+/// Encodes geometry commands, with parameters relative to the previous cursor position.
+#[derive(Default)]
+struct CommandEncoder {
+    cursor: (i64, i64),
+    commands: Vec<u32>,
+}
+
+impl CommandEncoder {
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push((id & 0x7) | ((count as u32) << 3));
+    }
+
+    fn params(&mut self, coords: &[(i64, i64)]) {
+        for (x, y) in coords {
+            self.commands.push(zigzag(x - self.cursor.0) as u32);
+            self.commands.push(zigzag(y - self.cursor.1) as u32);
+            self.cursor = (*x, *y);
+        }
+    }
+
+    fn points(&mut self, coords: impl Iterator<Item = impl CoordTrait<T = f64>>) {
+        let coords = coords.map(|coord| tile_coord(&coord)).collect::<Vec<_>>();
+        if !coords.is_empty() {
+            self.command(COMMAND_MOVE_TO, coords.len());
+            self.params(&coords);
+        }
+    }
+
+    fn line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) {
+        let coords = dedup_coords(line_string);
+        if coords.len() >= 2 {
+            self.command(COMMAND_MOVE_TO, 1);
+            self.params(&coords[..1]);
+            self.command(COMMAND_LINE_TO, coords.len() - 1);
+            self.params(&coords[1..]);
+        }
+    }
+
+    fn polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        let Some(exterior) = polygon.exterior() else {
+            return;
+        };
+        if !self.ring(&exterior, true) {
+            return;
+        }
+        for interior in polygon.interiors() {
+            self.ring(&interior, false);
+        }
+    }
+
+    /// Encode a ring, returning whether it was written.
+    fn ring(&mut self, ring: &impl LineStringTrait<T = f64>, exterior: bool) -> bool {
+        let mut coords = dedup_coords(ring);
+        // The ring is closed implicitly by ClosePath
+        if coords.len() > 1 && coords.first() == coords.last() {
+            coords.pop();
+        }
+        if coords.len() < 3 {
+            return false;
+        }
+
+        // Exterior rings must have a positive area and interior rings a negative area
+        let area = coords
+            .iter()
+            .zip(coords.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum::<i64>();
+        if area == 0 {
+            return false;
+        }
+        if (area > 0) != exterior {
+            coords[1..].reverse();
+        }
+
+        self.command(COMMAND_MOVE_TO, 1);
+        self.params(&coords[..1]);
+        self.command(COMMAND_LINE_TO, coords.len() - 1);
+        self.params(&coords[1..]);
+        self.command(COMMAND_CLOSE_PATH, 1);
+        true
+    }
+}
+
+fn tile_coord(coord: &impl CoordTrait<T = f64>) -> (i64, i64) {
+    (coord.x().round() as i64, coord.y().round() as i64)
+}
+
+fn dedup_coords(line_string: &impl LineStringTrait<T = f64>) -> Vec<(i64, i64)> {
+    let mut coords = line_string
+        .coords()
+        .map(|coord| tile_coord(&coord))
+        .collect::<Vec<_>>();
+    coords.dedup();
+    coords
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_tag(out: &mut Vec<u8>, field: u32, wire_type: u32) {
+    write_varint(out, ((field << 3) | wire_type) as u64);
+}
+
+fn write_bytes(out: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_tag(out, field, WIRE_LENGTH_DELIMITED);
+    write_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn write_packed(out: &mut Vec<u8>, field: u32, values: &[u32]) {
+    if values.is_empty() {
+        return;
+    }
+    let mut packed = vec![];
+    for value in values {
+        write_varint(&mut packed, *value as u64);
+    }
+    write_bytes(out, field, &packed);
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::geo::processing::AsMVTGeom;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_as_mvt() {
+        let ctx = SessionContext::new();
+        ctx.register_udaf(AsMVT::new().into());
+        ctx.register_udf(AsMVTGeom::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsMVT(named_struct('id', id, 'name', name, 'geom', ST_AsMVTGeom(ST_GeomFromText(wkt), ST_GeomFromText('LINESTRING(0 0,4096 4096)'))), 'cities', 4096, 'geom', 'id')
+                FROM (VALUES (1, 'a', 'POINT(25 4079)'), (2, 'b', 'POINT(10 10)')) AS t(id, name, wkt)
+                WHERE id = 1;")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let tile = batches[0].column(0).as_binary::<i32>().value(0);
+
+        let mut layer = vec![0x78, 0x02];
+        layer.extend_from_slice(&[0x0a, 0x06]);
+        layer.extend_from_slice(b"cities");
+        // id 1, tags [0, 0], point, MoveTo(1) (25, 17)
+        layer.extend_from_slice(&[
+            0x12, 0x0d, 0x08, 0x01, 0x12, 0x02, 0x00, 0x00, 0x18, 0x01, 0x22, 0x03, 0x09, 0x32,
+            0x22,
+        ]);
+        layer.extend_from_slice(&[0x1a, 0x04]);
+        layer.extend_from_slice(b"name");
+        layer.extend_from_slice(&[0x22, 0x03, 0x0a, 0x01, b'a']);
+        layer.extend_from_slice(&[0x28, 0x80, 0x20]);
+        let mut expected = vec![0x1a, layer.len() as u8];
+        expected.extend_from_slice(&layer);
+        assert_eq!(tile, expected.as_slice());
+    }
+}
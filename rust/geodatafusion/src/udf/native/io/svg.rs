@@ -0,0 +1,289 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArray;
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowResult;
+
+use super::geojson::format_ordinate;
+use super::util::to_simple_geometry;
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::io::util::scalar_arg;
+
+/// The default number of decimal digits written by `ST_AsSVG`, matching PostGIS.
+const DEFAULT_MAX_DECIMAL_DIGITS: usize = 15;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsSVG {
+    signature: Signature,
+}
+
+impl AsSVG {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=3).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsSVG {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsSVG {
+    fn name(&self) -> &str {
+        "st_assvg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_svg_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns SVG path data for a geometry. Points are written as cx/cy attributes, or x/y attributes in relative mode. Lines and polygons are written as path data, using absolute moves by default, or relative moves if rel is 1. Y coordinates are negated, as the SVG Y axis points down. Coordinates are written with at most maxdecimaldigits decimal places (default 15).",
+                "ST_AsSVG(geometry, rel, maxdecimaldigits)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("rel", "integer, 0 for absolute or 1 for relative moves, default 0")
+            .with_argument("maxdecimaldigits", "integer, default 15")
+            .build()
+        }))
+    }
+}
+
+fn as_svg_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let relative = match scalar_arg("ST_AsSVG", &args, 1, &DataType::Int64)? {
+        Some(ScalarValue::Int64(Some(0))) | Some(ScalarValue::Int64(None)) | None => false,
+        Some(ScalarValue::Int64(Some(1))) => true,
+        _ => {
+            return Err(
+                DataFusionError::Execution("ST_AsSVG rel must be 0 or 1".to_string()).into(),
+            );
+        }
+    };
+    let precision = match scalar_arg("ST_AsSVG", &args, 2, &DataType::Int64)? {
+        Some(ScalarValue::Int64(Some(digits))) => usize::try_from(digits).map_err(|_| {
+            DataFusionError::Execution("ST_AsSVG maxdecimaldigits must not be negative".to_string())
+        })?,
+        _ => DEFAULT_MAX_DECIMAL_DIGITS,
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let writer = SvgWriter {
+        relative,
+        precision,
+    };
+    let result = to_svg(&geo_array, &writer)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn to_svg(array: &dyn GeoArrowArray, writer: &SvgWriter) -> GeoArrowResult<StringArray> {
+    downcast_geoarrow_array!(array, _to_svg_impl, writer)
+}
+
+fn _to_svg_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    writer: &SvgWriter,
+) -> GeoArrowResult<StringArray> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let mut out = String::new();
+                writer.write_geometry(&geom?, &mut out)?;
+                Ok(out)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+struct SvgWriter {
+    relative: bool,
+    precision: usize,
+}
+
+impl SvgWriter {
+    fn write_geometry(
+        &self,
+        geom: &impl GeometryTrait<T = f64>,
+        out: &mut String,
+    ) -> GeoArrowResult<()> {
+        use geo_traits::GeometryType::*;
+
+        match geom.as_type() {
+            Point(g) => self.write_point(g, out),
+            LineString(g) => self.write_path(g.coords(), false, out),
+            Polygon(g) => self.write_polygon(g, out),
+            MultiPoint(g) => {
+                for (i, point) in g.points().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    self.write_point(&point, out);
+                }
+            }
+            MultiLineString(g) => {
+                for (i, line_string) in g.line_strings().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    self.write_path(line_string.coords(), false, out);
+                }
+            }
+            MultiPolygon(g) => {
+                for (i, polygon) in g.polygons().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    self.write_polygon(&polygon, out);
+                }
+            }
+            GeometryCollection(g) => {
+                for (i, geometry) in g.geometries().enumerate() {
+                    if i > 0 {
+                        out.push(';');
+                    }
+                    self.write_geometry(&geometry, out)?;
+                }
+            }
+            Rect(_) | Triangle(_) | Line(_) => {
+                self.write_geometry(&to_simple_geometry(geom)?, out)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_point(&self, point: &impl PointTrait<T = f64>, out: &mut String) {
+        if let Some(coord) = point.coord() {
+            let (x_name, y_name) = if self.relative {
+                ("x", "y")
+            } else {
+                ("cx", "cy")
+            };
+            out.push_str(&format!(
+                r#"{x_name}="{}" {y_name}="{}""#,
+                self.format(coord.x()),
+                self.format(-coord.y())
+            ));
+        }
+    }
+
+    fn write_polygon(&self, polygon: &impl PolygonTrait<T = f64>, out: &mut String) {
+        let Some(exterior) = polygon.exterior() else {
+            return;
+        };
+        self.write_path(exterior.coords(), true, out);
+        for interior in polygon.interiors() {
+            out.push(' ');
+            self.write_path(interior.coords(), true, out);
+        }
+    }
+
+    /// Write the path data of a line. The closing point of a ring is replaced by a close path
+    /// command.
+    fn write_path(
+        &self,
+        coords: impl ExactSizeIterator<Item = impl CoordTrait<T = f64>>,
+        close: bool,
+        out: &mut String,
+    ) {
+        let num_coords = if close {
+            coords.len().saturating_sub(1)
+        } else {
+            coords.len()
+        };
+        let mut previous = (0.0, 0.0);
+        for (i, coord) in coords.take(num_coords).enumerate() {
+            let (x, y) = (coord.x(), -coord.y());
+            match i {
+                0 => out.push_str("M "),
+                1 if self.relative => out.push_str(" l "),
+                1 => out.push_str(" L "),
+                _ => out.push(' '),
+            }
+            let (dx, dy) = if self.relative && i > 0 {
+                (x - previous.0, y - previous.1)
+            } else {
+                (x, y)
+            };
+            out.push_str(&self.format(dx));
+            out.push(' ');
+            out.push_str(&self.format(dy));
+            previous = (x, y);
+        }
+        if close && num_coords > 0 {
+            out.push_str(if self.relative { " z" } else { " Z" });
+        }
+    }
+
+    fn format(&self, value: f64) -> String {
+        format_ordinate(value, self.precision)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_as_svg() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsSVG::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_AsSVG(ST_GeomFromText('POLYGON((0 0,0 1,1 1,1 0,0 0))')),
+                    ST_AsSVG(ST_GeomFromText('LINESTRING(1 1,2.5 3,4 2)'), 1),
+                    ST_AsSVG(ST_GeomFromText('POINT(1.23456 2)'), 0, 2);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "M 0 0 L 0 -1 1 -1 1 0 Z"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "M 1 -1 l 1.5 -2 1.5 1"
+        );
+        assert_eq!(
+            batches[0].column(2).as_string::<i32>().value(0),
+            r#"cx="1.23" cy="-2""#
+        );
+    }
+}
@@ -0,0 +1,867 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::BinaryArray;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::*;
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::builder::GeometryBuilder;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::{CoordType, GeometryType, Metadata};
+use wkt::types::Dimension as WktDimension;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::io::util::{quantize, scalar_arg};
+
+const TWKB_POINT: u8 = 1;
+const TWKB_LINESTRING: u8 = 2;
+const TWKB_POLYGON: u8 = 3;
+const TWKB_MULTIPOINT: u8 = 4;
+const TWKB_MULTILINESTRING: u8 = 5;
+const TWKB_MULTIPOLYGON: u8 = 6;
+const TWKB_GEOMETRYCOLLECTION: u8 = 7;
+
+const TWKB_BBOX: u8 = 0x01;
+const TWKB_SIZE: u8 = 0x02;
+const TWKB_IDLIST: u8 = 0x04;
+const TWKB_EXTENDED_DIMS: u8 = 0x08;
+const TWKB_EMPTY: u8 = 0x10;
+
+/// The maximum nesting of geometry collections read from TWKB, which bounds the recursion on
+/// untrusted input.
+const MAX_TWKB_DEPTH: usize = 32;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct AsTWKB {
+    signature: Signature,
+}
+
+impl AsTWKB {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                (1..=6).map(TypeSignature::Any).collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for AsTWKB {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static AS_TWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for AsTWKB {
+    fn name(&self) -> &str {
+        "st_astwkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Binary)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(as_twkb_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(AS_TWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the geometry in TWKB (Tiny Well-Known Binary) format. Coordinates are rounded to the given number of decimal digits, which may be negative for X/Y. Optionally, the encoded size and bounding box of each geometry are included.",
+                "ST_AsTWKB(geometry, precision_xy, precision_z, precision_m, include_size, include_bbox)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("precision_xy", "integer, default 0")
+            .with_argument("precision_z", "integer, default 0")
+            .with_argument("precision_m", "integer, default 0")
+            .with_argument("include_size", "boolean, default false")
+            .with_argument("include_bbox", "boolean, default false")
+            .build()
+        }))
+    }
+}
+
+fn as_twkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let precision_xy = scalar_arg("ST_AsTWKB", &args, 1, &DataType::Int64)?
+        .map(|v| match v {
+            ScalarValue::Int64(Some(v)) if (-7..=7).contains(&v) => Ok(v as i8),
+            _ => Err(DataFusionError::Execution(
+                "ST_AsTWKB precision_xy must be between -7 and 7".to_string(),
+            )),
+        })
+        .transpose()?
+        .unwrap_or(0);
+    let mut precision_zm = [0; 2];
+    for (i, precision) in precision_zm.iter_mut().enumerate() {
+        if let Some(v) = scalar_arg("ST_AsTWKB", &args, 2 + i, &DataType::Int64)? {
+            *precision = match v {
+                ScalarValue::Int64(Some(v)) if (0..=7).contains(&v) => v as u8,
+                _ => {
+                    return Err(DataFusionError::Execution(
+                        "ST_AsTWKB precision_z and precision_m must be between 0 and 7".to_string(),
+                    )
+                    .into());
+                }
+            };
+        }
+    }
+    let include_size = matches!(
+        scalar_arg("ST_AsTWKB", &args, 4, &DataType::Boolean)?,
+        Some(ScalarValue::Boolean(Some(true)))
+    );
+    let include_bbox = matches!(
+        scalar_arg("ST_AsTWKB", &args, 5, &DataType::Boolean)?,
+        Some(ScalarValue::Boolean(Some(true)))
+    );
+
+    let options = TwkbOptions {
+        precision_xy,
+        precision_z: precision_zm[0],
+        precision_m: precision_zm[1],
+        include_size,
+        include_bbox,
+    };
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let result = to_twkb(&geo_array, &options)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+struct TwkbOptions {
+    precision_xy: i8,
+    precision_z: u8,
+    precision_m: u8,
+    include_size: bool,
+    include_bbox: bool,
+}
+
+fn to_twkb(array: &dyn GeoArrowArray, options: &TwkbOptions) -> GeoDataFusionResult<BinaryArray> {
+    downcast_geoarrow_array!(array, _to_twkb_impl, options)
+}
+
+fn _to_twkb_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    options: &TwkbOptions,
+) -> GeoDataFusionResult<BinaryArray> {
+    array
+        .iter()
+        .map(|item| {
+            item.map(|geom| {
+                let geom = geom?;
+                let (has_z, has_m) = dims(geom.dim());
+                let encoder = TwkbEncoder {
+                    options,
+                    has_z,
+                    has_m,
+                };
+                Ok(encoder.encode(&geom)?.0)
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+fn dims(dim: Dimensions) -> (bool, bool) {
+    match dim {
+        Dimensions::Xy | Dimensions::Unknown(2) => (false, false),
+        Dimensions::Xyz | Dimensions::Unknown(3) => (true, false),
+        Dimensions::Xym => (false, true),
+        Dimensions::Xyzm | Dimensions::Unknown(_) => (true, true),
+    }
+}
+
+/// Quantized bounds of each ordinate of a geometry.
+type Bounds = [(i64, i64); 4];
+
+/// Encodes geometries in TWKB with a fixed set of options and dimensions.
+struct TwkbEncoder<'a> {
+    options: &'a TwkbOptions,
+    has_z: bool,
+    has_m: bool,
+}
+
+/// Delta-encoding state for the coordinates of one TWKB geometry.
+struct CoordState<'a> {
+    scales: [f64; 4],
+    num_ordinates: usize,
+    ordinate_indices: [usize; 4],
+    last: [i64; 4],
+    bounds: Option<Bounds>,
+    body: &'a mut Vec<u8>,
+}
+
+impl CoordState<'_> {
+    fn write_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
+        for i in 0..self.num_ordinates {
+            let value = quantize(
+                "ST_AsTWKB",
+                coord.nth_or_panic(self.ordinate_indices[i]),
+                self.scales[i],
+            )?;
+            let delta = value.checked_sub(self.last[i]).ok_or_else(delta_overflow)?;
+            write_varint(self.body, zigzag_encode(delta));
+            self.last[i] = value;
+            let bounds = self.bounds.get_or_insert([(i64::MAX, i64::MIN); 4]);
+            bounds[i] = (bounds[i].0.min(value), bounds[i].1.max(value));
+        }
+        Ok(())
+    }
+
+    fn write_coords(
+        &mut self,
+        coords: impl ExactSizeIterator<Item = impl CoordTrait<T = f64>>,
+    ) -> Result<()> {
+        write_varint(self.body, coords.len() as u64);
+        for coord in coords {
+            self.write_coord(&coord)?;
+        }
+        Ok(())
+    }
+
+    fn write_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) -> Result<()> {
+        match polygon.exterior() {
+            Some(exterior) => {
+                write_varint(self.body, 1 + polygon.num_interiors() as u64);
+                self.write_coords(exterior.coords())?;
+                for interior in polygon.interiors() {
+                    self.write_coords(interior.coords())?;
+                }
+            }
+            None => write_varint(self.body, 0),
+        }
+        Ok(())
+    }
+}
+
+impl TwkbEncoder<'_> {
+    fn num_ordinates(&self) -> usize {
+        2 + self.has_z as usize + self.has_m as usize
+    }
+
+    /// Encode a full TWKB geometry, returning its bytes and the quantized bounds of its
+    /// coordinates.
+    fn encode(&self, geom: &impl GeometryTrait<T = f64>) -> Result<(Vec<u8>, Option<Bounds>)> {
+        use geo_traits::GeometryType::*;
+
+        let xy_scale = 10f64.powi(self.options.precision_xy as i32);
+        let z_scale = 10f64.powi(self.options.precision_z as i32);
+        let m_scale = 10f64.powi(self.options.precision_m as i32);
+        let (scales, ordinate_indices) = match (self.has_z, self.has_m) {
+            (false, false) => ([xy_scale, xy_scale, 0., 0.], [0, 1, 0, 0]),
+            (true, false) => ([xy_scale, xy_scale, z_scale, 0.], [0, 1, 2, 0]),
+            (false, true) => ([xy_scale, xy_scale, m_scale, 0.], [0, 1, 2, 0]),
+            (true, true) => ([xy_scale, xy_scale, z_scale, m_scale], [0, 1, 2, 3]),
+        };
+
+        let mut body = Vec::new();
+        let mut state = CoordState {
+            scales,
+            num_ordinates: self.num_ordinates(),
+            ordinate_indices,
+            last: [0; 4],
+            bounds: None,
+            body: &mut body,
+        };
+
+        let (geometry_type, is_empty) = match geom.as_type() {
+            Point(g) => {
+                if let Some(coord) = g.coord() {
+                    state.write_coord(&coord)?;
+                }
+                (TWKB_POINT, g.coord().is_none())
+            }
+            LineString(g) => {
+                state.write_coords(g.coords())?;
+                (TWKB_LINESTRING, g.num_coords() == 0)
+            }
+            Polygon(g) => {
+                state.write_polygon(g)?;
+                (TWKB_POLYGON, g.exterior().is_none())
+            }
+            MultiPoint(g) => {
+                // Empty points can't be represented in a TWKB multipoint, so they are skipped
+                let points = g.points().collect::<Vec<_>>();
+                let coords = points.iter().filter_map(|p| p.coord()).collect::<Vec<_>>();
+                state.write_coords(coords.into_iter())?;
+                (TWKB_MULTIPOINT, g.num_points() == 0)
+            }
+            MultiLineString(g) => {
+                write_varint(state.body, g.num_line_strings() as u64);
+                for line_string in g.line_strings() {
+                    state.write_coords(line_string.coords())?;
+                }
+                (TWKB_MULTILINESTRING, g.num_line_strings() == 0)
+            }
+            MultiPolygon(g) => {
+                write_varint(state.body, g.num_polygons() as u64);
+                for polygon in g.polygons() {
+                    state.write_polygon(&polygon)?;
+                }
+                (TWKB_MULTIPOLYGON, g.num_polygons() == 0)
+            }
+            GeometryCollection(g) => {
+                write_varint(state.body, g.num_geometries() as u64);
+                for geometry in g.geometries() {
+                    // Each member is a full TWKB geometry with its own header
+                    let (child, child_bounds) = self.encode(&geometry)?;
+                    state.body.extend_from_slice(&child);
+                    if let Some(child_bounds) = child_bounds {
+                        let bounds = state.bounds.get_or_insert([(i64::MAX, i64::MIN); 4]);
+                        for (bound, child_bound) in bounds.iter_mut().zip(child_bounds) {
+                            *bound = (bound.0.min(child_bound.0), bound.1.max(child_bound.1));
+                        }
+                    }
+                }
+                (TWKB_GEOMETRYCOLLECTION, g.num_geometries() == 0)
+            }
+            Rect(g) => {
+                let (min, max) = (g.min(), g.max());
+                let ring = [
+                    (min.x(), min.y()),
+                    (max.x(), min.y()),
+                    (max.x(), max.y()),
+                    (min.x(), max.y()),
+                    (min.x(), min.y()),
+                ]
+                .map(|(x, y)| wkt::types::Coord {
+                    x,
+                    y,
+                    z: self.has_z.then_some(0.),
+                    m: self.has_m.then_some(0.),
+                });
+                write_varint(state.body, 1);
+                state.write_coords(ring.into_iter())?;
+                (TWKB_POLYGON, false)
+            }
+            Triangle(g) => {
+                write_varint(state.body, 1);
+                state.write_coords([g.first(), g.second(), g.third(), g.first()].into_iter())?;
+                (TWKB_POLYGON, false)
+            }
+            Line(g) => {
+                state.write_coords([g.start(), g.end()].into_iter())?;
+                (TWKB_LINESTRING, false)
+            }
+        };
+        let bounds = state.bounds;
+
+        let mut out = Vec::with_capacity(body.len() + 8);
+        out.push(geometry_type | ((zigzag_encode(self.options.precision_xy as i64) as u8) << 4));
+
+        let extended_dims = self.has_z || self.has_m;
+        let mut metadata = 0;
+        if extended_dims {
+            metadata |= TWKB_EXTENDED_DIMS;
+        }
+        if is_empty {
+            metadata |= TWKB_EMPTY;
+        } else {
+            if self.options.include_bbox {
+                metadata |= TWKB_BBOX;
+            }
+            if self.options.include_size {
+                metadata |= TWKB_SIZE;
+            }
+        }
+        out.push(metadata);
+
+        if extended_dims {
+            out.push(
+                self.has_z as u8
+                    | (self.has_m as u8) << 1
+                    | (self.options.precision_z & 0x07) << 2
+                    | (self.options.precision_m & 0x07) << 5,
+            );
+        }
+
+        let mut bbox = Vec::new();
+        if !is_empty && self.options.include_bbox {
+            for (min, max) in bounds.unwrap_or_default().iter().take(self.num_ordinates()) {
+                write_varint(&mut bbox, zigzag_encode(*min));
+                let extent = max.checked_sub(*min).ok_or_else(delta_overflow)?;
+                write_varint(&mut bbox, zigzag_encode(extent));
+            }
+        }
+        if !is_empty && self.options.include_size {
+            write_varint(&mut out, (bbox.len() + body.len()) as u64);
+        }
+        out.extend_from_slice(&bbox);
+        out.extend_from_slice(&body);
+
+        Ok((out, bounds))
+    }
+}
+
+fn delta_overflow() -> DataFusionError {
+    DataFusionError::Execution("TWKB coordinate delta overflows a 64-bit integer".to_string())
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct GeomFromTWKB {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl GeomFromTWKB {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::uniform(
+                1,
+                vec![
+                    DataType::Binary,
+                    DataType::LargeBinary,
+                    DataType::BinaryView,
+                ],
+                Volatility::Immutable,
+            ),
+            coord_type,
+        }
+    }
+}
+
+impl Default for GeomFromTWKB {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static GEOM_FROM_TWKB_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromTWKB {
+    fn name(&self) -> &str {
+        "st_geomfromtwkb"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+        let input_field = &args.arg_fields[0];
+        let metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+        let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
+        Ok(geom_type
+            .to_field(input_field.name(), input_field.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(geom_from_twkb_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_TWKB_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Creates a geometry instance from a TWKB (Tiny Well-Known Binary) geometry representation.",
+                "ST_GeomFromTWKB(twkb)",
+            )
+            .with_argument("twkb", "bytea")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_twkb_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = args.args[0]
+        .cast_to(&DataType::Binary, None)?
+        .into_array(args.number_rows)?;
+    let typ = args.return_field.try_extension_type::<GeometryType>()?;
+    let mut builder = GeometryBuilder::new(typ);
+
+    for twkb in array.as_binary::<i32>() {
+        if let Some(twkb) = twkb {
+            let mut reader = TwkbReader {
+                buf: twkb,
+                pos: 0,
+                depth: 0,
+            };
+            builder.push_geometry(Some(&reader.read_geometry()?))?;
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+/// Decodes TWKB into [`wkt::Wkt`] geometries, which implement the geo-traits.
+struct TwkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    /// The number of geometry collections enclosing the geometry being read
+    depth: usize,
+}
+
+/// The dimensions, scales and delta state of the coordinates of one TWKB geometry.
+struct CoordReader {
+    dim: WktDimension,
+    has_z: bool,
+    has_m: bool,
+    scales: [f64; 4],
+    last: [i64; 4],
+}
+
+impl TwkbReader<'_> {
+    fn read_byte(&mut self) -> Result<u8> {
+        let byte = *self
+            .buf
+            .get(self.pos)
+            .ok_or_else(|| DataFusionError::Execution("Unexpected end of TWKB".to_string()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_byte()?;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DataFusionError::Execution(
+            "Invalid varint in TWKB".to_string(),
+        ))
+    }
+
+    fn read_length(&mut self) -> Result<usize> {
+        let length = self.read_varint()? as usize;
+        // Each element takes at least one byte, which bounds allocations on corrupt input
+        if length > self.buf.len() - self.pos {
+            return Err(DataFusionError::Execution(
+                "Invalid length in TWKB".to_string(),
+            ));
+        }
+        Ok(length)
+    }
+
+    fn read_coord(&mut self, coords: &mut CoordReader) -> Result<wkt::types::Coord<f64>> {
+        let num_ordinates = 2 + coords.has_z as usize + coords.has_m as usize;
+        let mut values = [0.; 4];
+        for (i, value) in values.iter_mut().enumerate().take(num_ordinates) {
+            let delta = zigzag_decode(self.read_varint()?);
+            coords.last[i] = coords.last[i]
+                .checked_add(delta)
+                .ok_or_else(delta_overflow)?;
+            *value = coords.last[i] as f64 / coords.scales[i];
+        }
+        Ok(wkt::types::Coord {
+            x: values[0],
+            y: values[1],
+            z: coords.has_z.then_some(values[2]),
+            m: coords
+                .has_m
+                .then(|| values[if coords.has_z { 3 } else { 2 }]),
+        })
+    }
+
+    fn read_line_string(
+        &mut self,
+        coords: &mut CoordReader,
+    ) -> Result<wkt::types::LineString<f64>> {
+        let num_coords = self.read_length()?;
+        let line_string = (0..num_coords)
+            .map(|_| self.read_coord(coords))
+            .collect::<Result<_>>()?;
+        Ok(wkt::types::LineString::new(line_string, coords.dim))
+    }
+
+    fn read_polygon(&mut self, coords: &mut CoordReader) -> Result<wkt::types::Polygon<f64>> {
+        let num_rings = self.read_length()?;
+        let rings = (0..num_rings)
+            .map(|_| self.read_line_string(coords))
+            .collect::<Result<_>>()?;
+        Ok(wkt::types::Polygon::new(rings, coords.dim))
+    }
+
+    /// Read the number of parts of a multi-geometry, skipping its id list if present.
+    fn read_num_parts(&mut self, has_idlist: bool) -> Result<usize> {
+        let num_parts = self.read_length()?;
+        if has_idlist {
+            for _ in 0..num_parts {
+                self.read_varint()?;
+            }
+        }
+        Ok(num_parts)
+    }
+
+    fn read_geometry(&mut self) -> Result<wkt::Wkt<f64>> {
+        let header = self.read_byte()?;
+        let geometry_type = header & 0x0F;
+        let precision_xy = zigzag_decode((header >> 4) as u64);
+        let metadata = self.read_byte()?;
+
+        let (mut has_z, mut has_m, mut precision_z, mut precision_m) = (false, false, 0, 0);
+        if metadata & TWKB_EXTENDED_DIMS != 0 {
+            let extended = self.read_byte()?;
+            has_z = extended & 0x01 != 0;
+            has_m = extended & 0x02 != 0;
+            precision_z = (extended >> 2) & 0x07;
+            precision_m = (extended >> 5) & 0x07;
+        }
+        let dim = match (has_z, has_m) {
+            (false, false) => WktDimension::XY,
+            (true, false) => WktDimension::XYZ,
+            (false, true) => WktDimension::XYM,
+            (true, true) => WktDimension::XYZM,
+        };
+
+        if metadata & TWKB_SIZE != 0 {
+            self.read_varint()?;
+        }
+        if metadata & TWKB_EMPTY != 0 {
+            return empty_geometry(geometry_type, dim);
+        }
+        if metadata & TWKB_BBOX != 0 {
+            for _ in 0..2 * (2 + has_z as usize + has_m as usize) {
+                self.read_varint()?;
+            }
+        }
+
+        let xy_scale = 10f64.powi(precision_xy as i32);
+        let z_scale = 10f64.powi(precision_z as i32);
+        let m_scale = 10f64.powi(precision_m as i32);
+        let mut coords = CoordReader {
+            dim,
+            has_z,
+            has_m,
+            scales: match (has_z, has_m) {
+                (false, true) => [xy_scale, xy_scale, m_scale, 0.],
+                _ => [xy_scale, xy_scale, z_scale, m_scale],
+            },
+            last: [0; 4],
+        };
+        let has_idlist = metadata & TWKB_IDLIST != 0;
+
+        let geometry = match geometry_type {
+            TWKB_POINT => wkt::Wkt::Point(wkt::types::Point::new(
+                Some(self.read_coord(&mut coords)?),
+                dim,
+            )),
+            TWKB_LINESTRING => wkt::Wkt::LineString(self.read_line_string(&mut coords)?),
+            TWKB_POLYGON => wkt::Wkt::Polygon(self.read_polygon(&mut coords)?),
+            TWKB_MULTIPOINT => {
+                let num_parts = self.read_num_parts(has_idlist)?;
+                let points = (0..num_parts)
+                    .map(|_| {
+                        Ok(wkt::types::Point::new(
+                            Some(self.read_coord(&mut coords)?),
+                            dim,
+                        ))
+                    })
+                    .collect::<Result<_>>()?;
+                wkt::Wkt::MultiPoint(wkt::types::MultiPoint::new(points, dim))
+            }
+            TWKB_MULTILINESTRING => {
+                let num_parts = self.read_num_parts(has_idlist)?;
+                let line_strings = (0..num_parts)
+                    .map(|_| self.read_line_string(&mut coords))
+                    .collect::<Result<_>>()?;
+                wkt::Wkt::MultiLineString(wkt::types::MultiLineString::new(line_strings, dim))
+            }
+            TWKB_MULTIPOLYGON => {
+                let num_parts = self.read_num_parts(has_idlist)?;
+                let polygons = (0..num_parts)
+                    .map(|_| self.read_polygon(&mut coords))
+                    .collect::<Result<_>>()?;
+                wkt::Wkt::MultiPolygon(wkt::types::MultiPolygon::new(polygons, dim))
+            }
+            TWKB_GEOMETRYCOLLECTION => {
+                if self.depth >= MAX_TWKB_DEPTH {
+                    return Err(DataFusionError::Execution(format!(
+                        "TWKB geometry collections are nested more than {MAX_TWKB_DEPTH} deep"
+                    )));
+                }
+                let num_parts = self.read_num_parts(has_idlist)?;
+                self.depth += 1;
+                let geometries = (0..num_parts)
+                    .map(|_| self.read_geometry())
+                    .collect::<Result<_>>();
+                self.depth -= 1;
+                let geometries = geometries?;
+                wkt::Wkt::GeometryCollection(wkt::types::GeometryCollection::new(geometries, dim))
+            }
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid TWKB geometry type {geometry_type}"
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+}
+
+fn empty_geometry(geometry_type: u8, dim: WktDimension) -> Result<wkt::Wkt<f64>> {
+    Ok(match geometry_type {
+        TWKB_POINT => wkt::Wkt::Point(wkt::types::Point::empty(dim)),
+        TWKB_LINESTRING => wkt::Wkt::LineString(wkt::types::LineString::empty(dim)),
+        TWKB_POLYGON => wkt::Wkt::Polygon(wkt::types::Polygon::empty(dim)),
+        TWKB_MULTIPOINT => wkt::Wkt::MultiPoint(wkt::types::MultiPoint::empty(dim)),
+        TWKB_MULTILINESTRING => wkt::Wkt::MultiLineString(wkt::types::MultiLineString::empty(dim)),
+        TWKB_MULTIPOLYGON => wkt::Wkt::MultiPolygon(wkt::types::MultiPolygon::empty(dim)),
+        TWKB_GEOMETRYCOLLECTION => {
+            wkt::Wkt::GeometryCollection(wkt::types::GeometryCollection::empty(dim))
+        }
+        _ => {
+            return Err(DataFusionError::Execution(format!(
+                "Invalid TWKB geometry type {geometry_type}"
+            )));
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsText, GeomFromText};
+
+    #[tokio::test]
+    async fn test_as_twkb() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsTWKB::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        // Matches the PostGIS documentation examples
+        let df = ctx
+            .sql("SELECT ST_AsTWKB(ST_GeomFromText('LINESTRING(1 1,5 5)')), ST_AsTWKB(ST_GeomFromText('LINESTRING(1 1,5 5)'), 0, 0, 0, false, true);")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_binary::<i32>().value(0),
+            &[0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08]
+        );
+        assert_eq!(
+            batches[0].column(1).as_binary::<i32>().value(0),
+            &[
+                0x02, 0x01, 0x02, 0x08, 0x02, 0x08, 0x02, 0x02, 0x02, 0x08, 0x08
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_twkb_round_trip() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsTWKB::new().into());
+        ctx.register_udf(GeomFromTWKB::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql("SELECT ST_AsText(ST_GeomFromTWKB(ST_AsTWKB(ST_GeomFromText('LINESTRING Z(0 0 1,-1.5 1.256 2)'), 2, 1, 0, true, true))), ST_AsText(ST_GeomFromTWKB(ST_AsTWKB(ST_GeomFromText('GEOMETRYCOLLECTION(POINT(1.25 -2.5),POLYGON((0 0,1 0,1 1,0 0)),POINT EMPTY)'), 2)));")
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "LINESTRING Z(0 0 1,-1.5 1.26 2)"
+        );
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "GEOMETRYCOLLECTION(POINT(1.25 -2.5),POLYGON((0 0,1 0,1 1,0 0)),POINT EMPTY)"
+        );
+    }
+
+    #[test]
+    fn test_twkb_nesting_limit() {
+        // A geometry collection containing a geometry collection, and so on
+        let twkb = [TWKB_GEOMETRYCOLLECTION, 0x00, 0x01].repeat(100_000);
+        let mut reader = TwkbReader {
+            buf: &twkb,
+            pos: 0,
+            depth: 0,
+        };
+        let err = reader.read_geometry().unwrap_err();
+        assert!(
+            err.to_string().contains("nested"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_as_twkb_out_of_range() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(AsTWKB::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        for (wkt, message) in [
+            ("POINT(1e300 0)", "out of range"),
+            ("LINESTRING(-9e18 0,9e18 0)", "overflows"),
+        ] {
+            let err = ctx
+                .sql(&format!(
+                    "SELECT ST_AsTWKB(ST_GeomFromText('{wkt}'), 0, 0, 0, false, false);"
+                ))
+                .await
+                .unwrap()
+                .collect()
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string().contains(message),
+                "unexpected error for {wkt}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_twkb_delta_overflow() {
+        // A linestring whose second x coordinate is one past i64::MAX
+        let mut twkb = vec![TWKB_LINESTRING, 0x00];
+        for value in [2, zigzag_encode(i64::MAX), 0, zigzag_encode(1), 0] {
+            write_varint(&mut twkb, value);
+        }
+        let mut reader = TwkbReader {
+            buf: &twkb,
+            pos: 0,
+            depth: 0,
+        };
+        let err = reader.read_geometry().unwrap_err();
+        assert!(
+            err.to_string().contains("overflows"),
+            "unexpected error: {err}"
+        );
+    }
+}
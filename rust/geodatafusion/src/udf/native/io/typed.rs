@@ -0,0 +1,542 @@
+//! Constructors from WKT and WKB that return a concrete geometry type.
+
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::BinaryArray;
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+use geo_traits::GeometryTrait;
+use geoarrow_array::array::{WkbArray, from_arrow_array};
+use geoarrow_array::builder::{
+    GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder, MultiPointBuilder,
+    MultiPolygonBuilder, PointBuilder, PolygonBuilder,
+};
+use geoarrow_array::cast::AsGeoArrowArray;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor};
+use geoarrow_schema::{
+    CoordType, Dimension, GeoArrowType, GeometryCollectionType, GeometryType, LineStringType,
+    Metadata, MultiLineStringType, MultiPointType, MultiPolygonType, PointType, PolygonType,
+};
+
+use super::{GeomFromText, GeomFromWKB};
+use crate::crs::{crs_from_srid, srid_arg};
+use crate::error::GeoDataFusionResult;
+
+/// The input representation of a typed constructor.
+#[derive(Debug, Clone, Copy)]
+enum Encoding {
+    Wkt,
+    Wkb,
+}
+
+impl Encoding {
+    /// The accepted input types, each with an optional SRID.
+    fn signature(self) -> Signature {
+        let input_types = match self {
+            Self::Wkt => [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
+            Self::Wkb => [
+                DataType::Binary,
+                DataType::LargeBinary,
+                DataType::BinaryView,
+            ],
+        };
+        let mut variants = vec![];
+        for input_type in input_types {
+            variants.push(TypeSignature::Exact(vec![input_type.clone()]));
+            variants.push(TypeSignature::Exact(vec![input_type, DataType::Int64]));
+        }
+        Signature::one_of(variants, Volatility::Immutable)
+    }
+
+    /// Parse the input into a [`GeometryType`] array, using `ST_GeomFromText` or
+    /// `ST_GeomFromWKB`.
+    ///
+    /// Only the input argument is passed on, as the SRID is already in the return field and
+    /// `ST_GeomFromWKB` takes no SRID.
+    fn parse(self, mut args: ScalarFunctionArgs, coord_type: CoordType) -> Result<ColumnarValue> {
+        args.args.truncate(1);
+        args.arg_fields.truncate(1);
+        match self {
+            Self::Wkt => ScalarUDFImpl::invoke_with_args(&GeomFromText::new(coord_type), args),
+            Self::Wkb => ScalarUDFImpl::invoke_with_args(&GeomFromWKB::new(coord_type), args),
+        }
+    }
+}
+
+/// The geometry type returned by a typed constructor.
+#[derive(Debug, Clone, Copy)]
+enum TypedGeometry {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl TypedGeometry {
+    fn data_type(
+        self,
+        dim: Dimension,
+        metadata: Arc<Metadata>,
+        coord_type: CoordType,
+    ) -> GeoArrowType {
+        match self {
+            Self::Point => {
+                GeoArrowType::Point(PointType::new(dim, metadata).with_coord_type(coord_type))
+            }
+            Self::LineString => GeoArrowType::LineString(
+                LineStringType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            Self::Polygon => {
+                GeoArrowType::Polygon(PolygonType::new(dim, metadata).with_coord_type(coord_type))
+            }
+            Self::MultiPoint => GeoArrowType::MultiPoint(
+                MultiPointType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            Self::MultiLineString => GeoArrowType::MultiLineString(
+                MultiLineStringType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            Self::MultiPolygon => GeoArrowType::MultiPolygon(
+                MultiPolygonType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+            Self::GeometryCollection => GeoArrowType::GeometryCollection(
+                GeometryCollectionType::new(dim, metadata).with_coord_type(coord_type),
+            ),
+        }
+    }
+
+    fn matches(self, geom: &impl GeometryTrait<T = f64>) -> bool {
+        use geo_traits::GeometryType as G;
+
+        matches!(
+            (self, geom.as_type()),
+            (Self::Point, G::Point(_))
+                | (Self::LineString, G::LineString(_))
+                | (Self::Polygon, G::Polygon(_))
+                | (Self::MultiPoint, G::MultiPoint(_))
+                | (Self::MultiLineString, G::MultiLineString(_))
+                | (Self::MultiPolygon, G::MultiPolygon(_))
+                | (Self::GeometryCollection, G::GeometryCollection(_))
+        )
+    }
+}
+
+macro_rules! impl_typed_constructor_udf {
+    ($struct_name:ident, $udf_name:expr, $function:expr, $aliases:expr, $documentation_name:ident, $encoding:expr, $target:expr, $doc_text:expr, $doc_example:expr) => {
+        #[derive(Debug, Eq, PartialEq, Hash)]
+        pub struct $struct_name {
+            signature: Signature,
+            coord_type: CoordType,
+            aliases: Vec<String>,
+        }
+
+        impl $struct_name {
+            pub fn new(coord_type: CoordType) -> Self {
+                let aliases: &[&str] = &$aliases;
+                Self {
+                    signature: $encoding.signature(),
+                    coord_type,
+                    aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+                }
+            }
+        }
+
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self::new(Default::default())
+            }
+        }
+
+        static $documentation_name: OnceLock<Documentation> = OnceLock::new();
+
+        impl ScalarUDFImpl for $struct_name {
+            fn name(&self) -> &str {
+                $udf_name
+            }
+
+            fn aliases(&self) -> &[String] {
+                &self.aliases
+            }
+
+            fn signature(&self) -> &Signature {
+                &self.signature
+            }
+
+            fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+                Err(DataFusionError::Internal("return_type".to_string()))
+            }
+
+            fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
+                typed_return_field($function, args, $encoding, $target, self.coord_type)
+            }
+
+            fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+                Ok(typed_impl(
+                    $function,
+                    args,
+                    $encoding,
+                    $target,
+                    self.coord_type,
+                )?)
+            }
+
+            fn documentation(&self) -> Option<&Documentation> {
+                Some($documentation_name.get_or_init(|| {
+                    Documentation::builder(DOC_SECTION_OTHER, $doc_text, $doc_example)
+                        .with_argument("input", "text or binary")
+                        .with_argument("srid", "integer SRID value")
+                        .build()
+                }))
+            }
+        }
+    };
+}
+
+impl_typed_constructor_udf!(
+    PointFromText,
+    "st_pointfromtext",
+    "ST_PointFromText",
+    [],
+    POINT_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::Point,
+    "Makes a Point from WKT with the given SRID. Returns NULL if the WKT is not a Point.",
+    "ST_PointFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    LineFromText,
+    "st_linefromtext",
+    "ST_LineFromText",
+    [],
+    LINE_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::LineString,
+    "Makes a LineString from WKT with the given SRID. Returns NULL if the WKT is not a LineString.",
+    "ST_LineFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    PolygonFromText,
+    "st_polygonfromtext",
+    "ST_PolygonFromText",
+    [],
+    POLYGON_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::Polygon,
+    "Makes a Polygon from WKT with the given SRID. Returns NULL if the WKT is not a Polygon.",
+    "ST_PolygonFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    MPointFromText,
+    "st_mpointfromtext",
+    "ST_MPointFromText",
+    [],
+    MPOINT_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::MultiPoint,
+    "Makes a MultiPoint from WKT with the given SRID. Returns NULL if the WKT is not a MultiPoint.",
+    "ST_MPointFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    MLineFromText,
+    "st_mlinefromtext",
+    "ST_MLineFromText",
+    [],
+    MLINE_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::MultiLineString,
+    "Makes a MultiLineString from WKT with the given SRID. Returns NULL if the WKT is not a MultiLineString.",
+    "ST_MLineFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    MPolyFromText,
+    "st_mpolyfromtext",
+    "ST_MPolyFromText",
+    [],
+    MPOLY_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::MultiPolygon,
+    "Makes a MultiPolygon from WKT with the given SRID. Returns NULL if the WKT is not a MultiPolygon.",
+    "ST_MPolyFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    GeomCollFromText,
+    "st_geomcollfromtext",
+    "ST_GeomCollFromText",
+    [],
+    GEOM_COLL_FROM_TEXT_DOC,
+    Encoding::Wkt,
+    TypedGeometry::GeometryCollection,
+    "Makes a GeometryCollection from WKT with the given SRID. Returns NULL if the WKT is not a GeometryCollection.",
+    "ST_GeomCollFromText(WKT, srid)"
+);
+impl_typed_constructor_udf!(
+    PointFromWKB,
+    "st_pointfromwkb",
+    "ST_PointFromWKB",
+    [],
+    POINT_FROM_WKB_DOC,
+    Encoding::Wkb,
+    TypedGeometry::Point,
+    "Makes a Point from WKB with the given SRID. Returns NULL if the WKB is not a Point.",
+    "ST_PointFromWKB(WKB, srid)"
+);
+impl_typed_constructor_udf!(
+    LineFromWKB,
+    "st_linefromwkb",
+    "ST_LineFromWKB",
+    ["st_linestringfromwkb"],
+    LINE_FROM_WKB_DOC,
+    Encoding::Wkb,
+    TypedGeometry::LineString,
+    "Makes a LineString from WKB with the given SRID. Returns NULL if the WKB is not a LineString.",
+    "ST_LineFromWKB(WKB, srid)"
+);
+
+/// The output field of a typed constructor.
+///
+/// The dimension of the output is read from a literal input. As the dimension of a column input
+/// is not known when planning, it is XY, and rows with Z or M values are an error.
+fn typed_return_field(
+    function: &str,
+    args: ReturnFieldArgs,
+    encoding: Encoding,
+    target: TypedGeometry,
+    coord_type: CoordType,
+) -> Result<Arc<Field>> {
+    let input_field = &args.arg_fields[0];
+    let mut metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+
+    if let Some(srid) = srid_arg(function, &args, 1)? {
+        metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+    }
+
+    let dim = match args.scalar_arguments.first() {
+        Some(Some(scalar)) => literal_dimension(scalar, encoding)?.unwrap_or(Dimension::XY),
+        _ => Dimension::XY,
+    };
+
+    // Rows of another geometry type are null
+    Ok(target
+        .data_type(dim, metadata, coord_type)
+        .to_field(input_field.name(), true)
+        .into())
+}
+
+fn literal_dimension(scalar: &ScalarValue, encoding: Encoding) -> Result<Option<Dimension>> {
+    let dim = match (encoding, scalar) {
+        (Encoding::Wkt, scalar) => match scalar.try_as_str().flatten() {
+            Some(text) => wkt::Wkt::<f64>::from_str(text)
+                .map_err(|err| DataFusionError::Execution(format!("Invalid WKT: {err}")))?
+                .dim(),
+            None => return Ok(None),
+        },
+        (
+            Encoding::Wkb,
+            ScalarValue::Binary(Some(buf))
+            | ScalarValue::LargeBinary(Some(buf))
+            | ScalarValue::BinaryView(Some(buf)),
+        ) => {
+            let array = WkbArray::new(BinaryArray::from_iter_values([buf]), Default::default());
+            array
+                .value(0)
+                .map_err(|err| DataFusionError::External(Box::new(err)))?
+                .dim()
+        }
+        (Encoding::Wkb, _) => return Ok(None),
+    };
+    Dimension::try_from(dim)
+        .map(Some)
+        .map_err(|err| DataFusionError::External(Box::new(err)))
+}
+
+fn typed_impl(
+    function: &str,
+    args: ScalarFunctionArgs,
+    encoding: Encoding,
+    target: TypedGeometry,
+    coord_type: CoordType,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
+    let number_rows = args.number_rows;
+
+    let geometry_field = Arc::new(
+        GeometryType::new(to_type.metadata().clone())
+            .with_coord_type(coord_type)
+            .to_field("", true),
+    );
+    let parsed = encoding
+        .parse(
+            ScalarFunctionArgs {
+                return_field: geometry_field.clone(),
+                ..args
+            },
+            coord_type,
+        )?
+        .into_array(number_rows)?;
+    let parsed = from_arrow_array(&parsed, &geometry_field)?;
+
+    let dim = to_type.dimension();
+    let geoms = parsed
+        .as_geometry()
+        .iter()
+        .map(|item| {
+            let Some(geom) = item.transpose()? else {
+                return Ok(None);
+            };
+            if !target.matches(&geom) {
+                return Ok(None);
+            }
+            let geom_dim = Dimension::try_from(geom.dim())?;
+            if Some(geom_dim) != dim {
+                return Err(DataFusionError::Execution(format!(
+                    "{function} found a geometry with dimension {geom_dim:?}, but its output has dimension {dim:?}. The output dimension of a column input is XY, use ST_GeomFromText or ST_GeomFromWKB for Z or M values"
+                ))
+                .into());
+            }
+            Ok(Some(geom))
+        })
+        .collect::<GeoDataFusionResult<Vec<_>>>()?;
+
+    let result: Arc<dyn GeoArrowArray> = match to_type {
+        GeoArrowType::Point(typ) => {
+            Arc::new(PointBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::LineString(typ) => {
+            Arc::new(LineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::Polygon(typ) => {
+            Arc::new(PolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiPoint(typ) => {
+            Arc::new(MultiPointBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiLineString(typ) => {
+            Arc::new(MultiLineStringBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::MultiPolygon(typ) => {
+            Arc::new(MultiPolygonBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        GeoArrowType::GeometryCollection(typ) => {
+            Arc::new(GeometryCollectionBuilder::from_nullable_geometries(&geoms, typ)?.finish())
+        }
+        _ => unreachable!(),
+    };
+    Ok(ColumnarValue::Array(result.to_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::{AsBinary, AsText};
+
+    #[tokio::test]
+    async fn test_typed_from_text() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(PointFromText::default().into());
+        ctx.register_udf(MLineFromText::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_PointFromText('POINT Z(1 2 3)', 4326) AS point,
+                    ST_AsText(ST_MLineFromText('MULTILINESTRING((1 2,3 4))')) AS mline,
+                    ST_PointFromText('LINESTRING(1 2,3 4)') AS not_point;",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+
+        let point_type = schema.field(0).extension_type::<PointType>();
+        assert_eq!(point_type.dimension(), Dimension::XYZ);
+        assert_eq!(point_type.metadata().crs(), &crs_from_srid(4326));
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "MULTILINESTRING((1 2,3 4))"
+        );
+        assert!(batches[0].column(2).is_null(0));
+    }
+
+    #[tokio::test]
+    async fn test_line_from_wkb() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(LineFromWKB::default().into());
+        ctx.register_udf(AsText::new().into());
+
+        // LINESTRING(1 2,3 4)
+        let wkb =
+            "X'010200000002000000000000000000F03F000000000000004000000000000008400000000000001040'";
+        let df = ctx
+            .sql(&format!(
+                "SELECT ST_LinestringFromWKB({wkb}, 4326) AS line,
+                    ST_AsText(ST_LinestringFromWKB({wkb}));"
+            ))
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        let line_type = schema.field(0).extension_type::<LineStringType>();
+        assert_eq!(line_type.dimension(), Dimension::XY);
+        assert_eq!(line_type.metadata().crs(), &crs_from_srid(4326));
+        assert_eq!(
+            batches[0].column(1).as_string::<i32>().value(0),
+            "LINESTRING(1 2,3 4)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_typed_from_column() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(PointFromText::default().into());
+        ctx.register_udf(PointFromWKB::default().into());
+        ctx.register_udf(AsBinary::new().into());
+        ctx.register_udf(AsText::new().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_PointFromText(w) AS point,
+                    ST_PointFromWKB(ST_AsBinary(ST_GeomFromText(w)), 4326) AS point_wkb,
+                    ST_AsText(ST_PointFromText(w))
+                FROM (VALUES ('POINT(4 5)'), ('LINESTRING(1 2,3 4)')) AS t(w);",
+            )
+            .await
+            .unwrap();
+        let batches = df.collect().await.unwrap();
+        let schema = batches[0].schema();
+        for field in &schema.fields()[..2] {
+            let point_type = field.extension_type::<PointType>();
+            assert_eq!(point_type.dimension(), Dimension::XY);
+        }
+        let text = batches[0].column(2).as_string::<i32>();
+        assert_eq!(text.value(0), "POINT(4 5)");
+        assert!(text.is_null(1));
+
+        // A column input is read as XY, so Z values are an error rather than dropped
+        let err = ctx
+            .sql("SELECT ST_PointFromText(w) FROM (VALUES ('POINT Z(1 2 3)')) AS t(w);")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("ST_PointFromText found a geometry with dimension XYZ"),
+            "unexpected error: {err}"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Helpers shared by the geometry input and output functions.
+
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion::scalar::ScalarValue;
+use geo_traits::GeometryTrait;
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+use geoarrow_schema::error::GeoArrowResult;
+
+/// Cast the scalar option argument of `function` at `index` to `data_type`, if it was passed.
+pub(crate) fn scalar_arg(
+    function: &str,
+    args: &ScalarFunctionArgs,
+    index: usize,
+    data_type: &DataType,
+) -> Result<Option<ScalarValue>> {
+    match args.args.get(index) {
+        None => Ok(None),
+        Some(ColumnarValue::Scalar(scalar)) => Ok(Some(scalar.cast_to(data_type)?)),
+        Some(ColumnarValue::Array(_)) => Err(DataFusionError::NotImplemented(format!(
+            "Vectorized {function} options not yet implemented"
+        ))),
+    }
+}
+
+/// Convert the geometry types without a GML, KML or SVG element to a polygon or linestring.
+pub(super) fn to_simple_geometry(
+    geom: &impl GeometryTrait<T = f64>,
+) -> GeoArrowResult<geo::Geometry> {
+    Ok(match geometry_to_geo(geom)? {
+        geo::Geometry::Rect(rect) => rect.to_polygon().into(),
+        geo::Geometry::Triangle(triangle) => triangle.to_polygon().into(),
+        geo::Geometry::Line(line) => geo::LineString::from(line).into(),
+        geom => geom,
+    })
+}
+
+/// Quantize an ordinate to an integer at the given scale, checking that it fits in an `i64`.
+pub(super) fn quantize(function: &str, value: f64, scale: f64) -> Result<i64> {
+    let quantized = (value * scale).round();
+    // i64::MAX converts to 2^63, which is itself out of range
+    if quantized.is_finite() && quantized >= i64::MIN as f64 && quantized < i64::MAX as f64 {
+        Ok(quantized as i64)
+    } else {
+        Err(DataFusionError::Execution(format!(
+            "{function} coordinate {value} is out of range at this precision"
+        )))
+    }
+}
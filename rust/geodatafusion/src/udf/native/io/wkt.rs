@@ -5,13 +5,14 @@ use datafusion::error::{DataFusionError, Result};
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
     ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
-    Volatility,
+    TypeSignature, Volatility,
 };
 use geoarrow_array::GeoArrowArray;
 use geoarrow_array::array::{LargeWktArray, WktArray, WktViewArray, from_arrow_array};
 use geoarrow_array::cast::{from_wkt, to_wkt};
 use geoarrow_schema::{CoordType, GeoArrowType, GeometryType, Metadata, WktType};
 
+use crate::crs::{crs_from_srid, srid_arg};
 use crate::data_types::any_single_geometry_type_input;
 use crate::error::GeoDataFusionResult;
 
@@ -92,19 +93,20 @@ pub struct GeomFromText {
 
 impl GeomFromText {
     pub fn new(coord_type: CoordType) -> Self {
+        let mut variants = vec![];
+        for text_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            variants.push(TypeSignature::Exact(vec![text_type.clone()]));
+            variants.push(TypeSignature::Exact(vec![text_type, DataType::Int64]));
+        }
         Self {
-            signature: Signature::uniform(
-                1,
-                vec![DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View],
-                Volatility::Immutable,
-            ),
+            signature: Signature::one_of(variants, Volatility::Immutable),
             coord_type,
             aliases: vec!["st_geometryfromtext".to_string(), "st_wkttosql".to_string()],
         }
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
-        let array = &ColumnarValue::values_to_arrays(&args.args)?[0];
+        let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
         let field = &args.arg_fields[0];
         let to_type = GeoArrowType::from_arrow_field(args.return_field.as_ref())?;
         let geom_arr = match field.data_type() {
@@ -154,7 +156,12 @@ impl ScalarUDFImpl for GeomFromText {
 
     fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<Arc<Field>> {
         let input_field = &args.arg_fields[0];
-        let metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+        let mut metadata = Arc::new(Metadata::try_from(input_field.as_ref())?);
+
+        if let Some(srid) = srid_arg("ST_GeomFromText", &args, 1)? {
+            metadata = Arc::new(Metadata::new(crs_from_srid(srid), None));
+        }
+
         let geom_type = GeometryType::new(metadata).with_coord_type(self.coord_type);
         Ok(geom_type
             .to_field(input_field.name(), input_field.is_nullable())
@@ -169,10 +176,11 @@ impl ScalarUDFImpl for GeomFromText {
         Some(GEOM_FROM_TEXT_DOC.get_or_init(|| {
             Documentation::builder(
                 DOC_SECTION_OTHER,
-                "Constructs a geometry object from the OGC Well-Known text representation.",
-                "ST_GeomFromText(text)",
+                "Constructs a geometry object from the OGC Well-Known text representation. If SRID is given, it is stored as the CRS of the output.",
+                "ST_GeomFromText(text) or ST_GeomFromText(text, 4326)",
             )
             .with_argument("g1", "geometry")
+            .with_argument("srid", "integer SRID value")
             .build()
         }))
     }
@@ -235,4 +243,33 @@ mod test {
 
         sql_df.show().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_from_text_srid() {
+        let ctx = SessionContext::new();
+
+        ctx.register_udf(GeomFromText::new(CoordType::Separated).into());
+
+        let sql_df = ctx
+            .sql(r#"SELECT ST_GeomFromText('POINT(30 10)', 4326);"#)
+            .await
+            .unwrap();
+
+        let output_batches = sql_df.collect().await.unwrap();
+        let output_schema = output_batches[0].schema();
+        let geom_type = output_schema.field(0).extension_type::<GeometryType>();
+        assert_eq!(
+            geom_type.metadata().crs(),
+            &Crs::from_authority_code("EPSG:4326".to_string())
+        );
+
+        let err = ctx
+            .sql("SELECT ST_GeomFromText('POINT(30 10)', srid) FROM (VALUES (4326)) AS t(srid);")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err.find_root(), DataFusionError::Plan(_)),
+            "unexpected error: {err}"
+        );
+    }
 }
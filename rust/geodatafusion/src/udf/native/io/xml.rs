@@ -0,0 +1,145 @@
+//! A minimal XML element tree, shared by the GML and KML readers, and helpers for the writers.
+
+use datafusion::error::{DataFusionError, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+
+/// The maximum nesting of XML elements, which bounds the recursion of the GML and KML readers,
+/// and of dropping the element tree, on untrusted input.
+const MAX_XML_DEPTH: usize = 128;
+
+/// An XML element, with namespace prefixes stripped from element and attribute names.
+#[derive(Debug, Default)]
+pub(super) struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlElement>,
+    text: String,
+}
+
+impl XmlElement {
+    /// Parse the root element of an XML document.
+    pub(super) fn parse(xml: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut stack: Vec<XmlElement> = vec![];
+        loop {
+            match reader.read_event().map_err(xml_error)? {
+                Event::Start(start) => {
+                    if stack.len() >= MAX_XML_DEPTH {
+                        return Err(DataFusionError::Execution(format!(
+                            "Invalid XML: elements are nested more than {MAX_XML_DEPTH} deep"
+                        )));
+                    }
+                    stack.push(Self::from_start(&start)?)
+                }
+                Event::Empty(start) => {
+                    let element = Self::from_start(&start)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop().ok_or_else(|| {
+                        DataFusionError::Execution("Invalid XML: unexpected end tag".to_string())
+                    })?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&text.decode().map_err(xml_error)?);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&data.decode().map_err(xml_error)?);
+                    }
+                }
+                Event::Eof => {
+                    return Err(DataFusionError::Execution(
+                        "Invalid XML: missing root element".to_string(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn from_start(start: &BytesStart) -> Result<Self> {
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute.map_err(xml_error)?;
+                let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).to_string();
+                let value = attribute.unescape_value().map_err(xml_error)?.to_string();
+                Ok((key, value))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).to_string(),
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    /// The local name of the element.
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the attribute with the given local name.
+    pub(super) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub(super) fn children(&self) -> impl Iterator<Item = &XmlElement> {
+        self.children.iter()
+    }
+
+    /// The child elements with the given local name.
+    pub(super) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// The first child element with the given local name.
+    pub(super) fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// The text content of the element, excluding that of its children.
+    pub(super) fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Validate a namespace prefix argument, returning it with the trailing colon, or an empty
+/// string to write unqualified elements.
+pub(super) fn namespace_prefix(function: &str, prefix: &str) -> Result<String> {
+    if prefix.is_empty() {
+        return Ok(String::new());
+    }
+    let mut chars = prefix.chars();
+    let valid_start = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_');
+    if valid_start && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.')) {
+        Ok(format!("{prefix}:"))
+    } else {
+        Err(DataFusionError::Execution(format!(
+            "{function} namespace prefix {prefix:?} is not a valid XML name"
+        )))
+    }
+}
+
+fn xml_error(err: impl std::fmt::Display) -> DataFusionError {
+    DataFusionError::Execution(format!("Invalid XML: {err}"))
+}
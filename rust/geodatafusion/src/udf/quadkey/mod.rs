@@ -0,0 +1,18 @@
+//! Bing Maps quadkey and XYZ tile functions, on the Web Mercator tile grid.
+
+#[allow(clippy::module_inception)]
+mod quadkey;
+mod quadkey_to_envelope;
+mod tile;
+mod util;
+
+pub use quadkey::QuadKey;
+pub use quadkey_to_envelope::QuadKeyToEnvelope;
+pub use tile::{TileX, TileY};
+
+pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(QuadKey::default().into());
+    session_context.register_udf(QuadKeyToEnvelope::default().into());
+    session_context.register_udf(TileX::default().into());
+    session_context.register_udf(TileY::default().into());
+}
@@ -0,0 +1,157 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::StringViewBuilder;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowError;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::quadkey::util::{lng_lat_to_tile, zoom_arg};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct QuadKey {
+    signature: Signature,
+}
+
+impl QuadKey {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for QuadKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for QuadKey {
+    fn name(&self) -> &str {
+        "quadkey"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8View)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(quadkey_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the Bing Maps quadkey of the Web Mercator tile at the given zoom level that contains a point. The point is expected to be in longitude/latitude order, and latitudes beyond ±85.05112878 are clamped to the edge of the grid. Empty points return NULL.",
+                "quadkey(point, zoom)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("zoom", "integer between 1 and 23")
+            .build()
+        }))
+    }
+}
+
+fn quadkey_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let zoom = zoom_arg(&args, 1, 1, "quadkey")?;
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let mut builder = StringViewBuilder::with_capacity(array.len());
+    quadkey(&geo_array, zoom, &mut builder)?;
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+fn quadkey(
+    array: &dyn GeoArrowArray,
+    zoom: u8,
+    builder: &mut StringViewBuilder,
+) -> GeoDataFusionResult<()> {
+    downcast_geoarrow_array!(array, _quadkey_impl, zoom, builder)
+}
+
+fn _quadkey_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    zoom: u8,
+    builder: &mut StringViewBuilder,
+) -> GeoDataFusionResult<()> {
+    for item in array.iter() {
+        let Some(geom) = item.transpose()? else {
+            builder.append_null();
+            continue;
+        };
+        let GeometryType::Point(point) = geom.as_type() else {
+            return Err(GeoArrowError::IncorrectGeometryType(
+                "quadkey only supports Point geometries".to_string(),
+            )
+            .into());
+        };
+        match point.coord() {
+            Some(coord) => builder.append_value(lng_lat_to_quadkey(coord.x(), coord.y(), zoom)),
+            None => builder.append_null(),
+        }
+    }
+    Ok(())
+}
+
+/// The quadkey of the tile containing a longitude/latitude point.
+fn lng_lat_to_quadkey(lng: f64, lat: f64, zoom: u8) -> String {
+    let (tile_x, tile_y) = lng_lat_to_tile(lng, lat, zoom);
+
+    (1..=zoom)
+        .rev()
+        .map(|level| {
+            let mask = 1 << (level - 1);
+            let mut digit = b'0';
+            if tile_x & mask != 0 {
+                digit += 1;
+            }
+            if tile_y & mask != 0 {
+                digit += 2;
+            }
+            digit as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_quadkey() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(QuadKey::default().into());
+        ctx.register_udf(Point::default().into());
+
+        // Tile x=3, y=5 at zoom 3, from the Bing Maps tile system documentation
+        let df = ctx
+            .sql("SELECT quadkey(ST_Point(-22.5, -50), 3), quadkey(ST_Point(0, 90), 2);")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(batches[0].column(0).as_string_view().value(0), "213");
+        assert_eq!(batches[0].column(1).as_string_view().value(0), "10");
+    }
+}
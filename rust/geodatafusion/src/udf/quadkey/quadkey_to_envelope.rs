@@ -0,0 +1,210 @@
+use std::f64::consts::PI;
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::StringArrayType;
+use arrow_array::cast::AsArray;
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::array::PolygonArray;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PolygonType};
+
+use crate::crs::crs_from_srid;
+use crate::error::GeoDataFusionResult;
+use crate::udf::quadkey::util::MAX_ZOOM;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct QuadKeyToEnvelope {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl QuadKeyToEnvelope {
+    pub fn new(coord_type: CoordType) -> Self {
+        let mut variants = vec![];
+        for input_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            variants.push(TypeSignature::Exact(vec![input_type]));
+        }
+        Self {
+            signature: Signature::one_of(variants, Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for QuadKeyToEnvelope {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for QuadKeyToEnvelope {
+    fn name(&self) -> &str {
+        "quadkey_to_envelope"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        let metadata = Arc::new(Metadata::new(crs_from_srid(4326), None));
+        let output_type =
+            PolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(quadkey_to_envelope_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the longitude/latitude bounds of the Web Mercator tile of a Bing Maps quadkey as a polygon, with the EPSG:4326 CRS.",
+                "quadkey_to_envelope(quadkey)",
+            )
+            .with_argument("quadkey", "text")
+            .build()
+        }))
+    }
+}
+
+fn quadkey_to_envelope_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(&args.args)?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let polygon_arr = match array.data_type() {
+        DataType::Utf8 => build_polygon_arr(typ, &array.as_string::<i32>()),
+        DataType::LargeUtf8 => build_polygon_arr(typ, &array.as_string::<i64>()),
+        DataType::Utf8View => build_polygon_arr(typ, &array.as_string_view()),
+        _ => unreachable!(),
+    }?;
+
+    Ok(ColumnarValue::Array(polygon_arr.into_array_ref()))
+}
+
+fn build_polygon_arr<'a>(
+    typ: PolygonType,
+    array: &impl StringArrayType<'a>,
+) -> GeoDataFusionResult<PolygonArray> {
+    let mut builder = PolygonBuilder::with_capacity(typ, Default::default());
+    for s in array.iter() {
+        if let Some(s) = s {
+            let (tile_x, tile_y, zoom) = quadkey_to_tile(s)?;
+            let size = (1u32 << zoom) as f64;
+            let (min_x, max_x) = (tile_lng(tile_x, size), tile_lng(tile_x + 1, size));
+            let (min_y, max_y) = (tile_lat(tile_y + 1, size), tile_lat(tile_y, size));
+            // Same ring order as ST_GeomFromGeoHash
+            let polygon = geo::Polygon::new(
+                geo::LineString::from(vec![
+                    (min_x, min_y),
+                    (min_x, max_y),
+                    (max_x, max_y),
+                    (max_x, min_y),
+                    (min_x, min_y),
+                ]),
+                vec![],
+            );
+            builder.push_polygon(Some(&polygon))?;
+        } else {
+            builder.push_polygon(None::<&geo::Polygon>)?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// The tile x, tile y and zoom level of a quadkey.
+fn quadkey_to_tile(quadkey: &str) -> Result<(u32, u32, u8)> {
+    if quadkey.is_empty() || quadkey.len() > MAX_ZOOM as usize {
+        return Err(DataFusionError::Execution(format!(
+            "Invalid quadkey '{quadkey}': must have between 1 and {MAX_ZOOM} digits"
+        )));
+    }
+    let (mut tile_x, mut tile_y) = (0, 0);
+    for digit in quadkey.bytes() {
+        let digit = match digit {
+            b'0'..=b'3' => (digit - b'0') as u32,
+            _ => {
+                return Err(DataFusionError::Execution(format!(
+                    "Invalid quadkey '{quadkey}': digits must be between 0 and 3"
+                )));
+            }
+        };
+        tile_x = (tile_x << 1) | (digit & 1);
+        tile_y = (tile_y << 1) | (digit >> 1);
+    }
+    Ok((tile_x, tile_y, quadkey.len() as u8))
+}
+
+fn tile_lng(tile_x: u32, size: f64) -> f64 {
+    tile_x as f64 / size * 360.0 - 180.0
+}
+
+fn tile_lat(tile_y: u32, size: f64) -> f64 {
+    (PI * (1.0 - 2.0 * tile_y as f64 / size))
+        .sinh()
+        .atan()
+        .to_degrees()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::bounding_box::{XMax, XMin, YMax, YMin};
+
+    #[tokio::test]
+    async fn test_quadkey_to_envelope() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(QuadKeyToEnvelope::default().into());
+        ctx.register_udf(XMin.into());
+        ctx.register_udf(XMax.into());
+        ctx.register_udf(YMin.into());
+        ctx.register_udf(YMax.into());
+
+        let df = ctx
+            .sql(
+                "SELECT ST_XMin(quadkey_to_envelope('213')), ST_XMax(quadkey_to_envelope('213')),
+                    ST_YMin(quadkey_to_envelope('213')), ST_YMax(quadkey_to_envelope('213'));",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let batch = &batches[0];
+        let value = |i: usize| batch.column(i).as_primitive::<Float64Type>().value(0);
+        assert_eq!(value(0), -45.0);
+        assert_eq!(value(1), 0.0);
+        assert!((value(2) - -66.51326044311186).abs() < 1e-9);
+        assert!((value(3) - -40.97989806962013).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_quadkey_to_envelope_invalid() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(QuadKeyToEnvelope::default().into());
+
+        let df = ctx.sql("SELECT quadkey_to_envelope('214');").await.unwrap();
+        assert!(df.collect().await.is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::Int64Array;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo_traits::RectTrait;
+use geo_traits::to_geo::ToGeoCoord;
+use geoarrow_array::GeoArrowArrayAccessor;
+use geoarrow_array::array::from_arrow_array;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::bounding_box::util::bounding_rect;
+use crate::udf::quadkey::util::{lng_lat_to_tile, zoom_arg};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct TileX {
+    signature: Signature,
+}
+
+impl TileX {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for TileX {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_X_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileX {
+    fn name(&self) -> &str {
+        "tile_x"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tile_impl(args, "tile_x", |(x, _)| x)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_X_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the column of the Web Mercator (XYZ) tile at the given zoom level that contains the center of the bounding box of a geometry. The geometry is expected to be in longitude/latitude order. Useful with tile_y as a key to partition COPY TO output, for example into tiles/z=.../x=.../y=... directories. Returns NULL for empty geometries.",
+                "tile_x(geom, zoom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zoom", "integer between 0 and 23")
+            .build()
+        }))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct TileY {
+    signature: Signature,
+}
+
+impl TileY {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(2)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for TileY {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TILE_Y_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileY {
+    fn name(&self) -> &str {
+        "tile_y"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(tile_impl(args, "tile_y", |(_, y)| y)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_Y_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the row of the Web Mercator (XYZ) tile at the given zoom level that contains the center of the bounding box of a geometry, counted from the north. The geometry is expected to be in longitude/latitude order, and latitudes beyond ±85.05112878 are clamped to the edge of the grid. Returns NULL for empty geometries.",
+                "tile_y(geom, zoom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zoom", "integer between 0 and 23")
+            .build()
+        }))
+    }
+}
+
+fn tile_impl(
+    args: ScalarFunctionArgs,
+    function_name: &str,
+    component: fn((u32, u32)) -> u32,
+) -> GeoDataFusionResult<ColumnarValue> {
+    let zoom = zoom_arg(&args, 1, 0, function_name)?;
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let rect_array = bounding_rect(&geo_array, false)?;
+
+    let result = rect_array
+        .iter()
+        .map(|rect| {
+            let Some(rect) = rect else {
+                return Ok(None);
+            };
+            let rect = rect?;
+            let (min, max) = (rect.min().to_coord(), rect.max().to_coord());
+            // The bounding box of an empty geometry is inverted
+            if min.x > max.x || min.y > max.y {
+                return Ok(None);
+            }
+            let tile = lng_lat_to_tile((min.x + max.x) / 2.0, (min.y + max.y) / 2.0, zoom);
+            Ok(Some(component(tile) as i64))
+        })
+        .collect::<GeoDataFusionResult<Int64Array>>()?;
+
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+    use crate::udf::native::io::GeomFromText;
+
+    #[tokio::test]
+    async fn test_tile() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(TileX::default().into());
+        ctx.register_udf(TileY::default().into());
+        ctx.register_udf(Point::default().into());
+        ctx.register_udf(GeomFromText::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT tile_x(ST_Point(-22.5, -50), 3), tile_y(ST_Point(-22.5, -50), 3),
+                    tile_x(ST_GeomFromText('LINESTRING(-1 -1,1 1)'), 1),
+                    tile_y(ST_GeomFromText('LINESTRING(-1 -1,1 1)'), 1);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let value = |i: usize| batches[0].column(i).as_primitive::<Int64Type>().value(0);
+        assert_eq!((value(0), value(1)), (3, 5));
+        assert_eq!((value(2), value(3)), (1, 1));
+    }
+}
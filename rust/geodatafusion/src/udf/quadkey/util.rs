@@ -0,0 +1,53 @@
+use std::f64::consts::PI;
+
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion::scalar::ScalarValue;
+
+/// The minimum latitude of the Web Mercator tile grid.
+const MIN_LATITUDE: f64 = -85.05112878;
+
+/// The maximum latitude of the Web Mercator tile grid.
+const MAX_LATITUDE: f64 = 85.05112878;
+
+/// The finest zoom level of a quadkey.
+pub(super) const MAX_ZOOM: u8 = 23;
+
+/// The zoom level scalar argument at `index`, between `min_zoom` and [`MAX_ZOOM`].
+pub(super) fn zoom_arg(
+    args: &ScalarFunctionArgs,
+    index: usize,
+    min_zoom: u8,
+    function_name: &str,
+) -> Result<u8> {
+    match &args.args[index] {
+        ColumnarValue::Scalar(scalar) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(zoom))
+                if (min_zoom as i64..=MAX_ZOOM as i64).contains(&zoom) =>
+            {
+                Ok(zoom as u8)
+            }
+            _ => Err(DataFusionError::Execution(format!(
+                "{function_name} zoom must be between {min_zoom} and {MAX_ZOOM}"
+            ))),
+        },
+        ColumnarValue::Array(_) => Err(DataFusionError::NotImplemented(format!(
+            "Vectorized {function_name} zoom not yet implemented"
+        ))),
+    }
+}
+
+/// The x and y of the Web Mercator tile of the given zoom level containing a longitude/latitude
+/// point.
+///
+/// Latitudes beyond the edge of the grid are clamped to it.
+pub(super) fn lng_lat_to_tile(lng: f64, lat: f64, zoom: u8) -> (u32, u32) {
+    let size = (1u32 << zoom) as f64;
+    let lat = lat.clamp(MIN_LATITUDE, MAX_LATITUDE).to_radians();
+    let x = (lng + 180.0) / 360.0;
+    let y = 0.5 - (lat.tan() + 1.0 / lat.cos()).ln() / (2.0 * PI);
+    let tile_x = (x * size).floor().clamp(0.0, size - 1.0) as u32;
+    let tile_y = (y * size).floor().clamp(0.0, size - 1.0) as u32;
+    (tile_x, tile_y)
+}
@@ -0,0 +1,196 @@
+//! S2 cell ids, following the reference implementation in the S2 Geometry library.
+//!
+//! A cell id packs the cube face in its top 3 bits, followed by the position of the cell along
+//! the Hilbert curve of that face, 2 bits per level, and a trailing 1 bit that marks the level.
+
+/// The finest S2 cell level.
+pub(super) const MAX_LEVEL: u8 = 30;
+
+/// The number of positions along each axis of a face at the finest level.
+const MAX_SIZE: u32 = 1 << MAX_LEVEL;
+
+/// Hilbert curve orientation bit that swaps the i and j axes.
+const SWAP_MASK: u8 = 1;
+
+/// Hilbert curve orientation bit that inverts the i and j axes.
+const INVERT_MASK: u8 = 2;
+
+/// The position of each (i, j) quadrant along the Hilbert curve, by orientation.
+const IJ_TO_POS: [[u8; 4]; 4] = [[0, 1, 3, 2], [0, 3, 1, 2], [2, 3, 1, 0], [2, 1, 3, 0]];
+
+/// The (i, j) quadrant at each position along the Hilbert curve, by orientation.
+const POS_TO_IJ: [[u8; 4]; 4] = [[0, 1, 3, 2], [0, 2, 3, 1], [3, 2, 0, 1], [3, 1, 0, 2]];
+
+/// The change of orientation at each position along the Hilbert curve.
+const POS_TO_ORIENTATION: [u8; 4] = [SWAP_MASK, 0, 0, INVERT_MASK | SWAP_MASK];
+
+/// The id of the cell of the given level that contains a longitude/latitude point.
+pub(super) fn cell_id_from_lng_lat(lng: f64, lat: f64, level: u8) -> u64 {
+    let (lng, lat) = (lng.to_radians(), lat.to_radians());
+    let xyz = [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()];
+    let (face, u, v) = xyz_to_face_uv(xyz);
+    let i = st_to_ij(uv_to_st(u));
+    let j = st_to_ij(uv_to_st(v));
+    parent(face_ij_to_leaf(face, i, j), level)
+}
+
+/// The level of a cell id, or `None` if the id is not a valid cell.
+pub(super) fn cell_level(id: u64) -> Option<u8> {
+    if id == 0 || (id >> 61) > 5 {
+        return None;
+    }
+    let trailing_zeros = id.trailing_zeros();
+    if !trailing_zeros.is_multiple_of(2) {
+        return None;
+    }
+    Some(MAX_LEVEL - (trailing_zeros / 2) as u8)
+}
+
+/// The six cells of level 0, one per cube face.
+pub(super) fn face_cells() -> impl Iterator<Item = u64> {
+    (0..6u64).map(|face| (face << 61) | (1 << 60))
+}
+
+/// The four cells of the next level contained in a cell, in Hilbert curve order.
+pub(super) fn children(id: u64) -> [u64; 4] {
+    let lsb = id & id.wrapping_neg();
+    let first = id - lsb + (lsb >> 2);
+    let step = lsb >> 1;
+    [first, first + step, first + 2 * step, first + 3 * step]
+}
+
+/// The boundary of a cell as a polygon of longitude/latitude coordinates.
+///
+/// The vertices are joined by straight lines in longitude/latitude space rather than by
+/// geodesics. Longitudes are unwrapped from the first vertex, so cells crossing the antimeridian
+/// extend beyond ±180 instead of being split, and a vertex at a pole is replaced by the pole's
+/// points at the longitudes of its two neighbours.
+pub(super) fn cell_polygon(id: u64) -> geo::Polygon {
+    let level = cell_level(id).expect("valid cell id");
+    let (face, i, j) = to_face_ij(id);
+    let size = 1 << (MAX_LEVEL - level);
+    let (i0, j0) = (i & !(size - 1), j & !(size - 1));
+    let vertices = [
+        (i0, j0),
+        (i0 + size, j0),
+        (i0 + size, j0 + size),
+        (i0, j0 + size),
+    ]
+    .map(|(i, j)| {
+        let u = st_to_uv(i as f64 / MAX_SIZE as f64);
+        let v = st_to_uv(j as f64 / MAX_SIZE as f64);
+        let [x, y, z] = face_uv_to_xyz(face, u, v);
+        let lat = z.atan2(x.hypot(y)).to_degrees();
+        let lng = (x != 0.0 || y != 0.0).then(|| y.atan2(x).to_degrees());
+        (lng, lat)
+    });
+
+    let mut coords: Vec<geo::Coord> = vec![];
+    let mut push = |lng: f64, lat: f64| {
+        let lng = match coords.first() {
+            Some(first) if lng - first.x > 180.0 => lng - 360.0,
+            Some(first) if first.x - lng > 180.0 => lng + 360.0,
+            _ => lng,
+        };
+        coords.push(geo::coord! { x: lng, y: lat });
+    };
+    for (index, (lng, lat)) in vertices.iter().enumerate() {
+        match lng {
+            Some(lng) => push(*lng, *lat),
+            None => {
+                // A cell has at most one vertex at a pole, so both neighbours have a longitude
+                let prev = vertices[(index + 3) % 4].0.unwrap();
+                let next = vertices[(index + 1) % 4].0.unwrap();
+                push(prev, *lat);
+                push(next, *lat);
+            }
+        }
+    }
+    coords.push(coords[0]);
+    geo::Polygon::new(geo::LineString::new(coords), vec![])
+}
+
+/// The ancestor of a cell at the given level.
+fn parent(id: u64, level: u8) -> u64 {
+    let lsb = 1u64 << (2 * (MAX_LEVEL - level) as u64);
+    (id & lsb.wrapping_neg()) | lsb
+}
+
+fn xyz_to_face_uv([x, y, z]: [f64; 3]) -> (u8, f64, f64) {
+    let face = if x.abs() >= y.abs() && x.abs() >= z.abs() {
+        if x >= 0.0 { 0 } else { 3 }
+    } else if y.abs() >= z.abs() {
+        if y >= 0.0 { 1 } else { 4 }
+    } else if z >= 0.0 {
+        2
+    } else {
+        5
+    };
+    let (u, v) = match face {
+        0 => (y / x, z / x),
+        1 => (-x / y, z / y),
+        2 => (-x / z, -y / z),
+        3 => (z / x, y / x),
+        4 => (z / y, -x / y),
+        _ => (-y / z, -x / z),
+    };
+    (face, u, v)
+}
+
+fn face_uv_to_xyz(face: u8, u: f64, v: f64) -> [f64; 3] {
+    match face {
+        0 => [1.0, u, v],
+        1 => [-u, 1.0, v],
+        2 => [-u, -v, 1.0],
+        3 => [-1.0, -v, -u],
+        4 => [v, -1.0, -u],
+        _ => [v, u, -1.0],
+    }
+}
+
+/// The quadratic projection from the cube face to the unit square used by S2.
+fn uv_to_st(u: f64) -> f64 {
+    if u >= 0.0 {
+        0.5 * (1.0 + 3.0 * u).sqrt()
+    } else {
+        1.0 - 0.5 * (1.0 - 3.0 * u).sqrt()
+    }
+}
+
+fn st_to_uv(s: f64) -> f64 {
+    if s >= 0.5 {
+        (4.0 * s * s - 1.0) / 3.0
+    } else {
+        (1.0 - 4.0 * (1.0 - s) * (1.0 - s)) / 3.0
+    }
+}
+
+fn st_to_ij(s: f64) -> u32 {
+    ((s * MAX_SIZE as f64).floor() as i64).clamp(0, MAX_SIZE as i64 - 1) as u32
+}
+
+fn face_ij_to_leaf(face: u8, i: u32, j: u32) -> u64 {
+    let mut orientation = face & SWAP_MASK;
+    let mut pos = 0u64;
+    for k in (0..MAX_LEVEL).rev() {
+        let ij = ((((i >> k) & 1) << 1) | ((j >> k) & 1)) as usize;
+        let sub_pos = IJ_TO_POS[orientation as usize][ij];
+        pos = (pos << 2) | sub_pos as u64;
+        orientation ^= POS_TO_ORIENTATION[sub_pos as usize];
+    }
+    ((face as u64) << 61) | (pos << 1) | 1
+}
+
+fn to_face_ij(id: u64) -> (u8, u32, u32) {
+    let face = (id >> 61) as u8;
+    let mut orientation = face & SWAP_MASK;
+    let (mut i, mut j) = (0u32, 0u32);
+    for k in (0..MAX_LEVEL).rev() {
+        let sub_pos = ((id >> (2 * k as u64 + 1)) & 3) as usize;
+        let ij = POS_TO_IJ[orientation as usize][sub_pos];
+        i |= ((ij >> 1) as u32) << k;
+        j |= ((ij & 1) as u32) << k;
+        orientation ^= POS_TO_ORIENTATION[sub_pos];
+    }
+    (face, i, j)
+}
@@ -0,0 +1,143 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::Int64Array;
+use arrow_schema::DataType;
+use datafusion::error::Result;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_schema::error::GeoArrowError;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::s2::cell::cell_id_from_lng_lat;
+use crate::udf::s2::util::level_arg;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CellId {
+    signature: Signature,
+}
+
+impl CellId {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(1), TypeSignature::Any(2)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl Default for CellId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellId {
+    fn name(&self) -> &str {
+        "s2_cell_id"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(cell_id_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the id of the S2 cell of the given level (default 30) that contains a point. The point is expected to be in longitude/latitude order. As in BigQuery, the 64-bit cell id is returned as a signed integer. Empty points return NULL.",
+                "s2_cell_id(point, level)",
+            )
+            .with_argument("point", "geometry")
+            .with_argument("level", "integer between 0 and 30, default 30")
+            .build()
+        }))
+    }
+}
+
+fn cell_id_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let level = match args.args.len() {
+        1 => 30,
+        _ => level_arg(&args, 1, "level")?,
+    };
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let result = cell_id(&geo_array, level)?;
+    Ok(ColumnarValue::Array(Arc::new(result)))
+}
+
+fn cell_id(array: &dyn GeoArrowArray, level: u8) -> GeoDataFusionResult<Int64Array> {
+    downcast_geoarrow_array!(array, _cell_id_impl, level)
+}
+
+fn _cell_id_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    level: u8,
+) -> GeoDataFusionResult<Int64Array> {
+    array
+        .iter()
+        .map(|item| {
+            let Some(geom) = item.transpose()? else {
+                return Ok(None);
+            };
+            let GeometryType::Point(point) = geom.as_type() else {
+                return Err(GeoArrowError::IncorrectGeometryType(
+                    "s2_cell_id only supports Point geometries".to_string(),
+                )
+                .into());
+            };
+            Ok(point
+                .coord()
+                .map(|coord| cell_id_from_lng_lat(coord.x(), coord.y(), level) as i64))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+
+    #[tokio::test]
+    async fn test_cell_id() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(CellId::default().into());
+        ctx.register_udf(Point::default().into());
+
+        let df = ctx
+            .sql("SELECT s2_cell_id(ST_Point(0, 0), 1), s2_cell_id(ST_Point(-74.006, 40.7128), 8);")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_primitive::<Int64Type>().value(0),
+            0x1400000000000000
+        );
+        assert_eq!(
+            batches[0].column(1).as_primitive::<Int64Type>().value(0) as u64,
+            0x89c2500000000000
+        );
+    }
+}
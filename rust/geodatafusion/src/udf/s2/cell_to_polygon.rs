@@ -0,0 +1,120 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_schema::{DataType, FieldRef};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    TypeSignature, Volatility,
+};
+use geoarrow_array::GeoArrowArray;
+use geoarrow_array::builder::PolygonBuilder;
+use geoarrow_schema::{CoordType, Dimension, Metadata, PolygonType};
+
+use crate::crs::crs_from_srid;
+use crate::error::GeoDataFusionResult;
+use crate::udf::s2::cell::cell_polygon;
+use crate::udf::s2::util::{cell_arg, cell_id};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct CellToPolygon {
+    signature: Signature,
+    coord_type: CoordType,
+}
+
+impl CellToPolygon {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(1)], Volatility::Immutable),
+            coord_type,
+        }
+    }
+}
+
+impl Default for CellToPolygon {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for CellToPolygon {
+    fn name(&self) -> &str {
+        "s2_cell_to_polygon"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Err(DataFusionError::Internal("return_type".to_string()))
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        let metadata = Arc::new(Metadata::new(crs_from_srid(4326), None));
+        let output_type =
+            PolygonType::new(Dimension::XY, metadata).with_coord_type(self.coord_type);
+        Ok(Arc::new(output_type.to_field("", true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(cell_to_polygon_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the boundary of an S2 cell as a polygon of its 4 vertices, with the EPSG:4326 CRS. The vertices are joined by straight lines in longitude/latitude rather than by geodesics, and cells crossing the antimeridian extend beyond ±180 instead of being split.",
+                "s2_cell_to_polygon(cell)",
+            )
+            .with_argument("cell", "S2 cell id")
+            .build()
+        }))
+    }
+}
+
+fn cell_to_polygon_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let cells = cell_arg(&args, 0)?;
+    let typ = args.return_field.extension_type::<PolygonType>();
+    let mut builder = PolygonBuilder::with_capacity(typ, Default::default());
+    for cell in cells.iter() {
+        if let Some(cell) = cell {
+            let polygon = cell_polygon(cell_id(cell)?);
+            builder.push_polygon(Some(&polygon))?;
+        } else {
+            builder.push_polygon(None::<&geo::Polygon>)?;
+        }
+    }
+    Ok(ColumnarValue::Array(builder.finish().into_array_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::io::AsText;
+
+    #[tokio::test]
+    async fn test_cell_to_polygon() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(CellToPolygon::default().into());
+        ctx.register_udf(AsText.into());
+
+        // The level 1 cell in the northeast quadrant of face 0, centered on 0°N 0°E
+        let df = ctx
+            .sql("SELECT ST_AsText(s2_cell_to_polygon(1441151880758558720));")
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>().value(0),
+            "POLYGON((0 0,45 0,45 35.264389682754654,0 45,0 0))"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::sync::{Arc, OnceLock};
+
+use arrow_array::builder::{Int64Builder, ListBuilder};
+use arrow_schema::{DataType, Field};
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature, TypeSignature,
+    Volatility,
+};
+use geo::{Contains, Intersects, MapCoords};
+use geoarrow_array::array::from_arrow_array;
+use geoarrow_array::{GeoArrowArray, GeoArrowArrayAccessor, downcast_geoarrow_array};
+use geoarrow_expr_geo::util::to_geo::geometry_to_geo;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::s2::cell::{cell_level, cell_polygon, children, face_cells};
+use crate::udf::s2::util::level_arg;
+
+/// The coarsest level whose cell polygons are compared with the geometry. Coarser cells are too
+/// distorted in longitude/latitude, so they are compared through their descendants at this level.
+const MIN_POLYGON_LEVEL: u8 = 3;
+
+/// The maximum number of cells in the covering of a single geometry.
+const MAX_COVERING_CELLS: usize = 1 << 16;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Covering {
+    signature: Signature,
+}
+
+impl Covering {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(vec![TypeSignature::Any(3)], Volatility::Immutable),
+        }
+    }
+}
+
+impl Default for Covering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Covering {
+    fn name(&self) -> &str {
+        "s2_covering"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            DataType::Int64,
+            true,
+        ))))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        Ok(covering_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the ids of S2 cells between min_level and max_level that cover a geometry, in Hilbert curve order. Cells inside the geometry are returned at the coarsest possible level, and cells on its boundary at max_level. The geometry is expected to be in longitude/latitude order, and cell boundaries are approximated by straight lines in longitude/latitude.",
+                "s2_covering(geom, min_level, max_level)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("min_level", "integer between 0 and 30")
+            .with_argument("max_level", "integer between min_level and 30")
+            .build()
+        }))
+    }
+}
+
+fn covering_impl(args: ScalarFunctionArgs) -> GeoDataFusionResult<ColumnarValue> {
+    let min_level = level_arg(&args, 1, "min_level")?;
+    let max_level = level_arg(&args, 2, "max_level")?;
+    if min_level > max_level {
+        return Err(DataFusionError::Execution(
+            "s2_covering min_level must not be greater than max_level".to_string(),
+        )
+        .into());
+    }
+
+    let array = &ColumnarValue::values_to_arrays(&args.args[..1])?[0];
+    let geo_array = from_arrow_array(&array, &args.arg_fields[0])?;
+    let mut builder = ListBuilder::with_capacity(Int64Builder::new(), array.len());
+    let coverer = Coverer {
+        min_level,
+        max_level,
+    };
+    covering(&geo_array, &coverer, &mut builder)?;
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+fn covering(
+    array: &dyn GeoArrowArray,
+    coverer: &Coverer,
+    builder: &mut ListBuilder<Int64Builder>,
+) -> GeoDataFusionResult<()> {
+    downcast_geoarrow_array!(array, _covering_impl, coverer, builder)
+}
+
+fn _covering_impl<'a>(
+    array: &'a impl GeoArrowArrayAccessor<'a>,
+    coverer: &Coverer,
+    builder: &mut ListBuilder<Int64Builder>,
+) -> GeoDataFusionResult<()> {
+    for item in array.iter() {
+        if let Some(geom) = item {
+            let geom = geometry_to_geo(&geom?)?;
+            let mut cells = vec![];
+            for face in face_cells() {
+                coverer.cover(&geom, face, &mut cells)?;
+            }
+            for cell in cells {
+                builder.values().append_value(cell as i64);
+            }
+            builder.append(true);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(())
+}
+
+struct Coverer {
+    min_level: u8,
+    max_level: u8,
+}
+
+impl Coverer {
+    /// Add the cells covering the part of the geometry in a cell, by recursive subdivision.
+    fn cover(&self, geom: &geo::Geometry, cell: u64, cells: &mut Vec<u64>) -> Result<()> {
+        if !intersects(geom, cell) {
+            return Ok(());
+        }
+        let level = cell_level(cell).unwrap();
+        if level == self.max_level || (level >= self.min_level && contains(geom, cell)) {
+            if cells.len() == MAX_COVERING_CELLS {
+                return Err(DataFusionError::Execution(format!(
+                    "s2_covering would return more than {MAX_COVERING_CELLS} cells, use a lower max_level"
+                )));
+            }
+            cells.push(cell);
+            return Ok(());
+        }
+        for child in children(cell) {
+            self.cover(geom, child, cells)?;
+        }
+        Ok(())
+    }
+}
+
+fn intersects(geom: &geo::Geometry, cell: u64) -> bool {
+    if cell_level(cell).unwrap() < MIN_POLYGON_LEVEL {
+        children(cell)
+            .into_iter()
+            .any(|child| intersects(geom, child))
+    } else {
+        shifted_polygons(cell_polygon(cell)).any(|polygon| geom.intersects(&polygon))
+    }
+}
+
+fn contains(geom: &geo::Geometry, cell: u64) -> bool {
+    if cell_level(cell).unwrap() < MIN_POLYGON_LEVEL {
+        children(cell)
+            .into_iter()
+            .all(|child| contains(geom, child))
+    } else {
+        let polygon = cell_polygon(cell);
+        !crosses_antimeridian(&polygon) && geom.contains(&polygon)
+    }
+}
+
+/// A cell polygon, and its copy shifted by 360° if it crosses the antimeridian.
+fn shifted_polygons(polygon: geo::Polygon) -> impl Iterator<Item = geo::Polygon> {
+    let shifted = crosses_antimeridian(&polygon).then(|| {
+        let offset = if polygon.exterior().coords().any(|c| c.x > 180.0) {
+            -360.0
+        } else {
+            360.0
+        };
+        polygon.map_coords(|c| geo::coord! { x: c.x + offset, y: c.y })
+    });
+    std::iter::once(polygon).chain(shifted)
+}
+
+fn crosses_antimeridian(polygon: &geo::Polygon) -> bool {
+    polygon.exterior().coords().any(|c| c.x.abs() > 180.0)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::constructors::Point;
+    use crate::udf::s2::CellId;
+
+    #[tokio::test]
+    async fn test_covering() {
+        let ctx = SessionContext::new();
+        ctx.register_udf(Covering::default().into());
+        ctx.register_udf(CellId::default().into());
+        ctx.register_udf(Point::default().into());
+
+        let df = ctx
+            .sql(
+                "SELECT s2_covering(ST_Point(-74.006, 40.7128), 4, 12),
+                    s2_cell_id(ST_Point(-74.006, 40.7128), 12);",
+            )
+            .await
+            .unwrap();
+
+        let batches = df.collect().await.unwrap();
+        let cells = batches[0].column(0).as_list::<i32>().value(0);
+        assert_eq!(cells.len(), 1);
+        assert_eq!(
+            cells.as_primitive::<Int64Type>().value(0),
+            batches[0].column(1).as_primitive::<Int64Type>().value(0)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! S2 cell functions.
+
+mod cell;
+mod cell_id;
+mod cell_to_polygon;
+mod covering;
+mod util;
+
+pub use cell_id::CellId;
+pub use cell_to_polygon::CellToPolygon;
+pub use covering::Covering;
+
+pub fn register(session_context: &datafusion::prelude::SessionContext) {
+    session_context.register_udf(CellId::default().into());
+    session_context.register_udf(CellToPolygon::default().into());
+    session_context.register_udf(Covering::default().into());
+}
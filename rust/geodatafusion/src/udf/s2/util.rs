@@ -0,0 +1,45 @@
+use arrow_array::Int64Array;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_schema::DataType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion::scalar::ScalarValue;
+
+use crate::udf::s2::cell::{MAX_LEVEL, cell_level};
+
+/// The S2 cells of the argument at `index`, cast from any integer type.
+pub(super) fn cell_arg(args: &ScalarFunctionArgs, index: usize) -> Result<Int64Array> {
+    let array = args.args[index]
+        .cast_to(&DataType::Int64, None)?
+        .into_array(args.number_rows)?;
+    Ok(array.as_primitive::<Int64Type>().clone())
+}
+
+/// Reinterpret a signed cell id as an S2 cell id, checking that it is valid.
+pub(super) fn cell_id(cell: i64) -> Result<u64> {
+    let id = cell as u64;
+    match cell_level(id) {
+        Some(_) => Ok(id),
+        None => Err(DataFusionError::Execution(format!(
+            "Invalid S2 cell id {cell}"
+        ))),
+    }
+}
+
+/// The S2 level scalar argument at `index`.
+pub(super) fn level_arg(args: &ScalarFunctionArgs, index: usize, name: &str) -> Result<u8> {
+    match &args.args[index] {
+        ColumnarValue::Scalar(scalar) => match scalar.cast_to(&DataType::Int64)? {
+            ScalarValue::Int64(Some(level)) if (0..=MAX_LEVEL as i64).contains(&level) => {
+                Ok(level as u8)
+            }
+            _ => Err(DataFusionError::Execution(format!(
+                "S2 {name} must be between 0 and {MAX_LEVEL}"
+            ))),
+        },
+        ColumnarValue::Array(_) => Err(DataFusionError::NotImplemented(format!(
+            "Vectorized S2 {name} not yet implemented"
+        ))),
+    }
+}